@@ -1,32 +1,91 @@
 use crate::{
     execute::{
+        airdrop::{claim_airdrop, close_airdrop, create_airdrop},
+        bonding_curve::{buy_on_bonding_curve, sell_on_bonding_curve},
         config::update_config,
-        ft::{burn, force_transfer, mint},
+        freeze::{freeze_account, set_global_pause, unfreeze_account},
+        ft::{burn, burn_from, force_transfer, mint, self_burn},
+        launch_protection::set_launch_protection_addr,
+        liquidity_lock::withdraw_locked_lp,
+        ownership::save_admin_addr,
+        transfer_tax::{
+            renounce_transfer_tax, set_tax_exempt_addr, set_taxed_addr,
+            set_transfer_tax, sweep_tax,
+        },
+        vesting::claim_vested,
     },
+    migrate::v0_2_0::migrate_to_v0_2_0,
     query::{
+        airdrop::{query_airdrop, query_airdrop_claimed},
+        bonding_curve::{
+            query_bonding_curve, query_simulate_buy_on_bonding_curve,
+            query_simulate_sell_on_bonding_curve,
+        },
         config::query_config,
+        freeze::{query_frozen_accounts, query_is_frozen},
         ft::{query_balance, query_supply},
+        launch_protection::query_launch_protection,
+        liquidity_lock::query_liquidity_lock,
+        transfer_tax::{query_tax_owed, query_transfer_tax},
+        vesting::query_vesting_schedule,
+    },
+    reply::{
+        create_pair_reply, force_transfer_reply,
+        provide_bonding_curve_liquidity_reply, provide_seed_liquidity_reply,
+    },
+    state::{BONDING_CURVE_STATE, CONFIG, DENOM_EXPONENT, VESTING_SCHEDULES},
+    sudo::{
+        launch_protection::block_before_send,
+        transfer_tax::{block_tax_before_send, track_tax_before_send},
     },
-    reply::create_pair_reply,
-    state::{CONFIG, DENOM_EXPONENT},
     util::{
-        assert_helper::assert_only_admin_can_call_this_function,
-        astroport::create_pair, token_factory::create_and_mint_token,
+        airdrop::attach_airdrop,
+        assert_helper::{
+            assert_bonding_curve_migrated,
+            assert_only_admin_can_call_this_function,
+        },
+        bonding_curve::validate_bonding_curve_params,
+        dex::{dex_adapter, validate_seed_liquidity_dex},
+        freeze::{assert_not_globally_paused, assert_send_not_frozen},
+        launch_protection::save_launch_protection_config,
+        liquidity_lock::validate_liquidity_lock_mode,
+        token_factory::create_and_mint_token,
+        transfer_tax::save_transfer_tax_config,
+        vesting::build_vesting_schedules,
     },
 };
-use coin::msg::{ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg};
+use coin::{
+    bonding_curve::{BondingCurvePhase, BondingCurveState},
+    msg::{ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg, SudoMsg},
+};
 use cosmwasm_std::{
-    entry_point, to_json_binary, Binary, Deps, DepsMut, Env, MessageInfo,
-    Reply, Response, StdResult, Uint128,
+    coins, entry_point, to_json_binary, BankMsg, Binary, Deps, DepsMut, Env,
+    MessageInfo, Reply, Response, StdResult, Uint128,
 };
 use cw2::set_contract_version;
 use cw_utils::{must_pay, nonpayable};
-use shared_pkg::error::ContractError;
+use osmosis_std::types::{
+    cosmos::base::v1beta1::Coin as SdkCoin,
+    osmosis::tokenfactory::v1beta1::MsgMint,
+};
+use semver::Version;
+use shared_pkg::{
+    burn::{query_burn_leaderboard, query_burned_amount},
+    error::ContractError,
+    migration::assert_can_migrate,
+    ownership::{
+        accept_admin, cancel_proposal, propose_new_admin, query_ownership,
+        renounce_admin,
+    },
+};
 
 pub const CONTRACT_NAME: &str = env!("CARGO_PKG_NAME");
 pub const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
 
 pub const REPLY_ID_CREATE_PAIR: u64 = 0;
+pub const REPLY_ID_PROVIDE_BONDING_CURVE_LIQUIDITY: u64 = 1;
+pub const REPLY_ID_PROVIDE_SEED_LIQUIDITY: u64 = 2;
+pub const REPLY_ID_FORCE_TRANSFER: u64 = 3;
 
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn instantiate(
@@ -52,6 +111,94 @@ pub fn instantiate(
     let contract_addr = contract_info.address;
 
     let one_denom_in_base_denom = Uint128::from(10u128.pow(DENOM_EXPONENT));
+    if msg.initial_supply_in_denom > msg.max_supply_in_denom {
+        return Err(ContractError::InitialSupplyExceedsMaxSupply {
+            initial_supply: msg.initial_supply_in_denom,
+            max_supply: msg.max_supply_in_denom,
+        });
+    }
+
+    let vesting_schedules = build_vesting_schedules(
+        deps.api,
+        &env.block,
+        &msg.vesting_allocations.clone().unwrap_or_default(),
+        one_denom_in_base_denom,
+    )?;
+    let vesting_supply_in_base_denom = vesting_schedules
+        .iter()
+        .fold(Uint128::zero(), |total, s| total + s.total_amount);
+    if !vesting_supply_in_base_denom.is_zero()
+        && msg.initial_supply_in_denom * one_denom_in_base_denom
+            + vesting_supply_in_base_denom
+            > msg.max_supply_in_denom * one_denom_in_base_denom
+    {
+        return Err(ContractError::InvalidVestingAllocation {
+            reason: "initial supply plus vesting allocations must not exceed max supply".to_string(),
+        });
+    }
+    if let Some(airdrop) = &msg.airdrop {
+        if msg.bonding_curve_config.is_some() {
+            return Err(ContractError::InvalidAirdropConfig {
+                reason: "airdrop cannot be attached to a bonding curve launch before migration".to_string(),
+            });
+        }
+        if msg.initial_supply_in_denom * one_denom_in_base_denom
+            + vesting_supply_in_base_denom
+            + airdrop.total_amount
+            > msg.max_supply_in_denom * one_denom_in_base_denom
+        {
+            return Err(ContractError::InvalidAirdropConfig {
+                reason: "initial supply plus vesting allocations and airdrop must not exceed max supply".to_string(),
+            });
+        }
+    }
+    for schedule in &vesting_schedules {
+        VESTING_SCHEDULES.save(deps.storage, &schedule.recipient, schedule)?;
+    }
+
+    if let Some(cfg) = &msg.seed_liquidity_config {
+        validate_liquidity_lock_mode(&cfg.lock_mode, &env.block)?;
+        validate_seed_liquidity_dex(
+            deps.api,
+            deps.querier,
+            &cfg.dex,
+            &cfg.lock_mode,
+            &cfg.paired_base_denom,
+            cfg.paired_base_denom_amount,
+        )?;
+        if let Some(pool_supply_in_denom) = cfg.pool_supply_in_denom {
+            if pool_supply_in_denom.is_zero()
+                || pool_supply_in_denom > msg.initial_supply_in_denom
+            {
+                return Err(ContractError::InvalidSeedLiquidityConfig {
+                    reason: "pool supply must be greater than 0 and not exceed initial supply".to_string(),
+                });
+            }
+        }
+    }
+    if let Some(cfg) = &msg.bonding_curve_config {
+        if msg.seed_liquidity_config.is_some() {
+            return Err(ContractError::InvalidBondingCurveConfig {
+                reason: "seed liquidity must be None".to_string(),
+            });
+        }
+        validate_bonding_curve_params(
+            &cfg.params,
+            msg.initial_supply_in_denom * one_denom_in_base_denom,
+            vesting_supply_in_base_denom,
+            msg.max_supply_in_denom * one_denom_in_base_denom,
+        )?;
+        deps.api.addr_validate(&cfg.astroport_factory_addr)?;
+        BONDING_CURVE_STATE.save(
+            deps.storage,
+            &BondingCurveState {
+                phase: BondingCurvePhase::Trading {},
+                supply_sold_in_base_denom: Uint128::zero(),
+                reserve_in_paired_base_denom: Uint128::zero(),
+                pair_addr: None,
+            },
+        )?;
+    }
 
     let (base_denom, create_and_mint_token_msgs) = create_and_mint_token(
         deps.api,
@@ -62,6 +209,7 @@ pub fn instantiate(
         msg.initial_supply_in_denom * one_denom_in_base_denom,
         msg.max_supply_in_denom * one_denom_in_base_denom,
         msg.clone().seed_liquidity_config,
+        msg.clone().bonding_curve_config,
         msg.subdenom.as_str(),
         msg.denom_description.as_str(),
         msg.denom_name.as_str(),
@@ -70,13 +218,83 @@ pub fn instantiate(
         msg.denom_uri_hash.as_str(),
     )?;
 
+    // hook is registered once even if both transfer tax and launch protection are set
+    let mut register_hook_msgs = match msg.transfer_tax_config.clone() {
+        Some(transfer_tax_config) => save_transfer_tax_config(
+            deps.api,
+            deps.storage,
+            transfer_tax_config,
+            &base_denom,
+            &contract_addr,
+        )?,
+        None => vec![],
+    };
+    if let Some(launch_protection_config) = msg.launch_protection_config.clone()
+    {
+        register_hook_msgs.extend(save_launch_protection_config(
+            deps.api,
+            deps.storage,
+            &env.block,
+            launch_protection_config,
+            &base_denom,
+            &contract_addr,
+        )?);
+    }
+
+    // airdrop coin is held by this contract until claimed
+    let mut mint_airdrop_supply_msgs = vec![];
+    if let Some(airdrop_config) = msg.airdrop.clone() {
+        let (_, mint_msg) = attach_airdrop(
+            deps.api,
+            deps.storage,
+            &env.block,
+            airdrop_config,
+            &base_denom,
+            &contract_addr,
+        )?;
+        mint_airdrop_supply_msgs.push(mint_msg);
+    }
+
+    // vesting coin is held by this contract until claimed
+    let mut mint_vesting_supply_msgs = vec![];
+    if !vesting_supply_in_base_denom.is_zero() {
+        mint_vesting_supply_msgs.push(MsgMint {
+            sender: contract_addr.to_string(),
+            amount: Some(SdkCoin {
+                amount: vesting_supply_in_base_denom.to_string(),
+                denom: base_denom.clone(),
+            }),
+            mint_to_address: contract_addr.to_string(),
+        });
+    }
+
+    let initial_supply_in_base_denom =
+        msg.initial_supply_in_denom * one_denom_in_base_denom;
+    let mut send_creator_supply_msgs = vec![];
     let (create_pair_submsg, create_pair_attributes) =
         match msg.seed_liquidity_config {
             Some(cfg) => {
-                let (submsg, attributes) = create_pair(
-                    &deps.api.addr_validate(&cfg.astroport_factory_addr)?,
+                let pool_supply = match cfg.pool_supply_in_denom {
+                    Some(pool_supply_in_denom) => {
+                        pool_supply_in_denom * one_denom_in_base_denom
+                    }
+                    None => initial_supply_in_base_denom,
+                };
+                // initial supply not deposited to the pool goes to creator
+                let creator_supply = initial_supply_in_base_denom - pool_supply;
+                if !creator_supply.is_zero() {
+                    send_creator_supply_msgs.push(BankMsg::Send {
+                        to_address: msg.creator_addr.clone(),
+                        amount: coins(creator_supply.u128(), &base_denom),
+                    });
+                }
+                let (submsg, attributes) = dex_adapter(&cfg.dex).create_pool(
+                    deps.querier,
+                    &contract_addr,
                     &cfg.paired_base_denom,
                     base_denom.as_str(),
+                    cfg.paired_base_denom_amount,
+                    pool_supply,
                 )?;
                 (vec![submsg], attributes)
             }
@@ -97,7 +315,19 @@ pub fn instantiate(
         .add_attribute("subdenom", msg.subdenom)
         .add_attribute("max_supply_in_denom", msg.max_supply_in_denom)
         .add_attribute("initial_supply_in_denom", msg.initial_supply_in_denom)
+        .add_attribute(
+            "bonding_curve",
+            msg.bonding_curve_config.is_some().to_string(),
+        )
+        .add_attribute(
+            "vesting_supply_in_base_denom",
+            vesting_supply_in_base_denom,
+        )
         .add_messages(create_and_mint_token_msgs)
+        .add_messages(register_hook_msgs)
+        .add_messages(mint_vesting_supply_msgs)
+        .add_messages(mint_airdrop_supply_msgs)
+        .add_messages(send_creator_supply_msgs)
         .add_submessages(create_pair_submsg)
         .add_attributes(create_pair_attributes))
 }
@@ -110,28 +340,76 @@ pub fn execute(
     msg: ExecuteMsg,
 ) -> Result<Response, ContractError> {
     let info_ref = &info;
-    nonpayable(info_ref)?;
-
     let contract_addr_ref = &env.contract.address;
     let sender_addr_ref = &info.clone().sender;
     let config_ref = &CONFIG.load(deps.storage)?;
     let base_denom = config_ref.denom_metadata.base.as_str();
     match msg {
-        ExecuteMsg::UpdateConfig { new_admin_addr } => {
+        ExecuteMsg::UpdateConfig {} => {
+            nonpayable(info_ref)?;
             assert_only_admin_can_call_this_function(
                 sender_addr_ref,
                 &config_ref.admin_addr,
-                "update_admin",
+                "update_config",
             )?;
-            update_config(deps.api, deps.storage, new_admin_addr)
+            update_config()
+        }
+        ExecuteMsg::ProposeNewAdmin {
+            new_admin_addr,
+            expiry,
+        } => {
+            nonpayable(info_ref)?;
+            assert_only_admin_can_call_this_function(
+                sender_addr_ref,
+                &config_ref.admin_addr,
+                "propose_new_admin",
+            )?;
+            propose_new_admin(
+                deps.api,
+                deps.storage,
+                &env.block,
+                new_admin_addr,
+                expiry,
+            )
+        }
+        ExecuteMsg::AcceptAdmin {} => {
+            nonpayable(info_ref)?;
+            accept_admin(
+                deps.storage,
+                &env.block,
+                sender_addr_ref,
+                save_admin_addr,
+            )
+        }
+        ExecuteMsg::CancelProposal {} => {
+            nonpayable(info_ref)?;
+            assert_only_admin_can_call_this_function(
+                sender_addr_ref,
+                &config_ref.admin_addr,
+                "cancel_proposal",
+            )?;
+            cancel_proposal(deps.storage)
+        }
+        ExecuteMsg::RenounceAdmin {} => {
+            nonpayable(info_ref)?;
+            assert_only_admin_can_call_this_function(
+                sender_addr_ref,
+                &config_ref.admin_addr,
+                "renounce_admin",
+            )?;
+            renounce_admin(deps.storage, save_admin_addr)
         }
         ExecuteMsg::Mint { amount, recipient } => {
+            nonpayable(info_ref)?;
             assert_only_admin_can_call_this_function(
                 sender_addr_ref,
                 &config_ref.admin_addr,
                 "mint",
             )?;
+            // curve price assumes it is the only source of supply until migration
+            assert_bonding_curve_migrated(deps.storage)?;
             mint(
+                deps.storage,
                 deps.querier,
                 config_ref,
                 amount,
@@ -141,20 +419,55 @@ pub fn execute(
             )
         }
         ExecuteMsg::Burn { amount } => {
+            nonpayable(info_ref)?;
             assert_only_admin_can_call_this_function(
                 sender_addr_ref,
                 &config_ref.admin_addr,
                 "burn",
             )?;
-            burn(amount, base_denom, contract_addr_ref)
+            burn(
+                deps.storage,
+                deps.querier,
+                amount,
+                base_denom,
+                contract_addr_ref,
+            )
+        }
+        ExecuteMsg::BurnFrom { from, amount } => {
+            nonpayable(info_ref)?;
+            assert_only_admin_can_call_this_function(
+                sender_addr_ref,
+                &config_ref.admin_addr,
+                "burn_from",
+            )?;
+            burn_from(
+                deps.storage,
+                deps.querier,
+                amount,
+                base_denom,
+                contract_addr_ref,
+                &deps.api.addr_validate(&from)?,
+            )
+        }
+        ExecuteMsg::SelfBurn {} => {
+            let amount = must_pay(info_ref, base_denom)?;
+            self_burn(
+                deps.storage,
+                amount,
+                base_denom,
+                contract_addr_ref,
+                sender_addr_ref,
+            )
         }
         ExecuteMsg::ForceTransfer { amount, from, to } => {
+            nonpayable(info_ref)?;
             assert_only_admin_can_call_this_function(
                 sender_addr_ref,
                 &config_ref.admin_addr,
                 "force_transfer",
             )?;
             force_transfer(
+                deps.storage,
                 amount,
                 base_denom,
                 contract_addr_ref,
@@ -162,33 +475,326 @@ pub fn execute(
                 &deps.api.addr_validate(&to)?,
             )
         }
+        ExecuteMsg::WithdrawLockedLp {} => {
+            nonpayable(info_ref)?;
+            if sender_addr_ref != config_ref.creator_addr {
+                return Err(ContractError::OnlyCreatorCanCallThisFunction {
+                    function: "withdraw_locked_lp".to_string(),
+                });
+            }
+            let lock_mode = match &config_ref.seed_liquidity_config {
+                Some(cfg) => &cfg.lock_mode,
+                None => return Err(ContractError::NoLockedLpToWithdraw {}),
+            };
+            withdraw_locked_lp(
+                deps.storage,
+                &env.block,
+                lock_mode,
+                sender_addr_ref,
+            )
+        }
+        ExecuteMsg::BuyOnBondingCurve { min_amount_out } => {
+            let paid_amount = match &config_ref.bonding_curve_config {
+                Some(cfg) => must_pay(info_ref, &cfg.paired_base_denom)?,
+                None => return Err(ContractError::BondingCurveNotEnabled {}),
+            };
+            buy_on_bonding_curve(
+                deps.api,
+                deps.storage,
+                deps.querier,
+                config_ref,
+                base_denom,
+                contract_addr_ref,
+                sender_addr_ref,
+                paid_amount,
+                min_amount_out,
+            )
+        }
+        ExecuteMsg::SellOnBondingCurve {
+            min_paired_amount_out,
+        } => {
+            let amount = must_pay(info_ref, base_denom)?;
+            // coin paid into this contract passes the pause, so the sell is blocked here
+            assert_not_globally_paused(deps.storage, config_ref)?;
+            sell_on_bonding_curve(
+                deps.storage,
+                config_ref,
+                base_denom,
+                contract_addr_ref,
+                sender_addr_ref,
+                amount,
+                min_paired_amount_out,
+            )
+        }
+        ExecuteMsg::ClaimVested {} => {
+            nonpayable(info_ref)?;
+            claim_vested(deps.storage, &env.block, base_denom, sender_addr_ref)
+        }
+        ExecuteMsg::CreateAirdrop { airdrop } => {
+            nonpayable(info_ref)?;
+            assert_only_admin_can_call_this_function(
+                sender_addr_ref,
+                &config_ref.admin_addr,
+                "create_airdrop",
+            )?;
+            // curve price assumes it is the only source of supply until migration
+            assert_bonding_curve_migrated(deps.storage)?;
+            create_airdrop(
+                deps.api,
+                deps.storage,
+                deps.querier,
+                &env.block,
+                config_ref,
+                airdrop,
+                base_denom,
+                contract_addr_ref,
+            )
+        }
+        ExecuteMsg::ClaimAirdrop {
+            index,
+            amount,
+            merkle_proof,
+        } => {
+            nonpayable(info_ref)?;
+            claim_airdrop(
+                deps.storage,
+                &env.block,
+                base_denom,
+                sender_addr_ref,
+                index,
+                amount,
+                merkle_proof,
+            )
+        }
+        ExecuteMsg::CloseAirdrop {} => {
+            nonpayable(info_ref)?;
+            close_airdrop(
+                deps.storage,
+                &env.block,
+                base_denom,
+                contract_addr_ref,
+            )
+        }
+        ExecuteMsg::SetTransferTax {
+            buy_tax_bps,
+            sell_tax_bps,
+            destination,
+        } => {
+            nonpayable(info_ref)?;
+            assert_only_admin_can_call_this_function(
+                sender_addr_ref,
+                &config_ref.admin_addr,
+                "set_transfer_tax",
+            )?;
+            set_transfer_tax(
+                deps.api,
+                deps.storage,
+                buy_tax_bps,
+                sell_tax_bps,
+                destination,
+                base_denom,
+                contract_addr_ref,
+            )
+        }
+        ExecuteMsg::SetTaxedAddr { addr, taxed } => {
+            nonpayable(info_ref)?;
+            assert_only_admin_can_call_this_function(
+                sender_addr_ref,
+                &config_ref.admin_addr,
+                "set_taxed_addr",
+            )?;
+            set_taxed_addr(deps.storage, &deps.api.addr_validate(&addr)?, taxed)
+        }
+        ExecuteMsg::SetTaxExemptAddr { addr, exempt } => {
+            nonpayable(info_ref)?;
+            assert_only_admin_can_call_this_function(
+                sender_addr_ref,
+                &config_ref.admin_addr,
+                "set_tax_exempt_addr",
+            )?;
+            set_tax_exempt_addr(
+                deps.storage,
+                &deps.api.addr_validate(&addr)?,
+                exempt,
+            )
+        }
+        ExecuteMsg::RenounceTransferTax {} => {
+            nonpayable(info_ref)?;
+            assert_only_admin_can_call_this_function(
+                sender_addr_ref,
+                &config_ref.admin_addr,
+                "renounce_transfer_tax",
+            )?;
+            renounce_transfer_tax(deps.storage)
+        }
+        ExecuteMsg::SweepTax { addrs } => {
+            nonpayable(info_ref)?;
+            sweep_tax(
+                deps.storage,
+                deps.querier,
+                base_denom,
+                contract_addr_ref,
+                addrs
+                    .iter()
+                    .map(|addr| deps.api.addr_validate(addr))
+                    .collect::<StdResult<Vec<_>>>()?,
+            )
+        }
+        ExecuteMsg::SetLaunchProtectionAddr { addr, kind } => {
+            nonpayable(info_ref)?;
+            assert_only_admin_can_call_this_function(
+                sender_addr_ref,
+                &config_ref.admin_addr,
+                "set_launch_protection_addr",
+            )?;
+            set_launch_protection_addr(
+                deps.storage,
+                &env.block,
+                &deps.api.addr_validate(&addr)?,
+                kind,
+            )
+        }
+        ExecuteMsg::FreezeAccount { addr } => {
+            nonpayable(info_ref)?;
+            assert_only_admin_can_call_this_function(
+                sender_addr_ref,
+                &config_ref.admin_addr,
+                "freeze_account",
+            )?;
+            freeze_account(deps.storage, &deps.api.addr_validate(&addr)?)
+        }
+        ExecuteMsg::UnfreezeAccount { addr } => {
+            nonpayable(info_ref)?;
+            assert_only_admin_can_call_this_function(
+                sender_addr_ref,
+                &config_ref.admin_addr,
+                "unfreeze_account",
+            )?;
+            unfreeze_account(deps.storage, &deps.api.addr_validate(&addr)?)
+        }
+        ExecuteMsg::SetGlobalPause { paused } => {
+            nonpayable(info_ref)?;
+            assert_only_admin_can_call_this_function(
+                sender_addr_ref,
+                &config_ref.admin_addr,
+                "set_global_pause",
+            )?;
+            set_global_pause(deps.storage, paused)
+        }
     }
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
-pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
+pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
     let config_ref = &CONFIG.load(deps.storage)?;
     let base_denom = config_ref.denom_metadata.base.as_str();
     match msg {
         QueryMsg::Config {} => to_json_binary(&query_config(config_ref)?),
+        QueryMsg::Ownership {} => to_json_binary(&query_ownership(
+            deps.storage,
+            config_ref.admin_addr.clone(),
+        )?),
         QueryMsg::Supply {} => to_json_binary({
-            &query_supply(deps.querier, config_ref, base_denom)?
+            &query_supply(deps.storage, deps.querier, config_ref, base_denom)?
         }),
         QueryMsg::Balance { owner } => to_json_binary(&query_balance(
             deps.querier,
             &deps.api.addr_validate(&owner)?,
             base_denom,
         )?),
+        QueryMsg::BurnedAmount { addr } => {
+            to_json_binary(&query_burned_amount(
+                deps.storage,
+                &deps.api.addr_validate(&addr)?,
+            )?)
+        }
+        QueryMsg::BurnLeaderboard { start_after, limit } => {
+            to_json_binary(&query_burn_leaderboard(
+                deps.api,
+                deps.storage,
+                start_after,
+                limit,
+            )?)
+        }
+        QueryMsg::LiquidityLock {} => to_json_binary(&query_liquidity_lock(
+            deps.storage,
+            &env.block,
+            config_ref,
+        )?),
+        QueryMsg::BondingCurve {} => {
+            to_json_binary(&query_bonding_curve(deps.storage, config_ref)?)
+        }
+        QueryMsg::SimulateBuyOnBondingCurve { paired_amount } => {
+            to_json_binary(&query_simulate_buy_on_bonding_curve(
+                deps.storage,
+                config_ref,
+                paired_amount,
+            )?)
+        }
+        QueryMsg::SimulateSellOnBondingCurve { amount } => {
+            to_json_binary(&query_simulate_sell_on_bonding_curve(
+                deps.storage,
+                config_ref,
+                amount,
+            )?)
+        }
+        QueryMsg::VestingSchedule { addr } => {
+            to_json_binary(&query_vesting_schedule(
+                deps.storage,
+                &env.block,
+                &deps.api.addr_validate(&addr)?,
+            )?)
+        }
+        QueryMsg::Airdrop {} => to_json_binary(&query_airdrop(deps.storage)?),
+        QueryMsg::AirdropClaimed { index } => {
+            to_json_binary(&query_airdrop_claimed(deps.storage, index)?)
+        }
+        QueryMsg::TransferTax {} => {
+            to_json_binary(&query_transfer_tax(deps.storage)?)
+        }
+        QueryMsg::TaxOwed { addr } => to_json_binary(&query_tax_owed(
+            deps.storage,
+            &deps.api.addr_validate(&addr)?,
+        )?),
+        QueryMsg::LaunchProtection {} => {
+            to_json_binary(&query_launch_protection(deps.storage, &env.block)?)
+        }
+        QueryMsg::IsFrozen { addr } => to_json_binary(&query_is_frozen(
+            deps.storage,
+            config_ref,
+            &deps.api.addr_validate(&addr)?,
+        )?),
+        QueryMsg::FrozenAccounts { start_after, limit } => {
+            to_json_binary(&query_frozen_accounts(
+                deps.api,
+                deps.storage,
+                config_ref,
+                start_after,
+                limit,
+            )?)
+        }
     }
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn migrate(
-    _deps: DepsMut,
-    _env: Env,
+    deps: DepsMut,
+    env: Env,
     _msg: MigrateMsg,
 ) -> Result<Response, ContractError> {
-    Ok(Response::new())
+    let contract_name = format!("crates.io:{CONTRACT_NAME}");
+    let stored_version =
+        assert_can_migrate(deps.storage, &contract_name, CONTRACT_VERSION)?;
+    let mut msgs = vec![];
+    if stored_version < Version::new(0, 2, 0) {
+        msgs.extend(migrate_to_v0_2_0(deps.storage, &env)?);
+    }
+    set_contract_version(deps.storage, contract_name, CONTRACT_VERSION)?;
+    Ok(Response::new()
+        .add_messages(msgs)
+        .add_attribute("action", "migrate")
+        .add_attribute("from_version", stored_version.to_string())
+        .add_attribute("to_version", CONTRACT_VERSION))
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
@@ -200,10 +806,82 @@ pub fn reply(
     match msg.id {
         REPLY_ID_CREATE_PAIR => create_pair_reply(
             deps.querier,
-            deps.as_ref().storage,
+            deps.storage,
+            msg,
+            &env.contract.address,
+        ),
+        REPLY_ID_PROVIDE_SEED_LIQUIDITY => provide_seed_liquidity_reply(
+            deps.querier,
+            deps.storage,
             msg,
             &env.contract.address,
         ),
+        REPLY_ID_PROVIDE_BONDING_CURVE_LIQUIDITY => {
+            provide_bonding_curve_liquidity_reply(
+                deps.querier,
+                deps.storage,
+                &env.contract.address,
+            )
+        }
+        REPLY_ID_FORCE_TRANSFER => force_transfer_reply(deps.storage),
         _ => Err(ContractError::UnknownReplyId { reply_id: msg.id }),
     }
 }
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn sudo(
+    deps: DepsMut,
+    env: Env,
+    msg: SudoMsg,
+) -> Result<Response, ContractError> {
+    let config_ref = &CONFIG.load(deps.storage)?;
+    let base_denom = config_ref.denom_metadata.base.as_str();
+    match msg {
+        SudoMsg::TrackBeforeSend { from, to, amount } => {
+            let tax_attrs = track_tax_before_send(
+                deps.storage,
+                base_denom,
+                amount.amount,
+                amount.denom.as_str(),
+                &deps.api.addr_validate(&from)?,
+                &deps.api.addr_validate(&to)?,
+            )?;
+            Ok(Response::new()
+                .add_attribute("action", "track_before_send")
+                .add_attributes(tax_attrs))
+        }
+        SudoMsg::BlockBeforeSend { from, to, amount } => {
+            let from_addr = deps.api.addr_validate(&from)?;
+            let to_addr = deps.api.addr_validate(&to)?;
+            assert_send_not_frozen(
+                deps.storage,
+                config_ref,
+                base_denom,
+                amount.denom.as_str(),
+                &env.contract.address,
+                &from_addr,
+                &to_addr,
+            )?;
+            block_tax_before_send(
+                deps.storage,
+                deps.querier,
+                base_denom,
+                amount.amount,
+                amount.denom.as_str(),
+                &from_addr,
+                &to_addr,
+            )?;
+            block_before_send(
+                deps.storage,
+                deps.querier,
+                &env.block,
+                base_denom,
+                &env.contract.address,
+                amount.amount,
+                amount.denom.as_str(),
+                &from_addr,
+                &to_addr,
+            )
+        }
+    }
+}
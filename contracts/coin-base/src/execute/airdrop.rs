@@ -0,0 +1,303 @@
+use crate::{
+    state::{AIRDROP, AIRDROP_CLAIMED_BITMAP},
+    util::{
+        airdrop::attach_airdrop,
+        assert_helper::assert_max_base_denom_supply_not_reached,
+        token_factory::burn_msg,
+    },
+};
+use coin::config::Config;
+use cosmwasm_std::{
+    coins, Addr, Api, BankMsg, BlockInfo, CosmosMsg, QuerierWrapper, Response,
+    Storage, Uint128,
+};
+use shared_pkg::{
+    airdrop::{
+        airdrop_claim_bit, airdrop_unclaimed_amount, assert_can_claim_airdrop,
+        AirdropConfig,
+    },
+    error::ContractError,
+};
+
+pub fn create_airdrop(
+    api: &dyn Api,
+    storage: &mut dyn Storage,
+    querier: QuerierWrapper,
+    block: &BlockInfo,
+    config: &Config,
+    airdrop_config: AirdropConfig,
+    base_denom: &str,
+    contract_addr: &Addr,
+) -> Result<Response, ContractError> {
+    assert_max_base_denom_supply_not_reached(
+        querier.query_supply(base_denom)?.amount,
+        config.max_supply_in_base_denom,
+        airdrop_config.total_amount,
+    )?;
+    let (airdrop, mint_msg) = attach_airdrop(
+        api,
+        storage,
+        block,
+        airdrop_config,
+        base_denom,
+        contract_addr,
+    )?;
+    Ok(Response::new()
+        .add_message(mint_msg)
+        .add_attribute("action", "create_airdrop")
+        .add_attribute("round", airdrop.round.to_string())
+        .add_attribute("total_amount", airdrop.total_amount)
+        .add_attribute("expiration", airdrop.expiration.to_string()))
+}
+
+pub fn claim_airdrop(
+    storage: &mut dyn Storage,
+    block: &BlockInfo,
+    base_denom: &str,
+    claimer_addr: &Addr,
+    index: u32,
+    amount: Uint128,
+    merkle_proof: Vec<Vec<u8>>,
+) -> Result<Response, ContractError> {
+    let mut airdrop = AIRDROP
+        .may_load(storage)?
+        .ok_or(ContractError::NoAirdrop {})?;
+    let (word_index, bit) = airdrop_claim_bit(index);
+    let bitmap_key = (airdrop.round, word_index);
+    let claimed_bitmap_word = AIRDROP_CLAIMED_BITMAP
+        .may_load(storage, bitmap_key)?
+        .unwrap_or_default();
+    assert_can_claim_airdrop(
+        &airdrop,
+        block,
+        claimed_bitmap_word,
+        index,
+        claimer_addr,
+        amount,
+        merkle_proof,
+    )?;
+    AIRDROP_CLAIMED_BITMAP.save(
+        storage,
+        bitmap_key,
+        &(claimed_bitmap_word | bit),
+    )?;
+    airdrop.claimed_amount += amount;
+    AIRDROP.save(storage, &airdrop)?;
+    Ok(Response::new()
+        .add_message(BankMsg::Send {
+            to_address: claimer_addr.to_string(),
+            amount: coins(amount.u128(), base_denom),
+        })
+        .add_attribute("action", "claim_airdrop")
+        .add_attribute("round", airdrop.round.to_string())
+        .add_attribute("index", index.to_string())
+        .add_attribute("claimer", claimer_addr)
+        .add_attribute("amount", amount))
+}
+
+/// Return unclaimed amount to unclaimed recipient or burn it after expiration
+pub fn close_airdrop(
+    storage: &mut dyn Storage,
+    block: &BlockInfo,
+    base_denom: &str,
+    contract_addr: &Addr,
+) -> Result<Response, ContractError> {
+    let mut airdrop = AIRDROP
+        .may_load(storage)?
+        .ok_or(ContractError::NoAirdrop {})?;
+    if airdrop.closed {
+        return Err(ContractError::AirdropExpired {});
+    }
+    if block.time < airdrop.expiration {
+        return Err(ContractError::AirdropNotExpired {});
+    }
+    let unclaimed_amount = airdrop_unclaimed_amount(&airdrop);
+    airdrop.closed = true;
+    AIRDROP.save(storage, &airdrop)?;
+    let mut msgs: Vec<CosmosMsg> = vec![];
+    if !unclaimed_amount.is_zero() {
+        msgs.push(match &airdrop.unclaimed_recipient {
+            Some(recipient_addr) => BankMsg::Send {
+                to_address: recipient_addr.to_string(),
+                amount: coins(unclaimed_amount.u128(), base_denom),
+            }
+            .into(),
+            None => burn_msg(
+                storage,
+                unclaimed_amount,
+                base_denom,
+                contract_addr,
+                contract_addr,
+            )?,
+        });
+    }
+    Ok(Response::new()
+        .add_messages(msgs)
+        .add_attribute("action", "close_airdrop")
+        .add_attribute("round", airdrop.round.to_string())
+        .add_attribute("unclaimed_amount", unclaimed_amount)
+        .add_attribute(
+            "unclaimed_recipient",
+            match airdrop.unclaimed_recipient {
+                Some(addr) => addr.to_string(),
+                None => "burned".to_string(),
+            },
+        ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cosmwasm_std::testing::{mock_env, MockApi, MockStorage};
+    use shared_pkg::merkle::keccak256;
+
+    const BASE_DENOM: &str = "factory/coin/ucoin";
+
+    fn leaf(index: u32, addr: &str, amount: u128) -> Vec<u8> {
+        keccak256(format!("{index},{addr},{amount}").as_bytes())
+    }
+
+    /// alice is leaf 0 and bob is leaf 65, their claims land in different bitmap words
+    fn merkle_root() -> Vec<u8> {
+        let (alice, bob) = (leaf(0, "alice", 100), leaf(65, "bob", 200));
+        let (first, second) = if alice < bob {
+            (alice, bob)
+        } else {
+            (bob, alice)
+        };
+        keccak256(&[first, second].concat())
+    }
+
+    fn attach(storage: &mut dyn Storage, block: &BlockInfo) {
+        attach_airdrop(
+            &MockApi::default(),
+            storage,
+            block,
+            AirdropConfig {
+                merkle_root: merkle_root(),
+                merkle_tree_uri: None,
+                total_amount: Uint128::new(300),
+                expiration: block.time.plus_seconds(1_000),
+                unclaimed_recipient: Some("treasury".to_string()),
+            },
+            BASE_DENOM,
+            &Addr::unchecked("coin"),
+        )
+        .unwrap();
+    }
+
+    fn claim(
+        storage: &mut dyn Storage,
+        block: &BlockInfo,
+        index: u32,
+        addr: &str,
+        amount: u128,
+    ) -> Result<Response, ContractError> {
+        let proof = if addr == "alice" {
+            leaf(65, "bob", 200)
+        } else {
+            leaf(0, "alice", 100)
+        };
+        claim_airdrop(
+            storage,
+            block,
+            BASE_DENOM,
+            &Addr::unchecked(addr),
+            index,
+            Uint128::new(amount),
+            vec![proof],
+        )
+    }
+
+    fn bitmap_word(storage: &dyn Storage, round: u64, word: u32) -> u64 {
+        AIRDROP_CLAIMED_BITMAP
+            .may_load(storage, (round, word))
+            .unwrap()
+            .unwrap_or_default()
+    }
+
+    #[test]
+    fn each_leaf_can_be_claimed_once() {
+        let mut storage = MockStorage::new();
+        let block = mock_env().block;
+        attach(&mut storage, &block);
+
+        let res = claim(&mut storage, &block, 0, "alice", 100).unwrap();
+        assert_eq!(
+            res.messages[0].msg,
+            BankMsg::Send {
+                to_address: "alice".to_string(),
+                amount: coins(100, BASE_DENOM),
+            }
+            .into()
+        );
+        assert_eq!(bitmap_word(&storage, 1, 0), 1);
+        assert!(matches!(
+            claim(&mut storage, &block, 0, "alice", 100).unwrap_err(),
+            ContractError::AirdropAlreadyClaimed { index: 0 }
+        ));
+
+        // leaf does not match proof
+        assert!(matches!(
+            claim(&mut storage, &block, 65, "bob", 201).unwrap_err(),
+            ContractError::InvalidMerkleProofForAirdrop {}
+        ));
+        assert!(matches!(
+            claim(&mut storage, &block, 64, "bob", 200).unwrap_err(),
+            ContractError::InvalidMerkleProofForAirdrop {}
+        ));
+        claim(&mut storage, &block, 65, "bob", 200).unwrap();
+        assert_eq!(bitmap_word(&storage, 1, 0), 1);
+        assert_eq!(bitmap_word(&storage, 1, 1), 1 << 1);
+        assert_eq!(
+            AIRDROP.load(&storage).unwrap().claimed_amount,
+            Uint128::new(300)
+        );
+    }
+
+    #[test]
+    fn unclaimed_amount_is_returned_and_next_round_has_fresh_claims() {
+        let mut storage = MockStorage::new();
+        let block = mock_env().block;
+        attach(&mut storage, &block);
+        claim(&mut storage, &block, 0, "alice", 100).unwrap();
+
+        assert!(matches!(
+            close_airdrop(
+                &mut storage,
+                &block,
+                BASE_DENOM,
+                &Addr::unchecked("coin")
+            )
+            .unwrap_err(),
+            ContractError::AirdropNotExpired {}
+        ));
+        let mut expired = block.clone();
+        expired.time = block.time.plus_seconds(1_000);
+        assert!(matches!(
+            claim(&mut storage, &expired, 65, "bob", 200).unwrap_err(),
+            ContractError::AirdropExpired {}
+        ));
+        let res = close_airdrop(
+            &mut storage,
+            &expired,
+            BASE_DENOM,
+            &Addr::unchecked("coin"),
+        )
+        .unwrap();
+        assert_eq!(
+            res.messages[0].msg,
+            BankMsg::Send {
+                to_address: "treasury".to_string(),
+                amount: coins(200, BASE_DENOM),
+            }
+            .into()
+        );
+
+        // same leaves in the next round are tracked in a new bitmap
+        attach(&mut storage, &expired);
+        assert_eq!(AIRDROP.load(&storage).unwrap().round, 2);
+        claim(&mut storage, &expired, 0, "alice", 100).unwrap();
+        assert_eq!(bitmap_word(&storage, 2, 0), 1);
+    }
+}
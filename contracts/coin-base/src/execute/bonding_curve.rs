@@ -0,0 +1,488 @@
+use crate::{
+    state::{BONDING_CURVE_STATE, DENOM_EXPONENT},
+    util::{
+        astroport::{create_pair, query_pair_info, query_pair_total_share},
+        bonding_curve::{
+            amount_out_for_paid, market_cap, provide_bonding_curve_liquidity,
+            return_for_sell,
+        },
+        vesting::total_vesting_amount,
+    },
+};
+use coin::{
+    bonding_curve::{BondingCurveConfig, BondingCurvePhase},
+    config::Config,
+    dex::SeedLiquidityPairType,
+};
+use cosmwasm_std::{
+    coins, Addr, Api, BankMsg, QuerierWrapper, Response, Storage, Uint128,
+};
+use osmosis_std::types::{
+    cosmos::base::v1beta1::Coin as SdkCoin,
+    osmosis::tokenfactory::v1beta1::{MsgBurn, MsgMint},
+};
+use shared_pkg::error::ContractError;
+
+fn load_bonding_curve_config(
+    config: &Config,
+) -> Result<&BondingCurveConfig, ContractError> {
+    config
+        .bonding_curve_config
+        .as_ref()
+        .ok_or(ContractError::BondingCurveNotEnabled {})
+}
+
+pub fn buy_on_bonding_curve(
+    api: &dyn Api,
+    storage: &mut dyn Storage,
+    querier: QuerierWrapper,
+    config: &Config,
+    base_denom: &str,
+    contract_addr: &Addr,
+    buyer_addr: &Addr,
+    paid_amount: Uint128,
+    min_amount_out: Option<Uint128>,
+) -> Result<Response, ContractError> {
+    let bonding_curve_config = load_bonding_curve_config(config)?;
+    let params = &bonding_curve_config.params;
+    let mut state = BONDING_CURVE_STATE.load(storage)?;
+    if state.phase != (BondingCurvePhase::Trading {}) {
+        return Err(ContractError::BondingCurveNotTrading {});
+    }
+    let curve_supply_in_base_denom = params.curve_supply_in_denom
+        * Uint128::from(10u128.pow(DENOM_EXPONENT));
+    let (amount_out, paired_amount_spent) = amount_out_for_paid(
+        &params.curve,
+        state.supply_sold_in_base_denom,
+        curve_supply_in_base_denom - state.supply_sold_in_base_denom,
+        paid_amount,
+    )?;
+    let min_amount_out = min_amount_out.unwrap_or(Uint128::one());
+    if amount_out < min_amount_out {
+        return Err(ContractError::BondingCurveAmountOutTooLow {
+            amount_out,
+            min_amount_out,
+        });
+    }
+    state.supply_sold_in_base_denom += amount_out;
+    state.reserve_in_paired_base_denom += paired_amount_spent;
+
+    let mut resp = Response::new()
+        .add_message(MsgMint {
+            sender: contract_addr.to_string(),
+            amount: Some(SdkCoin {
+                amount: amount_out.to_string(),
+                denom: base_denom.to_string(),
+            }),
+            mint_to_address: contract_addr.to_string(),
+        })
+        .add_message(BankMsg::Send {
+            to_address: buyer_addr.to_string(),
+            amount: coins(amount_out.u128(), base_denom),
+        })
+        .add_attribute("action", "buy_on_bonding_curve")
+        .add_attribute("buyer", buyer_addr)
+        .add_attribute("amount_out", amount_out)
+        .add_attribute("paired_amount_spent", paired_amount_spent);
+    let refund_amount = paid_amount - paired_amount_spent;
+    if !refund_amount.is_zero() {
+        resp = resp.add_message(BankMsg::Send {
+            to_address: buyer_addr.to_string(),
+            amount: coins(
+                refund_amount.u128(),
+                &bonding_curve_config.paired_base_denom,
+            ),
+        });
+    }
+
+    let current_market_cap = market_cap(
+        &params.curve,
+        state.supply_sold_in_base_denom,
+        config.max_supply_in_base_denom,
+    )?;
+    if current_market_cap >= params.migration_market_cap
+        || state.supply_sold_in_base_denom == curve_supply_in_base_denom
+    {
+        // mint the rest of max supply, it goes to the pair with the reserve in create pair reply
+        // vesting allocations are already minted at instantiate
+        let remaining_supply = config.max_supply_in_base_denom
+            - state.supply_sold_in_base_denom
+            - total_vesting_amount(storage)?;
+        resp = resp
+            .add_message(MsgMint {
+                sender: contract_addr.to_string(),
+                amount: Some(SdkCoin {
+                    amount: remaining_supply.to_string(),
+                    denom: base_denom.to_string(),
+                }),
+                mint_to_address: contract_addr.to_string(),
+            })
+            .add_attribute("migrate_to_astroport", "true")
+            .add_attribute("market_cap", current_market_cap);
+        let factory_addr =
+            api.addr_validate(&bonding_curve_config.astroport_factory_addr)?;
+        // anyone can create the pair before the curve migrates, reuse it instead of failing create pair
+        // but only while it is empty, otherwise reserve would be provided at whatever ratio the pair was seeded
+        match query_pair_info(
+            querier,
+            factory_addr.as_str(),
+            &bonding_curve_config.paired_base_denom,
+            base_denom,
+        ) {
+            Ok(pair_info) => {
+                if !query_pair_total_share(querier, &pair_info.contract_addr)?
+                    .is_zero()
+                {
+                    return Err(
+                        ContractError::BondingCurvePairAlreadyHasLiquidity {
+                            pair_addr: pair_info.contract_addr.to_string(),
+                        },
+                    );
+                }
+                let (provide_liquidity_submsg, attributes) =
+                    provide_bonding_curve_liquidity(
+                        storage,
+                        bonding_curve_config,
+                        &mut state,
+                        contract_addr,
+                        base_denom,
+                        pair_info.contract_addr,
+                        remaining_supply,
+                    )?;
+                resp = resp
+                    .add_submessage(provide_liquidity_submsg)
+                    .add_attributes(attributes);
+            }
+            Err(_) => {
+                let (create_pair_submsg, create_pair_attributes) = create_pair(
+                    &factory_addr,
+                    &bonding_curve_config.paired_base_denom,
+                    base_denom,
+                    &SeedLiquidityPairType::Xyk {},
+                )?;
+                state.phase = BondingCurvePhase::Migrating {};
+                resp = resp
+                    .add_submessage(create_pair_submsg)
+                    .add_attributes(create_pair_attributes);
+            }
+        }
+    }
+    BONDING_CURVE_STATE.save(storage, &state)?;
+    Ok(resp)
+}
+
+pub fn sell_on_bonding_curve(
+    storage: &mut dyn Storage,
+    config: &Config,
+    base_denom: &str,
+    contract_addr: &Addr,
+    seller_addr: &Addr,
+    amount: Uint128,
+    min_paired_amount_out: Option<Uint128>,
+) -> Result<Response, ContractError> {
+    let bonding_curve_config = load_bonding_curve_config(config)?;
+    let mut state = BONDING_CURVE_STATE.load(storage)?;
+    if state.phase != (BondingCurvePhase::Trading {}) {
+        return Err(ContractError::BondingCurveNotTrading {});
+    }
+    let paired_amount_out = return_for_sell(
+        &bonding_curve_config.params.curve,
+        state.supply_sold_in_base_denom,
+        amount,
+    )?;
+    let min_paired_amount_out = min_paired_amount_out.unwrap_or_default();
+    if paired_amount_out < min_paired_amount_out {
+        return Err(ContractError::BondingCurveAmountOutTooLow {
+            amount_out: paired_amount_out,
+            min_amount_out: min_paired_amount_out,
+        });
+    }
+    state.supply_sold_in_base_denom -= amount;
+    // buy rounds up and sell rounds down, so reserve always covers the curve
+    state.reserve_in_paired_base_denom -= paired_amount_out;
+    BONDING_CURVE_STATE.save(storage, &state)?;
+
+    // not counted as total burned, curve mints it again on the next buy
+    let mut resp = Response::new()
+        .add_message(MsgBurn {
+            sender: contract_addr.to_string(),
+            amount: Some(SdkCoin {
+                amount: amount.to_string(),
+                denom: base_denom.to_string(),
+            }),
+            burn_from_address: contract_addr.to_string(),
+        })
+        .add_attribute("action", "sell_on_bonding_curve")
+        .add_attribute("seller", seller_addr)
+        .add_attribute("amount", amount)
+        .add_attribute("paired_amount_out", paired_amount_out);
+    if !paired_amount_out.is_zero() {
+        resp = resp.add_message(BankMsg::Send {
+            to_address: seller_addr.to_string(),
+            amount: coins(
+                paired_amount_out.u128(),
+                &bonding_curve_config.paired_base_denom,
+            ),
+        });
+    }
+    Ok(resp)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use astroport::{
+        asset::{AssetInfo, PairInfo},
+        factory::PairType,
+        pair::PoolResponse,
+    };
+    use coin::bonding_curve::{
+        BondingCurve, BondingCurveLpMode, BondingCurveParams, BondingCurveState,
+    };
+    use cosmwasm_std::{
+        testing::{mock_dependencies, MockApi, MockQuerier, MockStorage},
+        to_json_binary, ContractResult, CosmosMsg, Decimal, OwnedDeps,
+        SystemError, SystemResult, WasmMsg, WasmQuery,
+    };
+    use osmosis_std::types::cosmos::bank::v1beta1::Metadata as DenomMetadata;
+
+    const ONE_DENOM: u128 = 1_000_000;
+    const BASE_DENOM: &str = "factory/coin/ucoin";
+    const PAIRED_BASE_DENOM: &str = "untrn";
+    const FACTORY: &str = "factory";
+    const PAIR: &str = "pair";
+
+    /// price = 1 + 0.1 * supply_sold, max supply is 1000 denom
+    /// so market cap is 1000 + 100 * supply_sold and buying s denom costs s + 0.05 * s ^ 2
+    fn config(migration_market_cap: u128) -> Config {
+        Config {
+            admin_addr: None,
+            creator_addr: Addr::unchecked("creator"),
+            denom_metadata: DenomMetadata::default(),
+            max_supply_in_base_denom: Uint128::new(1_000 * ONE_DENOM),
+            seed_liquidity_config: None,
+            bonding_curve_config: Some(BondingCurveConfig {
+                astroport_factory_addr: FACTORY.to_string(),
+                paired_base_denom: PAIRED_BASE_DENOM.to_string(),
+                params: BondingCurveParams {
+                    curve: BondingCurve::Linear {
+                        initial_price: Decimal::one(),
+                        slope: Decimal::percent(10),
+                    },
+                    curve_supply_in_denom: Uint128::new(100),
+                    migration_market_cap: Uint128::new(migration_market_cap),
+                    lp_mode: BondingCurveLpMode::Lock {},
+                },
+            }),
+        }
+    }
+
+    /// existing_pair_total_share is None if nobody has created the pair yet
+    fn setup(
+        existing_pair_total_share: Option<u128>,
+    ) -> OwnedDeps<MockStorage, MockApi, MockQuerier> {
+        let mut deps = mock_dependencies();
+        deps.querier.update_wasm(move |query| match query {
+            WasmQuery::Smart { contract_addr, .. }
+                if contract_addr == FACTORY =>
+            {
+                match existing_pair_total_share {
+                    Some(_) => SystemResult::Ok(ContractResult::Ok(
+                        to_json_binary(&PairInfo {
+                            asset_infos: vec![
+                                AssetInfo::NativeToken {
+                                    denom: PAIRED_BASE_DENOM.to_string(),
+                                },
+                                AssetInfo::NativeToken {
+                                    denom: BASE_DENOM.to_string(),
+                                },
+                            ],
+                            contract_addr: Addr::unchecked(PAIR),
+                            liquidity_token: Addr::unchecked("lp"),
+                            pair_type: PairType::Xyk {},
+                        })
+                        .unwrap(),
+                    )),
+                    None => SystemResult::Ok(ContractResult::Err(
+                        "pair not found".to_string(),
+                    )),
+                }
+            }
+            WasmQuery::Smart { contract_addr, .. } if contract_addr == PAIR => {
+                SystemResult::Ok(ContractResult::Ok(
+                    to_json_binary(&PoolResponse {
+                        assets: vec![],
+                        total_share: Uint128::new(
+                            existing_pair_total_share.unwrap_or_default(),
+                        ),
+                    })
+                    .unwrap(),
+                ))
+            }
+            _ => SystemResult::Err(SystemError::UnsupportedRequest {
+                kind: "unexpected wasm query".to_string(),
+            }),
+        });
+        BONDING_CURVE_STATE
+            .save(
+                deps.as_mut().storage,
+                &BondingCurveState {
+                    phase: BondingCurvePhase::Trading {},
+                    supply_sold_in_base_denom: Uint128::zero(),
+                    reserve_in_paired_base_denom: Uint128::zero(),
+                    pair_addr: None,
+                },
+            )
+            .unwrap();
+        deps
+    }
+
+    fn buy(
+        deps: &mut OwnedDeps<MockStorage, MockApi, MockQuerier>,
+        config: &Config,
+        paid_amount: u128,
+    ) -> Result<Response, ContractError> {
+        buy_on_bonding_curve(
+            &deps.api,
+            &mut deps.storage,
+            QuerierWrapper::new(&deps.querier),
+            config,
+            BASE_DENOM,
+            &Addr::unchecked("coin"),
+            &Addr::unchecked("buyer"),
+            Uint128::new(paid_amount),
+            None,
+        )
+    }
+
+    fn minted_amounts(resp: &Response) -> Vec<Uint128> {
+        resp.messages
+            .iter()
+            .filter_map(|submsg| match &submsg.msg {
+                CosmosMsg::Stargate { type_url, value }
+                    if type_url == MsgMint::TYPE_URL =>
+                {
+                    let msg = MsgMint::try_from(value.clone()).unwrap();
+                    Some(msg.amount.unwrap().amount.parse().unwrap())
+                }
+                _ => None,
+            })
+            .collect()
+    }
+
+    fn wasm_execute_targets(resp: &Response) -> Vec<String> {
+        resp.messages
+            .iter()
+            .filter_map(|submsg| match &submsg.msg {
+                CosmosMsg::Wasm(WasmMsg::Execute { contract_addr, .. }) => {
+                    Some(contract_addr.clone())
+                }
+                _ => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn buy_below_migration_market_cap_keeps_trading() {
+        let mut deps = setup(None);
+        let config = config(5_000);
+        // 100 buys about 34 denom, market cap stays below 5000
+        let resp = buy(&mut deps, &config, 100).unwrap();
+        let state = BONDING_CURVE_STATE.load(&deps.storage).unwrap();
+        assert_eq!(state.phase, BondingCurvePhase::Trading {});
+        assert!(state.supply_sold_in_base_denom < Uint128::new(40 * ONE_DENOM));
+        assert_eq!(
+            minted_amounts(&resp),
+            vec![state.supply_sold_in_base_denom]
+        );
+        assert!(wasm_execute_targets(&resp).is_empty());
+    }
+
+    #[test]
+    fn reaching_migration_market_cap_creates_pair() {
+        let mut deps = setup(None);
+        let config = config(5_000);
+        // 40 denom costs 40 + 0.05 * 40 ^ 2 = 120, market cap is then 1000 + 100 * 40 = 5000
+        let resp = buy(&mut deps, &config, 120).unwrap();
+        let state = BONDING_CURVE_STATE.load(&deps.storage).unwrap();
+        assert_eq!(state.phase, BondingCurvePhase::Migrating {});
+        assert_eq!(
+            state.supply_sold_in_base_denom,
+            Uint128::new(40 * ONE_DENOM)
+        );
+        assert_eq!(state.reserve_in_paired_base_denom, Uint128::new(120));
+        // rest of max supply is minted for the pair
+        assert_eq!(
+            minted_amounts(&resp),
+            vec![Uint128::new(40 * ONE_DENOM), Uint128::new(960 * ONE_DENOM)]
+        );
+        assert_eq!(wasm_execute_targets(&resp), vec![FACTORY.to_string()]);
+        assert!(matches!(
+            buy(&mut deps, &config, 1).unwrap_err(),
+            ContractError::BondingCurveNotTrading {}
+        ));
+    }
+
+    #[test]
+    fn buying_whole_curve_supply_migrates_and_refunds_rest() {
+        let mut deps = setup(None);
+        // market cap never reaches this before curve supply is sold out
+        let config = config(1_000_000);
+        // 100 denom costs 100 + 0.05 * 100 ^ 2 = 600
+        let resp = buy(&mut deps, &config, 1_000).unwrap();
+        let state = BONDING_CURVE_STATE.load(&deps.storage).unwrap();
+        assert_eq!(state.phase, BondingCurvePhase::Migrating {});
+        assert_eq!(
+            state.supply_sold_in_base_denom,
+            Uint128::new(100 * ONE_DENOM)
+        );
+        assert_eq!(state.reserve_in_paired_base_denom, Uint128::new(600));
+        assert!(resp.messages.iter().any(|submsg| submsg.msg
+            == CosmosMsg::Bank(BankMsg::Send {
+                to_address: "buyer".to_string(),
+                amount: coins(400, PAIRED_BASE_DENOM),
+            })));
+        assert_eq!(wasm_execute_targets(&resp), vec![FACTORY.to_string()]);
+    }
+
+    #[test]
+    fn migration_provides_into_existing_empty_pair() {
+        let mut deps = setup(Some(0));
+        let config = config(5_000);
+        let resp = buy(&mut deps, &config, 120).unwrap();
+        let state = BONDING_CURVE_STATE.load(&deps.storage).unwrap();
+        assert_eq!(state.phase, BondingCurvePhase::Migrated {});
+        assert_eq!(state.pair_addr, Some(Addr::unchecked(PAIR)));
+        assert_eq!(wasm_execute_targets(&resp), vec![PAIR.to_string()]);
+        let provide_funds = resp
+            .messages
+            .iter()
+            .find_map(|submsg| match &submsg.msg {
+                CosmosMsg::Wasm(WasmMsg::Execute { funds, .. }) => {
+                    Some(funds.clone())
+                }
+                _ => None,
+            })
+            .unwrap();
+        assert_eq!(
+            provide_funds,
+            vec![
+                cosmwasm_std::coin(120, PAIRED_BASE_DENOM),
+                cosmwasm_std::coin(960 * ONE_DENOM, BASE_DENOM),
+            ]
+        );
+    }
+
+    #[test]
+    fn migration_rejects_existing_pair_with_liquidity() {
+        let mut deps = setup(Some(1_000));
+        let config = config(5_000);
+        assert!(matches!(
+            buy(&mut deps, &config, 120).unwrap_err(),
+            ContractError::BondingCurvePairAlreadyHasLiquidity { pair_addr }
+                if pair_addr == PAIR
+        ));
+        // buys that do not reach the threshold are unaffected
+        buy(&mut deps, &config, 100).unwrap();
+    }
+}
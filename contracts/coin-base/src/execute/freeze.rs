@@ -0,0 +1,33 @@
+use crate::state::{FROZEN_ACCOUNTS, GLOBAL_PAUSE};
+use cosmwasm_std::{Addr, Empty, Response, Storage};
+use shared_pkg::error::ContractError;
+
+pub fn freeze_account(
+    storage: &mut dyn Storage,
+    addr: &Addr,
+) -> Result<Response, ContractError> {
+    FROZEN_ACCOUNTS.save(storage, addr, &Empty {})?;
+    Ok(Response::new()
+        .add_attribute("action", "freeze_account")
+        .add_attribute("addr", addr))
+}
+
+pub fn unfreeze_account(
+    storage: &mut dyn Storage,
+    addr: &Addr,
+) -> Result<Response, ContractError> {
+    FROZEN_ACCOUNTS.remove(storage, addr);
+    Ok(Response::new()
+        .add_attribute("action", "unfreeze_account")
+        .add_attribute("addr", addr))
+}
+
+pub fn set_global_pause(
+    storage: &mut dyn Storage,
+    paused: bool,
+) -> Result<Response, ContractError> {
+    GLOBAL_PAUSE.save(storage, &paused)?;
+    Ok(Response::new()
+        .add_attribute("action", "set_global_pause")
+        .add_attribute("paused", paused.to_string()))
+}
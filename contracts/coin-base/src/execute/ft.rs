@@ -1,13 +1,41 @@
-use crate::util::assert_helper::assert_max_base_denom_supply_not_reached;
+use crate::{
+    contract::REPLY_ID_FORCE_TRANSFER,
+    state::{PENDING_FORCE_TRANSFER, TOTAL_BURNED},
+    util::{
+        airdrop::total_unclaimed_airdrop_amount,
+        assert_helper::assert_max_base_denom_supply_not_reached,
+        token_factory::burn_msg, vesting::total_unclaimed_vesting_amount,
+    },
+};
 use coin::config::Config;
-use cosmwasm_std::{coins, Addr, BankMsg, QuerierWrapper, Response, Uint128};
+use cosmwasm_std::{Addr, QuerierWrapper, Response, Storage, SubMsg, Uint128};
 use osmosis_std::types::{
     cosmos::base::v1beta1::Coin as SdkCoin,
-    osmosis::tokenfactory::v1beta1::{MsgBurn, MsgForceTransfer, MsgMint},
+    osmosis::tokenfactory::v1beta1::{MsgForceTransfer, MsgMint},
 };
-use shared_pkg::error::ContractError;
+use shared_pkg::{burn::record_self_burn, error::ContractError};
+
+/// Coin held by this contract for vesting beneficiaries and airdrop claimants cannot be burned
+fn assert_burn_within_free_balance(
+    storage: &dyn Storage,
+    querier: QuerierWrapper,
+    base_denom: &str,
+    contract_addr: &Addr,
+    amount: Uint128,
+) -> Result<(), ContractError> {
+    let free_balance = querier
+        .query_balance(contract_addr, base_denom)?
+        .amount
+        .saturating_sub(total_unclaimed_vesting_amount(storage)?)
+        .saturating_sub(total_unclaimed_airdrop_amount(storage)?);
+    if amount > free_balance {
+        return Err(ContractError::BurnExceedsFreeBalance { free_balance });
+    }
+    Ok(())
+}
 
 pub fn mint(
+    storage: &dyn Storage,
     querier: QuerierWrapper,
     config: &Config,
     mint_amount: Uint128,
@@ -15,8 +43,11 @@ pub fn mint(
     contract_addr: &Addr,
     recipient_addr: &Addr,
 ) -> Result<Response, ContractError> {
+    // burned supply stays burned, max supply caps everything minted so far
+    let minted_supply = querier.query_supply(base_denom)?.amount
+        + TOTAL_BURNED.may_load(storage)?.unwrap_or_default();
     assert_max_base_denom_supply_not_reached(
-        querier.query_supply(base_denom)?.amount,
+        minted_supply,
         config.max_supply_in_base_denom,
         mint_amount,
     )?;
@@ -26,48 +57,105 @@ pub fn mint(
             amount: mint_amount.to_string(),
             denom: base_denom.to_string(),
         }),
-        // TODO: test if we can mint straight to the recipient
-        mint_to_address: contract_addr.to_string(),
-    };
-    let bank_msg = BankMsg::Send {
-        to_address: recipient_addr.to_string(),
-        amount: coins(mint_amount.u128(), base_denom),
+        mint_to_address: recipient_addr.to_string(),
     };
     Ok(Response::new()
         .add_message(mint_ft_msg)
-        .add_message(bank_msg)
         .add_attribute("action", "mint")
         .add_attribute("amount", mint_amount)
         .add_attribute("recipient", recipient_addr))
 }
 
 pub fn burn(
+    storage: &mut dyn Storage,
+    querier: QuerierWrapper,
     amount: Uint128,
     base_denom: &str,
     contract_addr: &Addr,
 ) -> Result<Response, ContractError> {
-    let msg = MsgBurn {
-        sender: contract_addr.to_string(),
-        amount: Some(SdkCoin {
-            amount: amount.to_string(),
-            denom: base_denom.to_string(),
-        }),
-        // TODO: test if we can burn straight from the a designated address
-        burn_from_address: contract_addr.to_string(),
-    };
+    assert_burn_within_free_balance(
+        storage,
+        querier,
+        base_denom,
+        contract_addr,
+        amount,
+    )?;
     Ok(Response::new()
-        .add_message(msg)
+        .add_message(burn_msg(
+            storage,
+            amount,
+            base_denom,
+            contract_addr,
+            contract_addr,
+        )?)
         .add_attribute("action", "burn")
         .add_attribute("amount", amount))
 }
 
+pub fn burn_from(
+    storage: &mut dyn Storage,
+    querier: QuerierWrapper,
+    amount: Uint128,
+    base_denom: &str,
+    contract_addr: &Addr,
+    from_addr: &Addr,
+) -> Result<Response, ContractError> {
+    if from_addr == contract_addr {
+        assert_burn_within_free_balance(
+            storage,
+            querier,
+            base_denom,
+            contract_addr,
+            amount,
+        )?;
+    }
+    Ok(Response::new()
+        .add_message(burn_msg(
+            storage,
+            amount,
+            base_denom,
+            contract_addr,
+            from_addr,
+        )?)
+        .add_attribute("action", "burn_from")
+        .add_attribute("amount", amount)
+        .add_attribute("from", from_addr))
+}
+
+/// Coin is already paid into this contract, burn it from here
+pub fn self_burn(
+    storage: &mut dyn Storage,
+    amount: Uint128,
+    base_denom: &str,
+    contract_addr: &Addr,
+    burner_addr: &Addr,
+) -> Result<Response, ContractError> {
+    let total_self_burned = record_self_burn(storage, burner_addr, amount)?;
+    Ok(Response::new()
+        .add_message(burn_msg(
+            storage,
+            amount,
+            base_denom,
+            contract_addr,
+            contract_addr,
+        )?)
+        .add_attribute("action", "self_burn")
+        .add_attribute("amount", amount)
+        .add_attribute("burner", burner_addr)
+        .add_attribute("total_self_burned", total_self_burned))
+}
+
+/// Goes through frozen accounts and global pause, reply clears the pending record
 pub fn force_transfer(
+    storage: &mut dyn Storage,
     amount: Uint128,
     base_denom: &str,
     contract_addr: &Addr,
     from_addr: &Addr,
     to_addr: &Addr,
 ) -> Result<Response, ContractError> {
+    PENDING_FORCE_TRANSFER
+        .save(storage, &(from_addr.clone(), to_addr.clone()))?;
     let msg = MsgForceTransfer {
         sender: contract_addr.to_string(),
         amount: Some(SdkCoin {
@@ -78,9 +166,206 @@ pub fn force_transfer(
         transfer_to_address: to_addr.to_string(),
     };
     Ok(Response::new()
-        .add_message(msg)
+        .add_submessage(SubMsg::reply_on_success(msg, REPLY_ID_FORCE_TRANSFER))
         .add_attribute("action", "force_transfer")
         .add_attribute("amount", amount)
         .add_attribute("from", from_addr)
         .add_attribute("to", to_addr))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::{AIRDROP, VESTING_SCHEDULES};
+    use coin::vesting::VestingSchedule;
+    use cosmwasm_std::{
+        coins,
+        testing::{
+            mock_dependencies_with_balances, MockApi, MockQuerier, MockStorage,
+        },
+        CosmosMsg, OwnedDeps, Timestamp,
+    };
+    use osmosis_std::types::{
+        cosmos::bank::v1beta1::Metadata as DenomMetadata,
+        osmosis::tokenfactory::v1beta1::MsgBurn,
+    };
+    use shared_pkg::{airdrop::Airdrop, burn::BURNED_AMOUNTS};
+
+    const BASE_DENOM: &str = "factory/coin/ucoin";
+
+    fn addr(name: &str) -> Addr {
+        Addr::unchecked(name)
+    }
+
+    fn config() -> Config {
+        Config {
+            admin_addr: Some(addr("admin")),
+            creator_addr: addr("creator"),
+            denom_metadata: DenomMetadata::default(),
+            max_supply_in_base_denom: Uint128::new(2_000),
+            seed_liquidity_config: None,
+            bonding_curve_config: None,
+        }
+    }
+
+    /// Coin contract holds 1000, 300 reserved for vesting and 150 for airdrop, alice holds 500
+    fn setup() -> OwnedDeps<MockStorage, MockApi, MockQuerier> {
+        let mut deps = mock_dependencies_with_balances(&[
+            ("coin", &coins(1_000, BASE_DENOM)),
+            ("alice", &coins(500, BASE_DENOM)),
+        ]);
+        VESTING_SCHEDULES
+            .save(
+                &mut deps.storage,
+                &addr("bob"),
+                &VestingSchedule {
+                    recipient: addr("bob"),
+                    total_amount: Uint128::new(400),
+                    claimed_amount: Uint128::new(100),
+                    start_time: Timestamp::from_seconds(0),
+                    cliff_time: Timestamp::from_seconds(0),
+                    end_time: Timestamp::from_seconds(100),
+                },
+            )
+            .unwrap();
+        AIRDROP
+            .save(
+                &mut deps.storage,
+                &Airdrop {
+                    round: 1,
+                    merkle_root: vec![0; 32],
+                    merkle_tree_uri: None,
+                    total_amount: Uint128::new(200),
+                    claimed_amount: Uint128::new(50),
+                    expiration: Timestamp::from_seconds(100),
+                    unclaimed_recipient: None,
+                    closed: false,
+                },
+            )
+            .unwrap();
+        deps
+    }
+
+    fn burned_from(res: &Response) -> String {
+        match &res.messages[0].msg {
+            CosmosMsg::Stargate { value, .. } => {
+                MsgBurn::try_from(value.clone()).unwrap().burn_from_address
+            }
+            _ => panic!("unexpected msg"),
+        }
+    }
+
+    fn total_burned(storage: &dyn Storage) -> Uint128 {
+        TOTAL_BURNED.may_load(storage).unwrap().unwrap_or_default()
+    }
+
+    #[test]
+    fn mint_cap_counts_burned_supply() {
+        let mut deps = setup();
+        TOTAL_BURNED
+            .save(&mut deps.storage, &Uint128::new(300))
+            .unwrap();
+        let mint_amount = |deps: &OwnedDeps<_, _, _>, amount: u128| {
+            mint(
+                &deps.storage,
+                QuerierWrapper::new(&deps.querier),
+                &config(),
+                Uint128::new(amount),
+                BASE_DENOM,
+                &addr("coin"),
+                &addr("alice"),
+            )
+        };
+        // 1500 in supply and 300 burned leaves room for 200
+        assert!(matches!(
+            mint_amount(&deps, 201).unwrap_err(),
+            ContractError::MaxBaseDenomSupplyReached { current_base_denom_supply, .. }
+                if current_base_denom_supply == Uint128::new(1_800)
+        ));
+        let res = mint_amount(&deps, 200).unwrap();
+        assert_eq!(
+            res.messages[0].msg,
+            MsgMint {
+                sender: "coin".to_string(),
+                amount: Some(SdkCoin {
+                    amount: "200".to_string(),
+                    denom: BASE_DENOM.to_string(),
+                }),
+                mint_to_address: "alice".to_string(),
+            }
+            .into()
+        );
+    }
+
+    #[test]
+    fn burn_cannot_touch_vesting_and_airdrop_reserves() {
+        let mut deps = setup();
+        let querier = QuerierWrapper::new(&deps.querier);
+        assert!(matches!(
+            burn(
+                &mut deps.storage,
+                querier,
+                Uint128::new(551),
+                BASE_DENOM,
+                &addr("coin"),
+            )
+            .unwrap_err(),
+            ContractError::BurnExceedsFreeBalance { free_balance }
+                if free_balance == Uint128::new(550)
+        ));
+        assert!(matches!(
+            burn_from(
+                &mut deps.storage,
+                querier,
+                Uint128::new(551),
+                BASE_DENOM,
+                &addr("coin"),
+                &addr("coin"),
+            )
+            .unwrap_err(),
+            ContractError::BurnExceedsFreeBalance { .. }
+        ));
+        let res = burn(
+            &mut deps.storage,
+            querier,
+            Uint128::new(550),
+            BASE_DENOM,
+            &addr("coin"),
+        )
+        .unwrap();
+        assert_eq!(burned_from(&res), "coin");
+        assert_eq!(total_burned(&deps.storage), Uint128::new(550));
+    }
+
+    #[test]
+    fn burn_from_holder_and_self_burn_add_to_total_burned() {
+        let mut deps = setup();
+        let res = burn_from(
+            &mut deps.storage,
+            QuerierWrapper::new(&deps.querier),
+            Uint128::new(500),
+            BASE_DENOM,
+            &addr("coin"),
+            &addr("alice"),
+        )
+        .unwrap();
+        assert_eq!(burned_from(&res), "alice");
+        assert_eq!(total_burned(&deps.storage), Uint128::new(500));
+
+        let res = self_burn(
+            &mut deps.storage,
+            Uint128::new(100),
+            BASE_DENOM,
+            &addr("coin"),
+            &addr("bob"),
+        )
+        .unwrap();
+        // paid in coin is burned from this contract
+        assert_eq!(burned_from(&res), "coin");
+        assert_eq!(total_burned(&deps.storage), Uint128::new(600));
+        assert_eq!(
+            BURNED_AMOUNTS.load(&deps.storage, &addr("bob")).unwrap(),
+            Uint128::new(100)
+        );
+    }
+}
@@ -0,0 +1,29 @@
+use crate::{
+    state::LAUNCH_PROTECTION_ADDRS,
+    util::launch_protection::load_active_launch_protection,
+};
+use coin::launch_protection::LaunchProtectionAddrKind;
+use cosmwasm_std::{Addr, BlockInfo, Response, Storage};
+use shared_pkg::error::ContractError;
+
+pub fn set_launch_protection_addr(
+    storage: &mut dyn Storage,
+    block: &BlockInfo,
+    addr: &Addr,
+    kind: Option<LaunchProtectionAddrKind>,
+) -> Result<Response, ContractError> {
+    load_active_launch_protection(storage, block)?;
+    let kind_attribute = match &kind {
+        Some(LaunchProtectionAddrKind::Exempt {}) => "exempt",
+        Some(LaunchProtectionAddrKind::Pool {}) => "pool",
+        None => "None",
+    };
+    match kind {
+        Some(kind) => LAUNCH_PROTECTION_ADDRS.save(storage, addr, &kind)?,
+        None => LAUNCH_PROTECTION_ADDRS.remove(storage, addr),
+    }
+    Ok(Response::new()
+        .add_attribute("action", "set_launch_protection_addr")
+        .add_attribute("addr", addr)
+        .add_attribute("kind", kind_attribute))
+}
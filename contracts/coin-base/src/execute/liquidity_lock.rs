@@ -0,0 +1,40 @@
+use crate::{
+    state::SEED_LIQUIDITY_LP,
+    util::{
+        dex::lp_transfer_msg, liquidity_lock::calculate_withdrawable_lp_amount,
+    },
+};
+use coin::liquidity_lock::LiquidityLockMode;
+use cosmwasm_std::{Addr, BlockInfo, Response, Storage};
+use shared_pkg::error::ContractError;
+
+pub fn withdraw_locked_lp(
+    storage: &mut dyn Storage,
+    block: &BlockInfo,
+    lock_mode: &LiquidityLockMode,
+    creator_addr: &Addr,
+) -> Result<Response, ContractError> {
+    let mut seed_liquidity_lp = SEED_LIQUIDITY_LP
+        .may_load(storage)?
+        .ok_or(ContractError::NoLockedLpToWithdraw {})?;
+    let withdraw_amount =
+        calculate_withdrawable_lp_amount(lock_mode, &seed_liquidity_lp, block);
+    if withdraw_amount.is_zero() {
+        return Err(ContractError::NoLockedLpToWithdraw {});
+    }
+    seed_liquidity_lp.withdrawn_amount += withdraw_amount;
+    SEED_LIQUIDITY_LP.save(storage, &seed_liquidity_lp)?;
+    Ok(Response::new()
+        .add_messages(lp_transfer_msg(
+            &seed_liquidity_lp.lp_token,
+            creator_addr,
+            withdraw_amount,
+        )?)
+        .add_attribute("action", "withdraw_locked_lp")
+        .add_attribute("creator", creator_addr)
+        .add_attribute("amount", withdraw_amount)
+        .add_attribute(
+            "total_withdrawn_amount",
+            seed_liquidity_lp.withdrawn_amount,
+        ))
+}
@@ -0,0 +1,317 @@
+use crate::{
+    state::{
+        TAXED_ADDRS, TAX_EXEMPT_ADDRS, TAX_OWED, TAX_UNCOLLECTABLE,
+        TRANSFER_TAX,
+    },
+    util::{
+        token_factory::burn_msg,
+        transfer_tax::{load_mutable_transfer_tax, save_transfer_tax},
+    },
+};
+use coin::transfer_tax::TaxDestination;
+use cosmwasm_std::{
+    Addr, Api, CosmosMsg, Empty, QuerierWrapper, Response, Storage, Uint128,
+};
+use osmosis_std::types::{
+    cosmos::base::v1beta1::Coin as SdkCoin,
+    osmosis::tokenfactory::v1beta1::MsgForceTransfer,
+};
+use shared_pkg::error::ContractError;
+
+pub fn set_transfer_tax(
+    api: &dyn Api,
+    storage: &mut dyn Storage,
+    buy_tax_bps: u64,
+    sell_tax_bps: u64,
+    destination: TaxDestination,
+    base_denom: &str,
+    contract_addr: &Addr,
+) -> Result<Response, ContractError> {
+    if TRANSFER_TAX.exists(storage) {
+        load_mutable_transfer_tax(storage)?;
+    }
+    let register_hook_msgs = save_transfer_tax(
+        api,
+        storage,
+        buy_tax_bps,
+        sell_tax_bps,
+        destination,
+        base_denom,
+        contract_addr,
+    )?;
+    Ok(Response::new()
+        .add_messages(register_hook_msgs)
+        .add_attribute("action", "set_transfer_tax")
+        .add_attribute("buy_tax_bps", buy_tax_bps.to_string())
+        .add_attribute("sell_tax_bps", sell_tax_bps.to_string()))
+}
+
+pub fn set_taxed_addr(
+    storage: &mut dyn Storage,
+    addr: &Addr,
+    taxed: bool,
+) -> Result<Response, ContractError> {
+    load_mutable_transfer_tax(storage)?;
+    if taxed {
+        TAXED_ADDRS.save(storage, addr, &Empty {})?;
+    } else {
+        TAXED_ADDRS.remove(storage, addr);
+    }
+    Ok(Response::new()
+        .add_attribute("action", "set_taxed_addr")
+        .add_attribute("addr", addr)
+        .add_attribute("taxed", taxed.to_string()))
+}
+
+pub fn set_tax_exempt_addr(
+    storage: &mut dyn Storage,
+    addr: &Addr,
+    exempt: bool,
+) -> Result<Response, ContractError> {
+    load_mutable_transfer_tax(storage)?;
+    if exempt {
+        TAX_EXEMPT_ADDRS.save(storage, addr, &Empty {})?;
+    } else {
+        TAX_EXEMPT_ADDRS.remove(storage, addr);
+    }
+    Ok(Response::new()
+        .add_attribute("action", "set_tax_exempt_addr")
+        .add_attribute("addr", addr)
+        .add_attribute("exempt", exempt.to_string()))
+}
+
+pub fn renounce_transfer_tax(
+    storage: &mut dyn Storage,
+) -> Result<Response, ContractError> {
+    let mut transfer_tax = load_mutable_transfer_tax(storage)?;
+    transfer_tax.renounced = true;
+    TRANSFER_TAX.save(storage, &transfer_tax)?;
+    Ok(Response::new().add_attribute("action", "renounce_transfer_tax"))
+}
+
+/// Only what is left in the balance is swept, the rest is written off as uncollectable
+pub fn sweep_tax(
+    storage: &mut dyn Storage,
+    querier: QuerierWrapper,
+    base_denom: &str,
+    contract_addr: &Addr,
+    addrs: Vec<Addr>,
+) -> Result<Response, ContractError> {
+    let transfer_tax = TRANSFER_TAX
+        .may_load(storage)?
+        .ok_or(ContractError::TransferTaxNotSet {})?;
+    let destination_addr = match &transfer_tax.destination {
+        TaxDestination::Treasury { addr } => addr.clone(),
+        // swept to this contract first, then burned
+        TaxDestination::Burn {} => contract_addr.to_string(),
+    };
+    let mut msgs: Vec<CosmosMsg> = vec![];
+    let mut total_swept = Uint128::zero();
+    let mut total_written_off = Uint128::zero();
+    for addr in addrs {
+        let owed = TAX_OWED.may_load(storage, &addr)?.unwrap_or_default();
+        if owed.is_zero() {
+            continue;
+        }
+        let balance = querier.query_balance(&addr, base_denom)?.amount;
+        let swept = owed.min(balance);
+        // removed before the force transfer so before send hook does not block it
+        TAX_OWED.remove(storage, &addr);
+        total_written_off += owed - swept;
+        if swept.is_zero() {
+            continue;
+        }
+        total_swept += swept;
+        msgs.push(
+            MsgForceTransfer {
+                sender: contract_addr.to_string(),
+                amount: Some(SdkCoin {
+                    amount: swept.to_string(),
+                    denom: base_denom.to_string(),
+                }),
+                transfer_from_address: addr.to_string(),
+                transfer_to_address: destination_addr.clone(),
+            }
+            .into(),
+        );
+    }
+    if total_swept.is_zero() && total_written_off.is_zero() {
+        return Err(ContractError::NoTaxToSweep {});
+    }
+    if !total_written_off.is_zero() {
+        let uncollectable =
+            TAX_UNCOLLECTABLE.may_load(storage)?.unwrap_or_default();
+        TAX_UNCOLLECTABLE
+            .save(storage, &(uncollectable + total_written_off))?;
+    }
+    if transfer_tax.destination == (TaxDestination::Burn {})
+        && !total_swept.is_zero()
+    {
+        msgs.push(burn_msg(
+            storage,
+            total_swept,
+            base_denom,
+            contract_addr,
+            contract_addr,
+        )?);
+    }
+    Ok(Response::new()
+        .add_messages(msgs)
+        .add_attribute("action", "sweep_tax")
+        .add_attribute("destination", destination_addr)
+        .add_attribute("amount", total_swept)
+        .add_attribute("written_off", total_written_off))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cosmwasm_std::{
+        coins,
+        testing::{
+            mock_dependencies_with_balances, MockApi, MockQuerier, MockStorage,
+        },
+        OwnedDeps,
+    };
+
+    const BASE_DENOM: &str = "factory/coin/ucoin";
+
+    fn addr(name: &str) -> Addr {
+        Addr::unchecked(name)
+    }
+
+    /// alice holds 1000, bob holds 30
+    fn setup(
+        destination: TaxDestination,
+    ) -> OwnedDeps<MockStorage, MockApi, MockQuerier> {
+        let mut deps = mock_dependencies_with_balances(&[
+            ("alice", &coins(1_000, BASE_DENOM)),
+            ("bob", &coins(30, BASE_DENOM)),
+        ]);
+        set_transfer_tax(
+            &deps.api,
+            &mut deps.storage,
+            500,
+            500,
+            destination,
+            BASE_DENOM,
+            &addr("coin"),
+        )
+        .unwrap();
+        deps
+    }
+
+    fn sweep(
+        deps: &mut OwnedDeps<MockStorage, MockApi, MockQuerier>,
+        addrs: &[&str],
+    ) -> Result<Response, ContractError> {
+        sweep_tax(
+            &mut deps.storage,
+            QuerierWrapper::new(&deps.querier),
+            BASE_DENOM,
+            &addr("coin"),
+            addrs.iter().map(|name| addr(name)).collect(),
+        )
+    }
+
+    fn force_transfer(from: &str, to: &str, amount: u128) -> CosmosMsg {
+        MsgForceTransfer {
+            sender: "coin".to_string(),
+            amount: Some(SdkCoin {
+                amount: amount.to_string(),
+                denom: BASE_DENOM.to_string(),
+            }),
+            transfer_from_address: from.to_string(),
+            transfer_to_address: to.to_string(),
+        }
+        .into()
+    }
+
+    #[test]
+    fn sweep_moves_owed_tax_to_treasury() {
+        let mut deps = setup(TaxDestination::Treasury {
+            addr: "treasury".to_string(),
+        });
+        TAX_OWED
+            .save(&mut deps.storage, &addr("alice"), &Uint128::new(50))
+            .unwrap();
+        let resp = sweep(&mut deps, &["alice", "carol"]).unwrap();
+        assert_eq!(
+            resp.messages
+                .into_iter()
+                .map(|submsg| submsg.msg)
+                .collect::<Vec<_>>(),
+            vec![force_transfer("alice", "treasury", 50)]
+        );
+        assert!(!TAX_OWED.has(&deps.storage, &addr("alice")));
+        assert!(TAX_UNCOLLECTABLE.may_load(&deps.storage).unwrap().is_none());
+        // nothing owed anymore
+        assert!(matches!(
+            sweep(&mut deps, &["alice"]).unwrap_err(),
+            ContractError::NoTaxToSweep {}
+        ));
+    }
+
+    #[test]
+    fn sweep_writes_off_tax_above_balance() {
+        let mut deps = setup(TaxDestination::Burn {});
+        TAX_OWED
+            .save(&mut deps.storage, &addr("bob"), &Uint128::new(80))
+            .unwrap();
+        // carol sent the whole balance away before the sweep
+        TAX_OWED
+            .save(&mut deps.storage, &addr("carol"), &Uint128::new(20))
+            .unwrap();
+        let resp = sweep(&mut deps, &["bob", "carol"]).unwrap();
+        // bob's 30 is swept to this contract and burned
+        assert_eq!(resp.messages.len(), 2);
+        assert_eq!(resp.messages[0].msg, force_transfer("bob", "coin", 30));
+        assert_eq!(
+            TAX_UNCOLLECTABLE.load(&deps.storage).unwrap(),
+            Uint128::new(70)
+        );
+        assert!(!TAX_OWED.has(&deps.storage, &addr("bob")));
+        assert!(!TAX_OWED.has(&deps.storage, &addr("carol")));
+    }
+
+    #[test]
+    fn renounced_tax_cannot_change_but_is_still_swept() {
+        let mut deps = setup(TaxDestination::Treasury {
+            addr: "treasury".to_string(),
+        });
+        set_taxed_addr(&mut deps.storage, &addr("pair"), true).unwrap();
+        renounce_transfer_tax(&mut deps.storage).unwrap();
+        assert!(TRANSFER_TAX.load(&deps.storage).unwrap().renounced);
+        assert!(matches!(
+            set_transfer_tax(
+                &deps.api,
+                &mut deps.storage,
+                0,
+                0,
+                TaxDestination::Burn {},
+                BASE_DENOM,
+                &addr("coin"),
+            )
+            .unwrap_err(),
+            ContractError::TransferTaxRenounced {}
+        ));
+        assert!(matches!(
+            set_taxed_addr(&mut deps.storage, &addr("pair"), false)
+                .unwrap_err(),
+            ContractError::TransferTaxRenounced {}
+        ));
+        assert!(matches!(
+            set_tax_exempt_addr(&mut deps.storage, &addr("alice"), true)
+                .unwrap_err(),
+            ContractError::TransferTaxRenounced {}
+        ));
+        assert!(matches!(
+            renounce_transfer_tax(&mut deps.storage).unwrap_err(),
+            ContractError::TransferTaxRenounced {}
+        ));
+        TAX_OWED
+            .save(&mut deps.storage, &addr("alice"), &Uint128::new(10))
+            .unwrap();
+        sweep(&mut deps, &["alice"]).unwrap();
+    }
+}
@@ -0,0 +1,31 @@
+use crate::{
+    state::VESTING_SCHEDULES, util::vesting::calculate_claimable_vested_amount,
+};
+use cosmwasm_std::{coins, Addr, BankMsg, BlockInfo, Response, Storage};
+use shared_pkg::error::ContractError;
+
+pub fn claim_vested(
+    storage: &mut dyn Storage,
+    block: &BlockInfo,
+    base_denom: &str,
+    recipient_addr: &Addr,
+) -> Result<Response, ContractError> {
+    let mut schedule = VESTING_SCHEDULES
+        .may_load(storage, recipient_addr)?
+        .ok_or(ContractError::NoVestedCoinToClaim {})?;
+    let claim_amount = calculate_claimable_vested_amount(&schedule, block);
+    if claim_amount.is_zero() {
+        return Err(ContractError::NoVestedCoinToClaim {});
+    }
+    schedule.claimed_amount += claim_amount;
+    VESTING_SCHEDULES.save(storage, recipient_addr, &schedule)?;
+    Ok(Response::new()
+        .add_message(BankMsg::Send {
+            to_address: recipient_addr.to_string(),
+            amount: coins(claim_amount.u128(), base_denom),
+        })
+        .add_attribute("action", "claim_vested")
+        .add_attribute("recipient", recipient_addr)
+        .add_attribute("amount", claim_amount)
+        .add_attribute("total_claimed_amount", schedule.claimed_amount))
+}
@@ -0,0 +1,71 @@
+use crate::{state::CONFIG, util::token_factory::register_before_send_hook};
+use coin::{
+    config::Config,
+    dex::{SeedLiquidityDex, SeedLiquidityPairType},
+    liquidity_lock::LiquidityLockMode,
+    msg::SeedLiquidityConfig,
+};
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, CosmosMsg, Env, Storage, Uint128};
+use cw_storage_plus::Item;
+use osmosis_std::types::cosmos::bank::v1beta1::Metadata as DenomMetadata;
+use shared_pkg::error::ContractError;
+
+/// Seed liquidity config before DEX, lock mode and pool supply were configurable
+#[cw_serde]
+struct SeedLiquidityConfigV0_1 {
+    astroport_factory_addr: String,
+    paired_base_denom: String,
+    paired_base_denom_amount: Uint128,
+}
+
+/// Config before bonding curve launch
+#[cw_serde]
+struct ConfigV0_1 {
+    admin_addr: Option<Addr>,
+    creator_addr: Addr,
+    denom_metadata: DenomMetadata,
+    max_supply_in_base_denom: Uint128,
+    seed_liquidity_config: Option<SeedLiquidityConfigV0_1>,
+}
+
+const CONFIG_V0_1: Item<ConfigV0_1> = Item::new("CONFIG");
+
+/// Mutable coins created before freeze and pause need the before send hook to enforce them
+pub fn migrate_to_v0_2_0(
+    storage: &mut dyn Storage,
+    env: &Env,
+) -> Result<Vec<CosmosMsg>, ContractError> {
+    // seed liquidity was always an Astroport XYK pool with LP sent to creator
+    let config_v0_1 = CONFIG_V0_1.load(storage)?;
+    let config = Config {
+        admin_addr: config_v0_1.admin_addr,
+        creator_addr: config_v0_1.creator_addr,
+        denom_metadata: config_v0_1.denom_metadata,
+        max_supply_in_base_denom: config_v0_1.max_supply_in_base_denom,
+        seed_liquidity_config: config_v0_1.seed_liquidity_config.map(
+            |seed_liquidity_config| SeedLiquidityConfig {
+                dex: SeedLiquidityDex::Astroport {
+                    factory_addr: seed_liquidity_config.astroport_factory_addr,
+                    pair_type: SeedLiquidityPairType::Xyk {},
+                },
+                paired_base_denom: seed_liquidity_config.paired_base_denom,
+                paired_base_denom_amount: seed_liquidity_config
+                    .paired_base_denom_amount,
+                lock_mode: LiquidityLockMode::Creator {},
+                pool_supply_in_denom: None,
+            },
+        ),
+        bonding_curve_config: None,
+    };
+    CONFIG.save(storage, &config)?;
+
+    if config.admin_addr.is_none() {
+        return Ok(vec![]);
+    }
+    Ok(register_before_send_hook(
+        storage,
+        &config.denom_metadata.base,
+        &env.contract.address,
+    )?)
+}
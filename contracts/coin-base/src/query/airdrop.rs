@@ -0,0 +1,30 @@
+use crate::state::{AIRDROP, AIRDROP_CLAIMED_BITMAP};
+use cosmwasm_std::{StdResult, Storage};
+use shared_pkg::airdrop::{
+    airdrop_claim_bit, AirdropClaimedResponse, AirdropResponse,
+};
+
+pub fn query_airdrop(storage: &dyn Storage) -> StdResult<AirdropResponse> {
+    Ok(AirdropResponse {
+        airdrop: AIRDROP.may_load(storage)?,
+    })
+}
+
+/// Whether leaf index of the latest airdrop is claimed
+pub fn query_airdrop_claimed(
+    storage: &dyn Storage,
+    index: u32,
+) -> StdResult<AirdropClaimedResponse> {
+    let claimed = match AIRDROP.may_load(storage)? {
+        Some(airdrop) => {
+            let (word_index, bit) = airdrop_claim_bit(index);
+            AIRDROP_CLAIMED_BITMAP
+                .may_load(storage, (airdrop.round, word_index))?
+                .unwrap_or_default()
+                & bit
+                != 0
+        }
+        None => false,
+    };
+    Ok(AirdropClaimedResponse { claimed })
+}
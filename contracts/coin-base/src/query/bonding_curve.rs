@@ -0,0 +1,83 @@
+use crate::{
+    state::{BONDING_CURVE_STATE, DENOM_EXPONENT},
+    util::bonding_curve::{
+        amount_out_for_paid, market_cap, return_for_sell, spot_price,
+    },
+};
+use coin::{
+    bonding_curve::BondingCurveConfig,
+    config::Config,
+    msg::{
+        BondingCurveResponse, SimulateBuyOnBondingCurveResponse,
+        SimulateSellOnBondingCurveResponse,
+    },
+};
+use cosmwasm_std::{StdError, StdResult, Storage, Uint128};
+
+fn load_bonding_curve_config(
+    config: &Config,
+) -> StdResult<&BondingCurveConfig> {
+    config.bonding_curve_config.as_ref().ok_or_else(|| {
+        StdError::generic_err("coin is not launched on a bonding curve")
+    })
+}
+
+pub fn query_bonding_curve(
+    storage: &dyn Storage,
+    config: &Config,
+) -> StdResult<BondingCurveResponse> {
+    let bonding_curve_config = load_bonding_curve_config(config)?;
+    let bonding_curve_state = BONDING_CURVE_STATE.load(storage)?;
+    let curve = &bonding_curve_config.params.curve;
+    Ok(BondingCurveResponse {
+        bonding_curve_config: bonding_curve_config.clone(),
+        spot_price: spot_price(
+            curve,
+            bonding_curve_state.supply_sold_in_base_denom,
+        )?,
+        market_cap: market_cap(
+            curve,
+            bonding_curve_state.supply_sold_in_base_denom,
+            config.max_supply_in_base_denom,
+        )?,
+        bonding_curve_state,
+    })
+}
+
+pub fn query_simulate_buy_on_bonding_curve(
+    storage: &dyn Storage,
+    config: &Config,
+    paired_amount: Uint128,
+) -> StdResult<SimulateBuyOnBondingCurveResponse> {
+    let bonding_curve_config = load_bonding_curve_config(config)?;
+    let state = BONDING_CURVE_STATE.load(storage)?;
+    let curve_supply_in_base_denom =
+        bonding_curve_config.params.curve_supply_in_denom
+            * Uint128::from(10u128.pow(DENOM_EXPONENT));
+    let (amount_out, paired_amount_spent) = amount_out_for_paid(
+        &bonding_curve_config.params.curve,
+        state.supply_sold_in_base_denom,
+        curve_supply_in_base_denom - state.supply_sold_in_base_denom,
+        paired_amount,
+    )?;
+    Ok(SimulateBuyOnBondingCurveResponse {
+        amount_out,
+        paired_amount_spent,
+    })
+}
+
+pub fn query_simulate_sell_on_bonding_curve(
+    storage: &dyn Storage,
+    config: &Config,
+    amount: Uint128,
+) -> StdResult<SimulateSellOnBondingCurveResponse> {
+    let bonding_curve_config = load_bonding_curve_config(config)?;
+    let state = BONDING_CURVE_STATE.load(storage)?;
+    Ok(SimulateSellOnBondingCurveResponse {
+        paired_amount_out: return_for_sell(
+            &bonding_curve_config.params.curve,
+            state.supply_sold_in_base_denom,
+            amount,
+        )?,
+    })
+}
@@ -0,0 +1,42 @@
+use crate::{
+    state::{DEFAULT_LIMIT, FROZEN_ACCOUNTS, MAX_LIMIT},
+    util::freeze::is_globally_paused,
+};
+use coin::{
+    config::Config,
+    msg::{FrozenAccountsResponse, IsFrozenResponse},
+};
+use cosmwasm_std::{Addr, Api, Order, StdResult, Storage};
+use cw_storage_plus::Bound;
+use cw_utils::maybe_addr;
+
+pub fn query_is_frozen(
+    storage: &dyn Storage,
+    config: &Config,
+    addr: &Addr,
+) -> StdResult<IsFrozenResponse> {
+    Ok(IsFrozenResponse {
+        is_frozen: config.admin_addr.is_some()
+            && FROZEN_ACCOUNTS.has(storage, addr),
+        globally_paused: is_globally_paused(storage, config)?,
+    })
+}
+
+pub fn query_frozen_accounts(
+    api: &dyn Api,
+    storage: &dyn Storage,
+    config: &Config,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<FrozenAccountsResponse> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let start_addr = maybe_addr(api, start_after)?;
+    let start = start_addr.as_ref().map(Bound::exclusive);
+    Ok(FrozenAccountsResponse {
+        addrs: FROZEN_ACCOUNTS
+            .keys(storage, start, None, Order::Ascending)
+            .take(limit)
+            .collect::<StdResult<Vec<Addr>>>()?,
+        globally_paused: is_globally_paused(storage, config)?,
+    })
+}
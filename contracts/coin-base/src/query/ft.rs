@@ -1,8 +1,9 @@
+use crate::state::TOTAL_BURNED;
 use coin::{
     config::Config,
     msg::{BalanceResponse, SupplyResponse},
 };
-use cosmwasm_std::{Addr, QuerierWrapper, StdResult};
+use cosmwasm_std::{Addr, QuerierWrapper, StdResult, Storage};
 
 pub fn query_balance(
     querier: QuerierWrapper,
@@ -17,6 +18,7 @@ pub fn query_balance(
 }
 
 pub fn query_supply(
+    storage: &dyn Storage,
     querier: QuerierWrapper,
     config: &Config,
     base_denom: &str,
@@ -26,5 +28,8 @@ pub fn query_supply(
     Ok(SupplyResponse {
         current_supply_in_base_denom,
         max_supply_in_base_denom,
+        total_burned_in_base_denom: TOTAL_BURNED
+            .may_load(storage)?
+            .unwrap_or_default(),
     })
 }
@@ -0,0 +1,27 @@
+use crate::{
+    state::{LAUNCH_PROTECTION, LAUNCH_PROTECTION_ADDRS},
+    util::launch_protection::is_launch_protection_active,
+};
+use coin::msg::{LaunchProtectionAddr, LaunchProtectionResponse};
+use cosmwasm_std::{BlockInfo, Order, StdError, StdResult, Storage};
+
+pub fn query_launch_protection(
+    storage: &dyn Storage,
+    block: &BlockInfo,
+) -> StdResult<LaunchProtectionResponse> {
+    let launch_protection =
+        LAUNCH_PROTECTION.may_load(storage)?.ok_or_else(|| {
+            StdError::generic_err("coin is launched without launch protection")
+        })?;
+    Ok(LaunchProtectionResponse {
+        trading_started: block.time >= launch_protection.trading_start_time,
+        active: is_launch_protection_active(&launch_protection, block),
+        addrs: LAUNCH_PROTECTION_ADDRS
+            .range(storage, None, None, Order::Ascending)
+            .map(|item| {
+                item.map(|(addr, kind)| LaunchProtectionAddr { addr, kind })
+            })
+            .collect::<StdResult<Vec<_>>>()?,
+        launch_protection,
+    })
+}
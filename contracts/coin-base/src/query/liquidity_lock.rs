@@ -0,0 +1,31 @@
+use crate::{
+    state::SEED_LIQUIDITY_LP,
+    util::liquidity_lock::calculate_withdrawable_lp_amount,
+};
+use coin::{config::Config, msg::LiquidityLockResponse};
+use cosmwasm_std::{BlockInfo, StdError, StdResult, Storage, Uint128};
+
+pub fn query_liquidity_lock(
+    storage: &dyn Storage,
+    block: &BlockInfo,
+    config: &Config,
+) -> StdResult<LiquidityLockResponse> {
+    let lock_mode = config
+        .seed_liquidity_config
+        .as_ref()
+        .ok_or_else(|| {
+            StdError::generic_err("coin is launched without seed liquidity")
+        })?
+        .lock_mode
+        .clone();
+    let seed_liquidity_lp = SEED_LIQUIDITY_LP.may_load(storage)?;
+    let withdrawable_amount = match &seed_liquidity_lp {
+        Some(lp) => calculate_withdrawable_lp_amount(&lock_mode, lp, block),
+        None => Uint128::zero(),
+    };
+    Ok(LiquidityLockResponse {
+        lock_mode,
+        seed_liquidity_lp,
+        withdrawable_amount,
+    })
+}
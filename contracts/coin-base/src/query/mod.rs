@@ -1,2 +1,9 @@
+pub(crate) mod airdrop;
+pub(crate) mod bonding_curve;
 pub(crate) mod config;
+pub(crate) mod freeze;
 pub(crate) mod ft;
+pub(crate) mod launch_protection;
+pub(crate) mod liquidity_lock;
+pub(crate) mod transfer_tax;
+pub(crate) mod vesting;
@@ -0,0 +1,34 @@
+use crate::state::{
+    TAXED_ADDRS, TAX_EXEMPT_ADDRS, TAX_OWED, TAX_UNCOLLECTABLE, TRANSFER_TAX,
+};
+use coin::msg::{TaxOwedResponse, TransferTaxResponse};
+use cosmwasm_std::{Addr, Order, StdError, StdResult, Storage};
+
+pub fn query_transfer_tax(
+    storage: &dyn Storage,
+) -> StdResult<TransferTaxResponse> {
+    let transfer_tax = TRANSFER_TAX
+        .may_load(storage)?
+        .ok_or_else(|| StdError::generic_err("transfer tax is not set"))?;
+    Ok(TransferTaxResponse {
+        transfer_tax,
+        taxed_addrs: TAXED_ADDRS
+            .keys(storage, None, None, Order::Ascending)
+            .collect::<StdResult<Vec<Addr>>>()?,
+        exempt_addrs: TAX_EXEMPT_ADDRS
+            .keys(storage, None, None, Order::Ascending)
+            .collect::<StdResult<Vec<Addr>>>()?,
+        uncollectable_tax: TAX_UNCOLLECTABLE
+            .may_load(storage)?
+            .unwrap_or_default(),
+    })
+}
+
+pub fn query_tax_owed(
+    storage: &dyn Storage,
+    addr: &Addr,
+) -> StdResult<TaxOwedResponse> {
+    Ok(TaxOwedResponse {
+        amount: TAX_OWED.may_load(storage, addr)?.unwrap_or_default(),
+    })
+}
@@ -0,0 +1,22 @@
+use crate::{
+    state::VESTING_SCHEDULES, util::vesting::calculate_claimable_vested_amount,
+};
+use coin::msg::VestingScheduleResponse;
+use cosmwasm_std::{Addr, BlockInfo, StdError, StdResult, Storage};
+
+pub fn query_vesting_schedule(
+    storage: &dyn Storage,
+    block: &BlockInfo,
+    addr: &Addr,
+) -> StdResult<VestingScheduleResponse> {
+    let vesting_schedule =
+        VESTING_SCHEDULES.may_load(storage, addr)?.ok_or_else(|| {
+            StdError::generic_err(format!("{addr} has no vesting allocation"))
+        })?;
+    let claimable_amount =
+        calculate_claimable_vested_amount(&vesting_schedule, block);
+    Ok(VestingScheduleResponse {
+        vesting_schedule,
+        claimable_amount,
+    })
+}
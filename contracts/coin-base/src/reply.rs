@@ -1,95 +1,208 @@
-use crate::{state::CONFIG, util::astroport::provide_seed_liquidity};
-use astroport::{
-    asset::{AssetInfo, PairInfo},
-    factory::QueryMsg::Pair,
+use crate::{
+    contract::REPLY_ID_PROVIDE_SEED_LIQUIDITY,
+    state::{
+        BONDING_CURVE_STATE, CONFIG, PENDING_FORCE_TRANSFER, SEED_LIQUIDITY_LP,
+        SEED_LIQUIDITY_POOL,
+    },
+    util::{
+        airdrop::total_unclaimed_airdrop_amount,
+        astroport::{
+            burn_lp_msg, parse_create_pair_reply, query_lp_balance,
+            query_pair_info,
+        },
+        bonding_curve::provide_bonding_curve_liquidity,
+        dex::{dex_adapter, lp_burn_msg, lp_transfer_msg},
+        launch_protection::add_pool_if_launch_protection_set,
+        transfer_tax::tax_pair_if_transfer_tax_set,
+        vesting::total_unclaimed_vesting_amount,
+    },
+};
+use astroport::{asset::PairInfo, pair::QueryMsg::Pair};
+use coin::{
+    dex::SeedLiquidityDex,
+    liquidity_lock::{LiquidityLockMode, SeedLiquidityLp},
 };
 use cosmwasm_std::{
-    Addr, QuerierWrapper, Reply, Response, Storage, SubMsgResult,
+    Addr, Binary, QuerierWrapper, Reply, ReplyOn, Response, StdError, Storage,
+    SubMsg, Uint128,
 };
 use shared_pkg::error::ContractError;
 
 pub fn create_pair_reply(
     querier: QuerierWrapper,
-    storage: &dyn Storage,
+    storage: &mut dyn Storage,
     msg: Reply,
     contract_addr: &Addr,
 ) -> Result<Response, ContractError> {
-    let resp = match msg.result {
-        SubMsgResult::Ok(resp) => resp,
-        SubMsgResult::Err(err) => {
-            return Err(ContractError::ErrorCreatePairInAstroport { err })
-        }
+    let config = CONFIG.load(storage)?;
+
+    if let Some(bonding_curve_config) = config.bonding_curve_config {
+        let base_denom = parse_create_pair_reply(msg.result)?;
+        let base_denom = base_denom.as_str();
+        let pair_addr = query_pair_info(
+            querier,
+            &bonding_curve_config.astroport_factory_addr,
+            &bonding_curve_config.paired_base_denom,
+            base_denom,
+        )?
+        .contract_addr;
+        let mut state = BONDING_CURVE_STATE.load(storage)?;
+        // remaining supply minted at migration, coins bought on the curve are already sent to buyers
+        // unclaimed vesting coin stays in this contract
+        let remaining_supply =
+            querier.query_balance(contract_addr, base_denom)?.amount
+                - total_unclaimed_vesting_amount(storage)?;
+        let (provide_liquidity_submsg, attributes) =
+            provide_bonding_curve_liquidity(
+                storage,
+                &bonding_curve_config,
+                &mut state,
+                contract_addr,
+                base_denom,
+                pair_addr,
+                remaining_supply,
+            )?;
+        BONDING_CURVE_STATE.save(storage, &state)?;
+        return Ok(Response::new()
+            .add_submessage(provide_liquidity_submsg)
+            .add_attributes(attributes));
+    }
+
+    let seed_liquidity_config = match config.seed_liquidity_config {
+        Some(cfg) => cfg,
+        None => return Err(ContractError::CannotFindSeedLiquidityConfig {}),
     };
+    let base_denom = config.denom_metadata.base.as_str();
+    let adapter = dex_adapter(&seed_liquidity_config.dex);
+    let pool = adapter.created_pool(
+        querier,
+        msg.result,
+        &seed_liquidity_config.paired_base_denom,
+        base_denom,
+    )?;
+    SEED_LIQUIDITY_POOL.save(storage, &pool)?;
+    // Osmosis pools have no contract address, admin adds their module account with SetTaxedAddr and SetLaunchProtectionAddr
+    if let SeedLiquidityDex::Astroport { .. } = seed_liquidity_config.dex {
+        tax_pair_if_transfer_tax_set(storage, &Addr::unchecked(&pool))?;
+        add_pool_if_launch_protection_set(storage, &Addr::unchecked(&pool))?;
+    }
 
-    let event =
-        resp.events
-            .iter()
-            .find(|event| {
-                event.attributes.iter().any(|attr| {
-                    attr.key == "action" && attr.value == "create_pair"
-                })
+    // creator supply is already sent at instantiate, vesting and airdrop coin stays in this contract
+    let pool_supply = querier.query_balance(contract_addr, base_denom)?.amount
+        - total_unclaimed_vesting_amount(storage)?
+        - total_unclaimed_airdrop_amount(storage)?;
+    match adapter.provide_liquidity(
+        querier,
+        &pool,
+        contract_addr,
+        &seed_liquidity_config.paired_base_denom,
+        base_denom,
+        seed_liquidity_config.paired_base_denom_amount,
+        pool_supply,
+    )? {
+        // LP goes to this contract first, then it is handled by lock mode in reply
+        Some((
+            provide_seed_liquidity_msg,
+            provide_seed_liquidity_attributes,
+        )) => Ok(Response::new()
+            .add_submessage(SubMsg {
+                id: REPLY_ID_PROVIDE_SEED_LIQUIDITY,
+                msg: provide_seed_liquidity_msg,
+                gas_limit: None,
+                reply_on: ReplyOn::Success,
             })
-            .ok_or({
-                ContractError::CannotFindCreatePairEventFromAstroportReply {}
-            })?;
-    let pair = &event
-        .attributes
-        .iter()
-        .find(|attr| attr.key == "pair")
-        .ok_or(ContractError::CannotFindPairFromAstroportReply {})?
-        .value;
-    // e.g. pair = untrn-base_denom, base_denom is in the format of factory/contract_addr/base_subdenom
-    let tokens = pair.split('-').collect::<Vec<&str>>();
-    let mut maybe_base_denom = None;
-    for token in tokens {
-        if token.contains("factory") {
-            maybe_base_denom = Some(token);
-        }
+            .add_attribute("pool", pool)
+            .add_attributes(provide_seed_liquidity_attributes)),
+        // seed liquidity is provided when creating the pool
+        None => handle_seed_liquidity_lp(querier, storage, contract_addr, None),
     }
+}
 
-    let base_denom = match maybe_base_denom {
-        Some(base_denom) => base_denom,
-        None => {
-            return Err(
-                ContractError::CannotFindCreatedTokenFromAstroportReply {},
-            )
-        }
-    };
+pub fn provide_seed_liquidity_reply(
+    querier: QuerierWrapper,
+    storage: &mut dyn Storage,
+    msg: Reply,
+    contract_addr: &Addr,
+) -> Result<Response, ContractError> {
+    let data = msg
+        .result
+        .into_result()
+        .map_err(StdError::generic_err)?
+        .data;
+    handle_seed_liquidity_lp(querier, storage, contract_addr, data)
+}
 
+/// Send LP received for seed liquidity to creator in Creator mode, otherwise record it and burn it in Burn mode
+fn handle_seed_liquidity_lp(
+    querier: QuerierWrapper,
+    storage: &mut dyn Storage,
+    contract_addr: &Addr,
+    data: Option<Binary>,
+) -> Result<Response, ContractError> {
     let config = CONFIG.load(storage)?;
+    let seed_liquidity_config = config
+        .seed_liquidity_config
+        .ok_or(ContractError::CannotFindSeedLiquidityConfig {})?;
+    let pool = SEED_LIQUIDITY_POOL.load(storage)?;
+    let (lp_token, lp_amount) = dex_adapter(&seed_liquidity_config.dex)
+        .received_lp(querier, &pool, contract_addr, data)?;
+    let resp = Response::new()
+        .add_attribute("pool", pool.clone())
+        .add_attribute("amount", lp_amount);
 
-    let seed_liquidity_config = match config.seed_liquidity_config {
-        Some(cfg) => cfg,
-        None => return Err(ContractError::CannotFindSeedLiquidityConfig {}),
-    };
+    if seed_liquidity_config.lock_mode == (LiquidityLockMode::Creator {}) {
+        return Ok(resp
+            .add_messages(lp_transfer_msg(
+                &lp_token,
+                &config.creator_addr,
+                lp_amount,
+            )?)
+            .add_attribute("action", "send_seed_liquidity_lp_to_creator")
+            .add_attribute("creator", config.creator_addr));
+    }
 
-    let pair: PairInfo = querier.query_wasm_smart(
-        seed_liquidity_config.clone().astroport_factory_addr,
-        &Pair {
-            asset_infos: vec![
-                AssetInfo::NativeToken {
-                    denom: seed_liquidity_config.clone().paired_base_denom,
-                },
-                AssetInfo::NativeToken {
-                    denom: base_denom.to_string(),
-                },
-            ],
+    SEED_LIQUIDITY_LP.save(
+        storage,
+        &SeedLiquidityLp {
+            pool,
+            lp_token: lp_token.clone(),
+            total_amount: lp_amount,
+            withdrawn_amount: Uint128::zero(),
         },
     )?;
-    let pair_addr = pair.contract_addr;
-
-    let total_supply = querier.query_balance(contract_addr, base_denom)?.amount;
-    let (provide_seed_liquidity_msg, provide_seed_liquidity_attributes) =
-        provide_seed_liquidity(
-            seed_liquidity_config.paired_base_denom.as_str(),
-            &pair_addr,
-            base_denom,
-            &config.creator_addr,
-            seed_liquidity_config.paired_base_denom_amount,
-            total_supply,
-        )?;
+    let resp = resp.add_attribute("action", "lock_seed_liquidity_lp");
+    match seed_liquidity_config.lock_mode {
+        LiquidityLockMode::Burn {} => Ok(resp
+            .add_messages(lp_burn_msg(&lp_token, lp_amount)?)
+            .add_attribute("burned", "true")),
+        _ => Ok(resp),
+    }
+}
 
+/// Burn all LP received from migrating bonding curve liquidity
+pub fn provide_bonding_curve_liquidity_reply(
+    querier: QuerierWrapper,
+    storage: &dyn Storage,
+    contract_addr: &Addr,
+) -> Result<Response, ContractError> {
+    let state = BONDING_CURVE_STATE.load(storage)?;
+    let pair_addr = state
+        .pair_addr
+        .ok_or(ContractError::CannotFindPairFromAstroportReply {})?;
+    let pair_info: PairInfo = querier.query_wasm_smart(&pair_addr, &Pair {})?;
+    let lp_amount =
+        query_lp_balance(querier, &pair_info.liquidity_token, contract_addr)?;
     Ok(Response::new()
-        .add_message(provide_seed_liquidity_msg)
-        .add_attributes(provide_seed_liquidity_attributes))
+        .add_message(burn_lp_msg(&pair_info.liquidity_token, lp_amount)?)
+        .add_attribute("action", "burn_bonding_curve_lp")
+        .add_attribute("lp_token", pair_info.liquidity_token)
+        .add_attribute("amount", lp_amount))
+}
+
+/// Force transfer went through, drop the pending record if before send hook did not consume it
+pub fn force_transfer_reply(
+    storage: &mut dyn Storage,
+) -> Result<Response, ContractError> {
+    PENDING_FORCE_TRANSFER.remove(storage);
+    Ok(Response::new())
 }
@@ -1,6 +1,77 @@
-use coin::config::Config;
-use cw_storage_plus::Item;
+use coin::{
+    bonding_curve::BondingCurveState,
+    config::Config,
+    launch_protection::{LaunchProtection, LaunchProtectionAddrKind},
+    liquidity_lock::SeedLiquidityLp,
+    transfer_tax::TransferTax,
+    vesting::VestingSchedule,
+};
+use cosmwasm_std::{Addr, Empty, Uint128};
+use cw_storage_plus::{Item, Map};
+use shared_pkg::airdrop::Airdrop;
 
 pub const DENOM_EXPONENT: u32 = 6;
+pub const DEFAULT_LIMIT: u32 = 10;
+pub const MAX_LIMIT: u32 = 100;
 
 pub const CONFIG: Item<Config> = Item::new("CONFIG");
+
+/// In base denom, every burn except selling back to the bonding curve adds to it
+pub const TOTAL_BURNED: Item<Uint128> = Item::new("TOTAL_BURNED");
+
+/// Only exists if coin is launched on a bonding curve
+pub const BONDING_CURVE_STATE: Item<BondingCurveState> =
+    Item::new("BONDING_CURVE_STATE");
+
+/// Pool seed liquidity is provided to, pair address on Astroport and pool id on Osmosis
+pub const SEED_LIQUIDITY_POOL: Item<String> = Item::new("SEED_LIQUIDITY_POOL");
+
+/// Only exists after seed liquidity is provided with LP held by or burned from this contract
+pub const SEED_LIQUIDITY_LP: Item<SeedLiquidityLp> =
+    Item::new("SEED_LIQUIDITY_LP");
+
+/// Key is recipient, coin is held by this contract until claimed
+pub const VESTING_SCHEDULES: Map<&Addr, VestingSchedule> =
+    Map::new("VESTING_SCHEDULES");
+
+/// Latest airdrop attached at instantiate or by admin
+pub const AIRDROP: Item<Airdrop> = Item::new("AIRDROP");
+/// Key is (airdrop round, word index), each bit marks a claimed leaf index
+pub const AIRDROP_CLAIMED_BITMAP: Map<(u64, u32), u64> =
+    Map::new("AIRDROP_CLAIMED_BITMAP");
+
+/// Set once this contract is registered as before send hook of the base denom
+pub const BEFORE_SEND_HOOK_REGISTERED: Item<bool> =
+    Item::new("BEFORE_SEND_HOOK_REGISTERED");
+
+/// Only exists once transfer tax is set, before send hook is registered then
+pub const TRANSFER_TAX: Item<TransferTax> = Item::new("TRANSFER_TAX");
+/// Coin moving out of these is a buy, into these is a sell, e.g. DEX pairs
+pub const TAXED_ADDRS: Map<&Addr, Empty> = Map::new("TAXED_ADDRS");
+/// Transfers from or to these are never taxed
+pub const TAX_EXEMPT_ADDRS: Map<&Addr, Empty> = Map::new("TAX_EXEMPT_ADDRS");
+/// Tax owed in base denom recorded by before send hook, settled by SweepTax
+/// Before send hook blocks sends that would leave less than this in the balance
+pub const TAX_OWED: Map<&Addr, Uint128> = Map::new("TAX_OWED");
+/// Tax written off by SweepTax because the payer's balance could not cover it
+/// Only tax recorded before sends were blocked on owed tax can end up here
+pub const TAX_UNCOLLECTABLE: Item<Uint128> = Item::new("TAX_UNCOLLECTABLE");
+
+/// Only exists if coin is launched with launch protection, kept after it expires
+pub const LAUNCH_PROTECTION: Item<LaunchProtection> =
+    Item::new("LAUNCH_PROTECTION");
+/// Addresses exempted from launch protection or treated as pools
+pub const LAUNCH_PROTECTION_ADDRS: Map<&Addr, LaunchProtectionAddrKind> =
+    Map::new("LAUNCH_PROTECTION_ADDRS");
+/// Block height of the last limited transfer of an address, used for cooldown
+pub const LAST_TRANSFER_HEIGHT: Map<&Addr, u64> =
+    Map::new("LAST_TRANSFER_HEIGHT");
+
+/// Sends from or to these are blocked, only mutable coins can freeze
+pub const FROZEN_ACCOUNTS: Map<&Addr, Empty> = Map::new("FROZEN_ACCOUNTS");
+/// If true, all sends except into this contract are blocked
+pub const GLOBAL_PAUSE: Item<bool> = Item::new("GLOBAL_PAUSE");
+/// Admin force transfer in flight, before send hook lets it through freezes and global pause
+/// Set right before the force transfer and cleared in its reply, never outlives the execute
+pub const PENDING_FORCE_TRANSFER: Item<(Addr, Addr)> =
+    Item::new("PENDING_FORCE_TRANSFER");
@@ -0,0 +1,49 @@
+use crate::{
+    state::LAUNCH_PROTECTION,
+    util::launch_protection::{
+        enforce_launch_protection, is_launch_protection_active,
+    },
+};
+use cosmwasm_std::{
+    Addr, BlockInfo, QuerierWrapper, Response, Storage, Uint128,
+};
+use shared_pkg::error::ContractError;
+
+/// Erroring blocks the send, nothing is blocked once launch protection expires
+pub fn block_before_send(
+    storage: &mut dyn Storage,
+    querier: QuerierWrapper,
+    block: &BlockInfo,
+    base_denom: &str,
+    contract_addr: &Addr,
+    amount: Uint128,
+    denom: &str,
+    from_addr: &Addr,
+    to_addr: &Addr,
+) -> Result<Response, ContractError> {
+    let launch_protection = match LAUNCH_PROTECTION.may_load(storage)? {
+        Some(launch_protection)
+            if denom == base_denom
+                && is_launch_protection_active(&launch_protection, block) =>
+        {
+            launch_protection
+        }
+        _ => return Ok(Response::new()),
+    };
+    enforce_launch_protection(
+        storage,
+        querier,
+        block,
+        &launch_protection,
+        base_denom,
+        contract_addr,
+        from_addr,
+        to_addr,
+        amount,
+    )?;
+    Ok(Response::new()
+        .add_attribute("action", "block_before_send")
+        .add_attribute("from", from_addr)
+        .add_attribute("to", to_addr)
+        .add_attribute("amount", amount))
+}
@@ -0,0 +1,2 @@
+pub(crate) mod launch_protection;
+pub(crate) mod transfer_tax;
@@ -0,0 +1,228 @@
+use crate::{
+    state::{TAX_OWED, TRANSFER_TAX},
+    util::transfer_tax::calculate_transfer_tax,
+};
+use cosmwasm_std::{
+    Addr, Attribute, QuerierWrapper, StdError, Storage, Uint128,
+};
+use shared_pkg::error::ContractError;
+
+/// Hook cannot move funds, tax is recorded as owed and swept later with force transfer
+/// Token factory calls this after BlockBeforeSend let the send through
+pub fn track_tax_before_send(
+    storage: &mut dyn Storage,
+    base_denom: &str,
+    amount: Uint128,
+    denom: &str,
+    from_addr: &Addr,
+    to_addr: &Addr,
+) -> Result<Vec<Attribute>, ContractError> {
+    let transfer_tax = match TRANSFER_TAX.may_load(storage)? {
+        Some(transfer_tax) if denom == base_denom => transfer_tax,
+        _ => return Ok(vec![]),
+    };
+    let (payer_addr, tax) = match calculate_transfer_tax(
+        storage,
+        &transfer_tax,
+        from_addr,
+        to_addr,
+        amount,
+    )? {
+        Some(payer_and_tax) => payer_and_tax,
+        None => return Ok(vec![]),
+    };
+    let owed =
+        TAX_OWED.may_load(storage, &payer_addr)?.unwrap_or_default() + tax;
+    TAX_OWED.save(storage, &payer_addr, &owed)?;
+    Ok(vec![
+        Attribute::new("tax_payer", payer_addr),
+        Attribute::new("tax", tax),
+        Attribute::new("tax_owed", owed),
+    ])
+}
+
+/// Token factory ignores errors of TrackBeforeSend, so only this hook can keep tax collectable
+/// Erroring blocks a send that would leave the sender with something but less than it owes
+/// A send of the whole balance goes through, sweep writes off what it cannot collect
+pub fn block_tax_before_send(
+    storage: &dyn Storage,
+    querier: QuerierWrapper,
+    base_denom: &str,
+    amount: Uint128,
+    denom: &str,
+    from_addr: &Addr,
+    to_addr: &Addr,
+) -> Result<(), ContractError> {
+    let transfer_tax = match TRANSFER_TAX.may_load(storage)? {
+        Some(transfer_tax) if denom == base_denom => transfer_tax,
+        _ => return Ok(()),
+    };
+    // sell tax of this send is owed by the sender too, it is recorded right after this hook
+    let send_tax = match calculate_transfer_tax(
+        storage,
+        &transfer_tax,
+        from_addr,
+        to_addr,
+        amount,
+    )? {
+        Some((payer_addr, tax)) if payer_addr == *from_addr => tax,
+        _ => Uint128::zero(),
+    };
+    let sender_owed =
+        TAX_OWED.may_load(storage, from_addr)?.unwrap_or_default() + send_tax;
+    if sender_owed.is_zero() {
+        return Ok(());
+    }
+    let balance = querier.query_balance(from_addr, base_denom)?.amount;
+    let balance_after = balance.checked_sub(amount).map_err(StdError::from)?;
+    if !balance_after.is_zero() && balance_after < sender_owed {
+        return Err(ContractError::TransferLeavesTaxUnpaid {
+            owed: sender_owed,
+            balance_after,
+        });
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{state::TAXED_ADDRS, util::transfer_tax::save_transfer_tax};
+    use coin::transfer_tax::TaxDestination;
+    use cosmwasm_std::{
+        coins,
+        testing::{
+            mock_dependencies_with_balances, MockApi, MockQuerier, MockStorage,
+        },
+        Empty, OwnedDeps,
+    };
+
+    const BASE_DENOM: &str = "factory/coin/ucoin";
+
+    fn addr(name: &str) -> Addr {
+        Addr::unchecked(name)
+    }
+
+    /// 5% buy tax and 10% sell tax on the pair, seller holds 1000
+    fn setup() -> OwnedDeps<MockStorage, MockApi, MockQuerier> {
+        let mut deps = mock_dependencies_with_balances(&[(
+            "seller",
+            &coins(1_000, BASE_DENOM),
+        )]);
+        save_transfer_tax(
+            &deps.api,
+            &mut deps.storage,
+            500,
+            1_000,
+            TaxDestination::Burn {},
+            BASE_DENOM,
+            &addr("coin"),
+        )
+        .unwrap();
+        TAXED_ADDRS
+            .save(&mut deps.storage, &addr("pair"), &Empty {})
+            .unwrap();
+        deps
+    }
+
+    fn owed(storage: &dyn Storage, name: &str) -> Uint128 {
+        TAX_OWED
+            .may_load(storage, &addr(name))
+            .unwrap()
+            .unwrap_or_default()
+    }
+
+    #[test]
+    fn buy_and_sell_tax_accrue_to_payer() {
+        let mut deps = setup();
+        let track = |storage: &mut dyn Storage, from: &str, to: &str| {
+            track_tax_before_send(
+                storage,
+                BASE_DENOM,
+                Uint128::new(1_000),
+                BASE_DENOM,
+                &addr(from),
+                &addr(to),
+            )
+            .unwrap()
+        };
+        track(&mut deps.storage, "pair", "buyer");
+        assert_eq!(owed(&deps.storage, "buyer"), Uint128::new(50));
+        track(&mut deps.storage, "pair", "buyer");
+        assert_eq!(owed(&deps.storage, "buyer"), Uint128::new(100));
+        track(&mut deps.storage, "seller", "pair");
+        assert_eq!(owed(&deps.storage, "seller"), Uint128::new(100));
+        // neither side is taxed
+        assert!(track(&mut deps.storage, "seller", "friend").is_empty());
+        // coin contract is always exempt
+        assert!(track(&mut deps.storage, "pair", "coin").is_empty());
+        assert_eq!(owed(&deps.storage, "coin"), Uint128::zero());
+    }
+
+    #[test]
+    fn other_denoms_are_not_taxed() {
+        let mut deps = setup();
+        let attrs = track_tax_before_send(
+            &mut deps.storage,
+            BASE_DENOM,
+            Uint128::new(1_000),
+            "untrn",
+            &addr("pair"),
+            &addr("buyer"),
+        )
+        .unwrap();
+        assert!(attrs.is_empty());
+        assert_eq!(owed(&deps.storage, "buyer"), Uint128::zero());
+    }
+
+    #[test]
+    fn send_must_leave_tax_owed_or_empty_balance() {
+        let mut deps = setup();
+        TAX_OWED
+            .save(&mut deps.storage, &addr("seller"), &Uint128::new(50))
+            .unwrap();
+        let block = |amount: u128, to: &str| {
+            block_tax_before_send(
+                &deps.storage,
+                QuerierWrapper::new(&deps.querier),
+                BASE_DENOM,
+                Uint128::new(amount),
+                BASE_DENOM,
+                &addr("seller"),
+                &addr(to),
+            )
+        };
+        block(950, "friend").unwrap();
+        assert!(matches!(
+            block(951, "friend").unwrap_err(),
+            ContractError::TransferLeavesTaxUnpaid { owed, balance_after }
+                if owed == Uint128::new(50) && balance_after == Uint128::new(49)
+        ));
+        // selling 500 adds 50 sell tax, 500 left covers 100 owed
+        block(500, "pair").unwrap();
+        // selling 910 adds 91 sell tax, 90 left does not cover 141 owed
+        assert!(matches!(
+            block(910, "pair").unwrap_err(),
+            ContractError::TransferLeavesTaxUnpaid { owed, balance_after }
+                if owed == Uint128::new(141) && balance_after == Uint128::new(90)
+        ));
+        // whole balance can always be sent, sweep writes off the tax
+        block(1_000, "friend").unwrap();
+        block(1_000, "pair").unwrap();
+    }
+
+    #[test]
+    fn send_is_not_blocked_without_tax_owed() {
+        let deps = setup();
+        block_tax_before_send(
+            &deps.storage,
+            QuerierWrapper::new(&deps.querier),
+            BASE_DENOM,
+            Uint128::new(999),
+            BASE_DENOM,
+            &addr("seller"),
+            &addr("friend"),
+        )
+        .unwrap();
+    }
+}
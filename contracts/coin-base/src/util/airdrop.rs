@@ -0,0 +1,51 @@
+use crate::state::AIRDROP;
+use cosmwasm_std::{
+    Addr, Api, BlockInfo, CosmosMsg, StdResult, Storage, Uint128,
+};
+use osmosis_std::types::{
+    cosmos::base::v1beta1::Coin as SdkCoin,
+    osmosis::tokenfactory::v1beta1::MsgMint,
+};
+use shared_pkg::{
+    airdrop::{airdrop_unclaimed_amount, new_airdrop, Airdrop, AirdropConfig},
+    error::ContractError,
+};
+
+/// Save new airdrop, its total amount is minted to and held by this contract
+pub fn attach_airdrop(
+    api: &dyn Api,
+    storage: &mut dyn Storage,
+    block: &BlockInfo,
+    airdrop_config: AirdropConfig,
+    base_denom: &str,
+    contract_addr: &Addr,
+) -> Result<(Airdrop, CosmosMsg), ContractError> {
+    let round = match AIRDROP.may_load(storage)? {
+        Some(airdrop) if !airdrop.closed => {
+            return Err(ContractError::AirdropAlreadyActive {})
+        }
+        Some(airdrop) => airdrop.round + 1,
+        None => 1,
+    };
+    let airdrop = new_airdrop(api, block, airdrop_config, round)?;
+    AIRDROP.save(storage, &airdrop)?;
+    let mint_msg = MsgMint {
+        sender: contract_addr.to_string(),
+        amount: Some(SdkCoin {
+            amount: airdrop.total_amount.to_string(),
+            denom: base_denom.to_string(),
+        }),
+        mint_to_address: contract_addr.to_string(),
+    };
+    Ok((airdrop, mint_msg.into()))
+}
+
+/// Airdrop coin still held by this contract, it must not be deposited to a pool
+pub fn total_unclaimed_airdrop_amount(
+    storage: &dyn Storage,
+) -> StdResult<Uint128> {
+    Ok(AIRDROP
+        .may_load(storage)?
+        .map(|airdrop| airdrop_unclaimed_amount(&airdrop))
+        .unwrap_or_default())
+}
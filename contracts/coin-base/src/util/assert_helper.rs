@@ -1,4 +1,6 @@
-use cosmwasm_std::{Addr, Uint128};
+use crate::state::BONDING_CURVE_STATE;
+use coin::bonding_curve::BondingCurvePhase;
+use cosmwasm_std::{Addr, Storage, Uint128};
 use shared_pkg::error::ContractError;
 
 pub fn assert_only_admin_can_call_this_function(
@@ -37,3 +39,14 @@ pub fn assert_max_base_denom_supply_not_reached(
     }
     Ok(())
 }
+
+pub fn assert_bonding_curve_migrated(
+    storage: &dyn Storage,
+) -> Result<(), ContractError> {
+    match BONDING_CURVE_STATE.may_load(storage)? {
+        Some(state) if state.phase != (BondingCurvePhase::Migrated {}) => {
+            Err(ContractError::CannotMintBeforeBondingCurveMigrates {})
+        }
+        _ => Ok(()),
+    }
+}
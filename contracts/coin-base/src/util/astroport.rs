@@ -1,26 +1,166 @@
-use crate::contract::REPLY_ID_CREATE_PAIR;
+use crate::{contract::REPLY_ID_CREATE_PAIR, util::dex::DexAdapter};
 use astroport::{
-    asset::{Asset, AssetInfo},
-    factory::{ExecuteMsg::CreatePair, PairType},
-    pair::ExecuteMsg::ProvideLiquidity,
+    asset::{Asset, AssetInfo, PairInfo},
+    factory::{ExecuteMsg::CreatePair, PairType, QueryMsg::Pair},
+    pair::{
+        ExecuteMsg::ProvideLiquidity, PoolResponse, QueryMsg as PairQueryMsg,
+        StablePoolParams,
+    },
 };
+use coin::dex::{SeedLiquidityLpToken, SeedLiquidityPairType};
 use cosmwasm_std::{
-    coin, to_json_binary, Addr, Attribute, CosmosMsg, ReplyOn, SubMsg, Uint128,
-    WasmMsg,
+    coin, to_json_binary, Addr, Attribute, Binary, CosmosMsg, QuerierWrapper,
+    ReplyOn, StdResult, SubMsg, SubMsgResult, Uint128, WasmMsg,
 };
+use cw20::{BalanceResponse, Cw20ExecuteMsg, Cw20QueryMsg};
 use shared_pkg::error::ContractError;
 
+pub struct AstroportAdapter<'a> {
+    pub factory_addr: &'a str,
+    pub pair_type: &'a SeedLiquidityPairType,
+}
+
+impl DexAdapter for AstroportAdapter<'_> {
+    fn create_pool(
+        &self,
+        _querier: QuerierWrapper,
+        _contract_addr: &Addr,
+        paired_base_denom: &str,
+        base_denom: &str,
+        _paired_base_denom_amount: Uint128,
+        _pool_supply: Uint128,
+    ) -> Result<(SubMsg, Vec<Attribute>), ContractError> {
+        create_pair(
+            &Addr::unchecked(self.factory_addr),
+            paired_base_denom,
+            base_denom,
+            self.pair_type,
+        )
+    }
+
+    fn created_pool(
+        &self,
+        querier: QuerierWrapper,
+        result: SubMsgResult,
+        paired_base_denom: &str,
+        base_denom: &str,
+    ) -> Result<String, ContractError> {
+        parse_create_pair_reply(result)?;
+        let pair_info = query_pair_info(
+            querier,
+            self.factory_addr,
+            paired_base_denom,
+            base_denom,
+        )?;
+        Ok(pair_info.contract_addr.to_string())
+    }
+
+    fn provide_liquidity(
+        &self,
+        _querier: QuerierWrapper,
+        pool: &str,
+        contract_addr: &Addr,
+        paired_base_denom: &str,
+        base_denom: &str,
+        paired_base_denom_amount: Uint128,
+        pool_supply: Uint128,
+    ) -> Result<Option<(CosmosMsg, Vec<Attribute>)>, ContractError> {
+        let (msg, attrs) = provide_seed_liquidity(
+            paired_base_denom,
+            &Addr::unchecked(pool),
+            base_denom,
+            contract_addr,
+            paired_base_denom_amount,
+            pool_supply,
+        )?;
+        Ok(Some((msg, attrs)))
+    }
+
+    fn received_lp(
+        &self,
+        querier: QuerierWrapper,
+        pool: &str,
+        contract_addr: &Addr,
+        _data: Option<Binary>,
+    ) -> Result<(SeedLiquidityLpToken, Uint128), ContractError> {
+        let pair_info: PairInfo =
+            querier.query_wasm_smart(pool, &PairQueryMsg::Pair {})?;
+        let lp_amount = query_lp_balance(
+            querier,
+            &pair_info.liquidity_token,
+            contract_addr,
+        )?;
+        Ok((
+            SeedLiquidityLpToken::Cw20 {
+                addr: pair_info.liquidity_token,
+            },
+            lp_amount,
+        ))
+    }
+}
+
+/// Returns base denom of created token in the pair
+pub fn parse_create_pair_reply(
+    result: SubMsgResult,
+) -> Result<String, ContractError> {
+    let resp = match result {
+        SubMsgResult::Ok(resp) => resp,
+        SubMsgResult::Err(err) => {
+            return Err(ContractError::ErrorCreatePairInAstroport { err })
+        }
+    };
+
+    let event =
+        resp.events
+            .iter()
+            .find(|event| {
+                event.attributes.iter().any(|attr| {
+                    attr.key == "action" && attr.value == "create_pair"
+                })
+            })
+            .ok_or({
+                ContractError::CannotFindCreatePairEventFromAstroportReply {}
+            })?;
+    let pair = &event
+        .attributes
+        .iter()
+        .find(|attr| attr.key == "pair")
+        .ok_or(ContractError::CannotFindPairFromAstroportReply {})?
+        .value;
+    // e.g. pair = untrn-base_denom, base_denom is in the format of factory/contract_addr/base_subdenom
+    pair.split('-')
+        .rfind(|token| token.contains("factory"))
+        .map(|base_denom| base_denom.to_string())
+        .ok_or(ContractError::CannotFindCreatedTokenFromAstroportReply {})
+}
+
 pub fn create_pair(
     astroport_factory_addr: &Addr,
     chain_base_denom: &str,
     base_denom: &str,
+    pair_type: &SeedLiquidityPairType,
 ) -> Result<(SubMsg, Vec<Attribute>), ContractError> {
+    let (astroport_pair_type, init_params) = match pair_type {
+        SeedLiquidityPairType::Xyk {} => (PairType::Xyk {}, None),
+        SeedLiquidityPairType::Stable { amp } => (
+            PairType::Stable {},
+            Some(to_json_binary(&StablePoolParams {
+                amp: *amp,
+                owner: None,
+            })?),
+        ),
+        // PCL is registered as a custom pair type in Astroport factory
+        SeedLiquidityPairType::Concentrated { params } => (
+            PairType::Custom("concentrated".to_string()),
+            Some(to_json_binary(params)?),
+        ),
+    };
     let msg: SubMsg = SubMsg {
         id: REPLY_ID_CREATE_PAIR,
         msg: CosmosMsg::Wasm(WasmMsg::Execute {
             contract_addr: astroport_factory_addr.to_string(),
             msg: to_json_binary(&CreatePair {
-                pair_type: PairType::Xyk {},
+                pair_type: astroport_pair_type.clone(),
                 asset_infos: vec![
                     AssetInfo::NativeToken {
                         denom: chain_base_denom.to_string(),
@@ -29,7 +169,7 @@ pub fn create_pair(
                         denom: base_denom.to_string(),
                     },
                 ],
-                init_params: None,
+                init_params,
             })?,
             funds: vec![],
         }),
@@ -41,19 +181,51 @@ pub fn create_pair(
         Attribute::new("action", "create_pair"),
         Attribute::new("token_1", chain_base_denom.to_string()),
         Attribute::new("token_2", base_denom),
+        Attribute::new("pair_type", astroport_pair_type.to_string()),
     ];
     Ok((msg, attrs))
 }
 
-pub fn provide_seed_liquidity(
+pub fn query_pair_info(
+    querier: QuerierWrapper,
+    astroport_factory_addr: &str,
+    paired_base_denom: &str,
+    base_denom: &str,
+) -> StdResult<PairInfo> {
+    querier.query_wasm_smart(
+        astroport_factory_addr,
+        &Pair {
+            asset_infos: vec![
+                AssetInfo::NativeToken {
+                    denom: paired_base_denom.to_string(),
+                },
+                AssetInfo::NativeToken {
+                    denom: base_denom.to_string(),
+                },
+            ],
+        },
+    )
+}
+
+/// Total LP issued by the pair, 0 if nobody has provided liquidity yet
+pub fn query_pair_total_share(
+    querier: QuerierWrapper,
+    pair_addr: &Addr,
+) -> StdResult<Uint128> {
+    let resp: PoolResponse =
+        querier.query_wasm_smart(pair_addr, &PairQueryMsg::Pool {})?;
+    Ok(resp.total_share)
+}
+
+pub fn provide_liquidity_msg(
     paired_base_denom: &str,
     pair_addr: &Addr,
     base_denom: &str,
-    creator_addr: &Addr,
+    receiver_addr: &Addr,
     paired_base_denom_amount: Uint128,
-    total_supply: Uint128,
-) -> Result<(CosmosMsg, Vec<Attribute>), ContractError> {
-    let msg: CosmosMsg = WasmMsg::Execute {
+    base_denom_amount: Uint128,
+) -> StdResult<CosmosMsg> {
+    Ok(WasmMsg::Execute {
         contract_addr: pair_addr.to_string(),
         msg: to_json_binary(&ProvideLiquidity {
             assets: vec![
@@ -67,26 +239,44 @@ pub fn provide_seed_liquidity(
                     info: AssetInfo::NativeToken {
                         denom: base_denom.to_string(),
                     },
-                    amount: total_supply,
+                    amount: base_denom_amount,
                 },
             ],
             slippage_tolerance: None,
             auto_stake: Some(false),
-            receiver: Some(creator_addr.to_string()),
+            receiver: Some(receiver_addr.to_string()),
         })?,
         funds: vec![
             coin(
                 paired_base_denom_amount.u128(),
                 paired_base_denom.to_string(),
             ),
-            coin(total_supply.u128(), base_denom),
+            coin(base_denom_amount.u128(), base_denom),
         ],
     }
-    .into();
+    .into())
+}
+
+pub fn provide_seed_liquidity(
+    paired_base_denom: &str,
+    pair_addr: &Addr,
+    base_denom: &str,
+    lp_receiver_addr: &Addr,
+    paired_base_denom_amount: Uint128,
+    total_supply: Uint128,
+) -> Result<(CosmosMsg, Vec<Attribute>), ContractError> {
+    let msg = provide_liquidity_msg(
+        paired_base_denom,
+        pair_addr,
+        base_denom,
+        lp_receiver_addr,
+        paired_base_denom_amount,
+        total_supply,
+    )?;
 
     let attrs = vec![
         Attribute::new("action", "provide_seed_liquidity"),
-        Attribute::new("creator", creator_addr.to_string()),
+        Attribute::new("lp_receiver", lp_receiver_addr.to_string()),
         Attribute::new("token_1", paired_base_denom.to_string()),
         Attribute::new("token_2", base_denom),
         Attribute::new("token_1_liquidity", paired_base_denom_amount),
@@ -94,3 +284,163 @@ pub fn provide_seed_liquidity(
     ];
     Ok((msg, attrs))
 }
+
+/// LP token of Astroport pair is a CW20 token
+pub fn query_lp_balance(
+    querier: QuerierWrapper,
+    lp_token_addr: &Addr,
+    holder_addr: &Addr,
+) -> StdResult<Uint128> {
+    let resp: BalanceResponse = querier.query_wasm_smart(
+        lp_token_addr,
+        &Cw20QueryMsg::Balance {
+            address: holder_addr.to_string(),
+        },
+    )?;
+    Ok(resp.balance)
+}
+
+pub fn burn_lp_msg(
+    lp_token_addr: &Addr,
+    amount: Uint128,
+) -> StdResult<CosmosMsg> {
+    Ok(WasmMsg::Execute {
+        contract_addr: lp_token_addr.to_string(),
+        msg: to_json_binary(&Cw20ExecuteMsg::Burn { amount })?,
+        funds: vec![],
+    }
+    .into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use astroport::{
+        factory::ExecuteMsg as FactoryExecuteMsg,
+        pair_concentrated::ConcentratedPoolParams,
+    };
+    use cosmwasm_std::{from_json, Event, SubMsgResponse};
+
+    fn create_pair_msg(
+        pair_type: &SeedLiquidityPairType,
+    ) -> (PairType, Option<Binary>) {
+        let (submsg, _) = create_pair(
+            &Addr::unchecked("factory"),
+            "untrn",
+            "factory/coin/ucoin",
+            pair_type,
+        )
+        .unwrap();
+        match submsg.msg {
+            CosmosMsg::Wasm(WasmMsg::Execute { msg, .. }) => {
+                match from_json(msg).unwrap() {
+                    FactoryExecuteMsg::CreatePair {
+                        pair_type,
+                        init_params,
+                        ..
+                    } => (pair_type, init_params),
+                    _ => panic!("unexpected factory msg"),
+                }
+            }
+            _ => panic!("unexpected msg"),
+        }
+    }
+
+    #[test]
+    fn create_pair_maps_pair_type_and_init_params() {
+        assert_eq!(
+            create_pair_msg(&SeedLiquidityPairType::Xyk {}),
+            (PairType::Xyk {}, None)
+        );
+        assert_eq!(
+            create_pair_msg(&SeedLiquidityPairType::Stable { amp: 100 }),
+            (
+                PairType::Stable {},
+                Some(
+                    to_json_binary(&StablePoolParams {
+                        amp: 100,
+                        owner: None,
+                    })
+                    .unwrap()
+                )
+            )
+        );
+        let params: ConcentratedPoolParams = from_json(
+            r#"{"amp":"10","gamma":"0.000145","mid_fee":"0.0026","out_fee":"0.0045","fee_gamma":"0.00023","repeg_profit_threshold":"0.000002","min_price_scale_delta":"0.000146","price_scale":"0.001","ma_half_time":600}"#,
+        )
+        .unwrap();
+        assert_eq!(
+            create_pair_msg(&SeedLiquidityPairType::Concentrated {
+                params: params.clone(),
+            }),
+            (
+                PairType::Custom("concentrated".to_string()),
+                Some(to_json_binary(&params).unwrap())
+            )
+        );
+    }
+
+    fn create_pair_reply(attrs: &[(&str, &str)]) -> SubMsgResult {
+        SubMsgResult::Ok(SubMsgResponse {
+            events: vec![
+                Event::new("wasm").add_attribute("action", "register"),
+                Event::new("wasm").add_attributes(attrs.to_vec()),
+            ],
+            data: None,
+        })
+    }
+
+    #[test]
+    fn create_pair_reply_yields_base_denom() {
+        assert_eq!(
+            parse_create_pair_reply(create_pair_reply(&[
+                ("action", "create_pair"),
+                ("pair", "untrn-factory/coin/ucoin"),
+            ]))
+            .unwrap(),
+            "factory/coin/ucoin"
+        );
+        // paired denom can be a token factory denom too, base denom is last
+        assert_eq!(
+            parse_create_pair_reply(create_pair_reply(&[
+                ("action", "create_pair"),
+                ("pair", "factory/usdc/uusdc-factory/coin/ucoin"),
+            ]))
+            .unwrap(),
+            "factory/coin/ucoin"
+        );
+    }
+
+    #[test]
+    fn create_pair_reply_errors() {
+        assert!(matches!(
+            parse_create_pair_reply(SubMsgResult::Err("failed".to_string()))
+                .unwrap_err(),
+            ContractError::ErrorCreatePairInAstroport { err } if err == "failed"
+        ));
+        assert!(matches!(
+            parse_create_pair_reply(create_pair_reply(&[(
+                "pair",
+                "untrn-factory/coin/ucoin"
+            )]))
+            .unwrap_err(),
+            ContractError::CannotFindCreatePairEventFromAstroportReply {}
+        ));
+        assert!(matches!(
+            parse_create_pair_reply(create_pair_reply(&[(
+                "action",
+                "create_pair"
+            )]))
+            .unwrap_err(),
+            ContractError::CannotFindPairFromAstroportReply {}
+        ));
+        assert!(matches!(
+            parse_create_pair_reply(create_pair_reply(&[
+                ("action", "create_pair"),
+                ("pair", "untrn-uusdc"),
+            ]))
+            .unwrap_err(),
+            ContractError::CannotFindCreatedTokenFromAstroportReply {}
+        ));
+    }
+}
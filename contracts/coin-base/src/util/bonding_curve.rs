@@ -0,0 +1,497 @@
+use crate::{
+    contract::REPLY_ID_PROVIDE_BONDING_CURVE_LIQUIDITY,
+    state::DENOM_EXPONENT,
+    util::{
+        astroport::provide_liquidity_msg,
+        launch_protection::add_pool_if_launch_protection_set,
+        transfer_tax::tax_pair_if_transfer_tax_set,
+    },
+};
+use coin::bonding_curve::{
+    BondingCurve, BondingCurveConfig, BondingCurveLpMode, BondingCurveParams,
+    BondingCurvePhase, BondingCurveState,
+};
+use cosmwasm_std::{
+    Addr, Attribute, Decimal256, ReplyOn, StdError, StdResult, Storage, SubMsg,
+    Uint128, Uint256,
+};
+use shared_pkg::error::ContractError;
+
+fn one_denom_in_base_denom() -> Uint128 {
+    Uint128::from(10u128.pow(DENOM_EXPONENT))
+}
+
+fn convert_base_denom_to_denom(amount_in_base_denom: Uint128) -> Decimal256 {
+    Decimal256::from_ratio(amount_in_base_denom, one_denom_in_base_denom())
+}
+
+fn convert_to_uint128(amount: Uint256) -> StdResult<Uint128> {
+    Uint128::try_from(amount)
+        .map_err(|_| StdError::generic_err("amount does not fit in Uint128"))
+}
+
+/// Number of full steps and (1 + growth_rate) ^ steps of exponential curve at given supply
+fn exponential_step_growth(
+    growth_rate: Decimal256,
+    step_size_in_base_denom: Uint128,
+    supply_in_base_denom: Uint128,
+) -> StdResult<(Uint128, Decimal256)> {
+    let steps = supply_in_base_denom / step_size_in_base_denom;
+    let exponent = u32::try_from(steps.u128())
+        .map_err(|_| StdError::generic_err("too many bonding curve steps"))?;
+    let growth = (Decimal256::one() + growth_rate).checked_pow(exponent)?;
+    Ok((steps, growth))
+}
+
+/// Price in paired base denom per denom when supply_in_base_denom has been sold
+pub fn spot_price(
+    curve: &BondingCurve,
+    supply_in_base_denom: Uint128,
+) -> StdResult<Decimal256> {
+    match curve {
+        BondingCurve::Linear {
+            initial_price,
+            slope,
+        } => {
+            let supply = convert_base_denom_to_denom(supply_in_base_denom);
+            Ok(Decimal256::from(*initial_price)
+                .checked_add(Decimal256::from(*slope).checked_mul(supply)?)?)
+        }
+        BondingCurve::Exponential {
+            initial_price,
+            growth_rate,
+            step_size_in_denom,
+        } => {
+            let (_, growth) = exponential_step_growth(
+                Decimal256::from(*growth_rate),
+                *step_size_in_denom * one_denom_in_base_denom(),
+                supply_in_base_denom,
+            )?;
+            Ok(Decimal256::from(*initial_price).checked_mul(growth)?)
+        }
+    }
+}
+
+/// Paired base denom needed to buy supply_in_base_denom from 0, i.e. area under the curve
+fn reserve_for_supply(
+    curve: &BondingCurve,
+    supply_in_base_denom: Uint128,
+) -> StdResult<Decimal256> {
+    let supply = convert_base_denom_to_denom(supply_in_base_denom);
+    match curve {
+        BondingCurve::Linear {
+            initial_price,
+            slope,
+        } => {
+            // initial_price * supply + slope * supply ^ 2 / 2
+            let flat_part =
+                Decimal256::from(*initial_price).checked_mul(supply)?;
+            let slope_part = Decimal256::from(*slope)
+                .checked_mul(supply)?
+                .checked_mul(supply)?
+                .checked_mul(Decimal256::percent(50))?;
+            Ok(flat_part.checked_add(slope_part)?)
+        }
+        BondingCurve::Exponential {
+            initial_price,
+            growth_rate,
+            step_size_in_denom,
+        } => {
+            let initial_price = Decimal256::from(*initial_price);
+            let growth_rate = Decimal256::from(*growth_rate);
+            let step_size = Decimal256::from_ratio(*step_size_in_denom, 1u128);
+            let (steps, growth) = exponential_step_growth(
+                growth_rate,
+                *step_size_in_denom * one_denom_in_base_denom(),
+                supply_in_base_denom,
+            )?;
+            // full steps form a geometric series
+            // initial_price * step_size * ((1 + growth_rate) ^ steps - 1) / growth_rate
+            let full_steps_part = if growth_rate.is_zero() {
+                initial_price
+                    .checked_mul(step_size)?
+                    .checked_mul(Decimal256::from_ratio(steps, 1u128))?
+            } else {
+                initial_price
+                    .checked_mul(step_size)?
+                    .checked_mul(growth - Decimal256::one())?
+                    .checked_div(growth_rate)
+                    .map_err(|_| {
+                        StdError::generic_err("bonding curve reserve overflow")
+                    })?
+            };
+            // rest of the supply is in the current step at the current price
+            let partial_step_supply = supply.checked_sub(
+                step_size.checked_mul(Decimal256::from_ratio(steps, 1u128))?,
+            )?;
+            let partial_step_part = initial_price
+                .checked_mul(growth)?
+                .checked_mul(partial_step_supply)?;
+            Ok(full_steps_part.checked_add(partial_step_part)?)
+        }
+    }
+}
+
+/// Paired base denom needed to buy amount when supply_sold has been sold, rounded up
+pub fn cost_to_buy(
+    curve: &BondingCurve,
+    supply_sold_in_base_denom: Uint128,
+    amount_in_base_denom: Uint128,
+) -> StdResult<Uint128> {
+    let reserve_before = reserve_for_supply(curve, supply_sold_in_base_denom)?;
+    let reserve_after = reserve_for_supply(
+        curve,
+        supply_sold_in_base_denom.checked_add(amount_in_base_denom)?,
+    )?;
+    convert_to_uint128(
+        reserve_after.checked_sub(reserve_before)?.to_uint_ceil(),
+    )
+}
+
+/// Paired base denom returned for selling amount when supply_sold has been sold, rounded down
+pub fn return_for_sell(
+    curve: &BondingCurve,
+    supply_sold_in_base_denom: Uint128,
+    amount_in_base_denom: Uint128,
+) -> StdResult<Uint128> {
+    let reserve_before = reserve_for_supply(curve, supply_sold_in_base_denom)?;
+    let reserve_after = reserve_for_supply(
+        curve,
+        supply_sold_in_base_denom.checked_sub(amount_in_base_denom)?,
+    )?;
+    convert_to_uint128(
+        reserve_before.checked_sub(reserve_after)?.to_uint_floor(),
+    )
+}
+
+/// Largest amount that paired_amount can buy, capped by max_amount
+/// Return (amount in base denom, paired base denom spent)
+pub fn amount_out_for_paid(
+    curve: &BondingCurve,
+    supply_sold_in_base_denom: Uint128,
+    max_amount_in_base_denom: Uint128,
+    paired_amount: Uint128,
+) -> StdResult<(Uint128, Uint128)> {
+    // cost grows with amount so binary search the largest affordable amount
+    let (mut low, mut high) = (Uint128::zero(), max_amount_in_base_denom);
+    while low < high {
+        let mid = low + (high - low + Uint128::one()) / Uint128::new(2);
+        if cost_to_buy(curve, supply_sold_in_base_denom, mid)? <= paired_amount
+        {
+            low = mid;
+        } else {
+            high = mid - Uint128::one();
+        }
+    }
+    Ok((low, cost_to_buy(curve, supply_sold_in_base_denom, low)?))
+}
+
+/// Fully diluted market cap in paired base denom
+pub fn market_cap(
+    curve: &BondingCurve,
+    supply_sold_in_base_denom: Uint128,
+    max_supply_in_base_denom: Uint128,
+) -> StdResult<Uint128> {
+    let price = spot_price(curve, supply_sold_in_base_denom)?;
+    convert_to_uint128(
+        price
+            .checked_mul(convert_base_denom_to_denom(max_supply_in_base_denom))?
+            .to_uint_floor(),
+    )
+}
+
+/// Provide curve reserve and pool supply to the pair and close the curve, caller saves state
+pub fn provide_bonding_curve_liquidity(
+    storage: &mut dyn Storage,
+    bonding_curve_config: &BondingCurveConfig,
+    state: &mut BondingCurveState,
+    contract_addr: &Addr,
+    base_denom: &str,
+    pair_addr: Addr,
+    pool_supply: Uint128,
+) -> Result<(SubMsg, Vec<Attribute>), ContractError> {
+    // LP goes to this contract, there is no way to withdraw it
+    let provide_liquidity_msg = provide_liquidity_msg(
+        bonding_curve_config.paired_base_denom.as_str(),
+        &pair_addr,
+        base_denom,
+        contract_addr,
+        state.reserve_in_paired_base_denom,
+        pool_supply,
+    )?;
+    let provide_liquidity_submsg = match bonding_curve_config.params.lp_mode {
+        BondingCurveLpMode::Lock {} => SubMsg::new(provide_liquidity_msg),
+        BondingCurveLpMode::Burn {} => SubMsg {
+            id: REPLY_ID_PROVIDE_BONDING_CURVE_LIQUIDITY,
+            msg: provide_liquidity_msg,
+            gas_limit: None,
+            reply_on: ReplyOn::Success,
+        },
+    };
+    let attributes = vec![
+        Attribute::new("action", "migrate_bonding_curve_liquidity"),
+        Attribute::new("pair_addr", pair_addr.to_string()),
+        Attribute::new("token_1", &bonding_curve_config.paired_base_denom),
+        Attribute::new("token_2", base_denom),
+        Attribute::new("token_1_liquidity", state.reserve_in_paired_base_denom),
+        Attribute::new("token_2_liquidity", pool_supply),
+    ];
+    tax_pair_if_transfer_tax_set(storage, &pair_addr)?;
+    add_pool_if_launch_protection_set(storage, &pair_addr)?;
+    state.phase = BondingCurvePhase::Migrated {};
+    state.pair_addr = Some(pair_addr);
+    Ok((provide_liquidity_submsg, attributes))
+}
+
+pub fn validate_bonding_curve_params(
+    params: &BondingCurveParams,
+    initial_supply_in_base_denom: Uint128,
+    vesting_supply_in_base_denom: Uint128,
+    max_supply_in_base_denom: Uint128,
+) -> Result<(), ContractError> {
+    let invalid = |reason: &str| ContractError::InvalidBondingCurveConfig {
+        reason: reason.to_string(),
+    };
+    if !initial_supply_in_base_denom.is_zero() {
+        return Err(invalid("initial supply must be 0"));
+    }
+    let curve_supply_in_base_denom =
+        params.curve_supply_in_denom * one_denom_in_base_denom();
+    if curve_supply_in_base_denom.is_zero()
+        || curve_supply_in_base_denom + vesting_supply_in_base_denom
+            >= max_supply_in_base_denom
+    {
+        return Err(invalid(
+            "curve supply must be greater than 0 and less than max supply minus vesting allocations",
+        ));
+    }
+    if params.migration_market_cap.is_zero() {
+        return Err(invalid("migration market cap must be greater than 0"));
+    }
+    match params.curve {
+        BondingCurve::Linear { initial_price, .. } => {
+            if initial_price.is_zero() {
+                return Err(invalid("initial price must be greater than 0"));
+            }
+        }
+        BondingCurve::Exponential {
+            initial_price,
+            step_size_in_denom,
+            ..
+        } => {
+            if initial_price.is_zero() {
+                return Err(invalid("initial price must be greater than 0"));
+            }
+            if step_size_in_denom.is_zero() {
+                return Err(invalid("step size must be greater than 0"));
+            }
+        }
+    }
+    // make sure the whole curve can be bought without overflow
+    cost_to_buy(&params.curve, Uint128::zero(), curve_supply_in_base_denom)
+        .map_err(|_| invalid("curve overflows before curve supply is sold"))?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cosmwasm_std::Decimal;
+
+    const ONE_DENOM: u128 = 1_000_000;
+
+    fn linear(initial_price: Decimal, slope: Decimal) -> BondingCurve {
+        BondingCurve::Linear {
+            initial_price,
+            slope,
+        }
+    }
+
+    fn exponential(
+        initial_price: Decimal,
+        growth_rate: Decimal,
+        step_size_in_denom: u128,
+    ) -> BondingCurve {
+        BondingCurve::Exponential {
+            initial_price,
+            growth_rate,
+            step_size_in_denom: Uint128::new(step_size_in_denom),
+        }
+    }
+
+    fn curves() -> Vec<BondingCurve> {
+        vec![
+            linear(Decimal::percent(1), Decimal::zero()),
+            linear(Decimal::permille(1), Decimal::permille(3)),
+            exponential(Decimal::percent(1), Decimal::percent(5), 7),
+            exponential(Decimal::one(), Decimal::zero(), 3),
+        ]
+    }
+
+    #[test]
+    fn linear_cost_is_area_under_curve() {
+        // 1 + 2 * supply, buying 10 denom from 0 costs 10 + 10 ^ 2 = 110
+        let curve = linear(Decimal::one(), Decimal::from_ratio(2u128, 1u128));
+        assert_eq!(
+            cost_to_buy(&curve, Uint128::zero(), Uint128::new(10 * ONE_DENOM))
+                .unwrap(),
+            Uint128::new(110)
+        );
+        assert_eq!(
+            return_for_sell(
+                &curve,
+                Uint128::new(10 * ONE_DENOM),
+                Uint128::new(10 * ONE_DENOM)
+            )
+            .unwrap(),
+            Uint128::new(110)
+        );
+    }
+
+    #[test]
+    fn exponential_cost_sums_steps() {
+        // price doubles every 10 denom, 10 * 1 + 10 * 2 + 5 * 4 = 50
+        let curve = exponential(Decimal::one(), Decimal::one(), 10);
+        assert_eq!(
+            cost_to_buy(&curve, Uint128::zero(), Uint128::new(25 * ONE_DENOM))
+                .unwrap(),
+            Uint128::new(50)
+        );
+        assert_eq!(
+            spot_price(&curve, Uint128::new(10 * ONE_DENOM - 1)).unwrap(),
+            Decimal256::one()
+        );
+        assert_eq!(
+            spot_price(&curve, Uint128::new(10 * ONE_DENOM)).unwrap(),
+            Decimal256::from_ratio(2u128, 1u128)
+        );
+    }
+
+    #[test]
+    fn buy_rounds_up_and_sell_rounds_down() {
+        // 1 denom is worth 1 paired base denom
+        let curve = linear(Decimal::one(), Decimal::zero());
+        let supply = Uint128::new(5 * ONE_DENOM);
+        assert_eq!(
+            cost_to_buy(&curve, supply, Uint128::new(ONE_DENOM)).unwrap(),
+            Uint128::new(1)
+        );
+        assert_eq!(
+            cost_to_buy(&curve, supply, Uint128::new(ONE_DENOM + 1)).unwrap(),
+            Uint128::new(2)
+        );
+        assert_eq!(
+            return_for_sell(&curve, supply, Uint128::new(2 * ONE_DENOM - 1))
+                .unwrap(),
+            Uint128::new(1)
+        );
+        assert_eq!(
+            return_for_sell(&curve, supply, Uint128::new(1)).unwrap(),
+            Uint128::zero()
+        );
+    }
+
+    #[test]
+    fn sell_after_buy_never_returns_more_than_paid() {
+        for curve in curves() {
+            for supply in [0u128, 1, 999_999, 3 * ONE_DENOM + 17] {
+                for amount in [1u128, 333_333, ONE_DENOM, 20 * ONE_DENOM + 1] {
+                    let supply = Uint128::new(supply);
+                    let amount = Uint128::new(amount);
+                    let paid = cost_to_buy(&curve, supply, amount).unwrap();
+                    let returned =
+                        return_for_sell(&curve, supply + amount, amount)
+                            .unwrap();
+                    assert!(returned <= paid);
+                    // the difference is rounding only
+                    assert!(paid - returned <= Uint128::one());
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn buying_in_pieces_costs_at_least_buying_at_once() {
+        for curve in curves() {
+            let at_once =
+                cost_to_buy(&curve, Uint128::zero(), Uint128::new(ONE_DENOM))
+                    .unwrap();
+            let mut supply = Uint128::zero();
+            let mut in_pieces = Uint128::zero();
+            for _ in 0..10 {
+                in_pieces +=
+                    cost_to_buy(&curve, supply, Uint128::new(ONE_DENOM / 10))
+                        .unwrap();
+                supply += Uint128::new(ONE_DENOM / 10);
+            }
+            assert!(in_pieces >= at_once);
+        }
+    }
+
+    #[test]
+    fn amount_out_for_paid_is_largest_affordable_amount() {
+        for curve in curves() {
+            let supply = Uint128::new(2 * ONE_DENOM + 5);
+            let max_amount = Uint128::new(50 * ONE_DENOM);
+            for paid in [0u128, 1, 7, 1_000] {
+                let paid = Uint128::new(paid);
+                let (amount_out, spent) =
+                    amount_out_for_paid(&curve, supply, max_amount, paid)
+                        .unwrap();
+                assert!(spent <= paid);
+                assert_eq!(
+                    spent,
+                    cost_to_buy(&curve, supply, amount_out).unwrap()
+                );
+                if amount_out < max_amount {
+                    assert!(
+                        cost_to_buy(
+                            &curve,
+                            supply,
+                            amount_out + Uint128::one()
+                        )
+                        .unwrap()
+                            > paid
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn amount_out_for_paid_is_capped_by_max_amount() {
+        let curve = linear(Decimal::percent(1), Decimal::zero());
+        let max_amount = Uint128::new(ONE_DENOM);
+        let (amount_out, spent) = amount_out_for_paid(
+            &curve,
+            Uint128::zero(),
+            max_amount,
+            Uint128::new(1_000_000),
+        )
+        .unwrap();
+        assert_eq!(amount_out, max_amount);
+        assert_eq!(spent, Uint128::one());
+    }
+
+    #[test]
+    fn market_cap_is_spot_price_times_max_supply() {
+        // price 0.01 + 0.001 * 1000 = 1.01 after 1000 denom sold
+        let curve = linear(Decimal::percent(1), Decimal::permille(1));
+        let max_supply = Uint128::new(1_000_000 * ONE_DENOM);
+        assert_eq!(
+            market_cap(&curve, Uint128::zero(), max_supply).unwrap(),
+            Uint128::new(10_000)
+        );
+        assert_eq!(
+            market_cap(&curve, Uint128::new(1_000 * ONE_DENOM), max_supply)
+                .unwrap(),
+            Uint128::new(1_010_000)
+        );
+        // rounded down
+        assert_eq!(
+            market_cap(&curve, Uint128::zero(), Uint128::new(ONE_DENOM))
+                .unwrap(),
+            Uint128::zero()
+        );
+    }
+}
@@ -0,0 +1,256 @@
+use crate::util::{
+    astroport::AstroportAdapter,
+    osmosis::{
+        pool_creation_fee_in_paired_denom, OsmosisBalancerAdapter,
+        OsmosisConcentratedAdapter,
+    },
+};
+use coin::{
+    dex::{SeedLiquidityDex, SeedLiquidityLpToken},
+    liquidity_lock::LiquidityLockMode,
+};
+use cosmwasm_std::{
+    coins, to_json_binary, Addr, Api, Attribute, BankMsg, Binary, CosmosMsg,
+    Decimal, QuerierWrapper, StdResult, SubMsg, SubMsgResult, Uint128, WasmMsg,
+};
+use cw20::Cw20ExecuteMsg;
+use shared_pkg::error::ContractError;
+
+/// Creates seed liquidity pool and provides liquidity to it on a DEX
+/// create_pool -> created_pool in create pair reply -> provide_liquidity -> received_lp
+pub trait DexAdapter {
+    /// Reply is handled with REPLY_ID_CREATE_PAIR
+    fn create_pool(
+        &self,
+        querier: QuerierWrapper,
+        contract_addr: &Addr,
+        paired_base_denom: &str,
+        base_denom: &str,
+        paired_base_denom_amount: Uint128,
+        pool_supply: Uint128,
+    ) -> Result<(SubMsg, Vec<Attribute>), ContractError>;
+
+    /// Pair address on Astroport, pool id on Osmosis
+    fn created_pool(
+        &self,
+        querier: QuerierWrapper,
+        result: SubMsgResult,
+        paired_base_denom: &str,
+        base_denom: &str,
+    ) -> Result<String, ContractError>;
+
+    /// LP goes to coin contract
+    /// None if seed liquidity is already provided when creating the pool
+    fn provide_liquidity(
+        &self,
+        querier: QuerierWrapper,
+        pool: &str,
+        contract_addr: &Addr,
+        paired_base_denom: &str,
+        base_denom: &str,
+        paired_base_denom_amount: Uint128,
+        pool_supply: Uint128,
+    ) -> Result<Option<(CosmosMsg, Vec<Attribute>)>, ContractError>;
+
+    /// LP held by coin contract after seed liquidity is provided
+    /// data is the reply data of provide_liquidity, None if there is no such reply
+    fn received_lp(
+        &self,
+        querier: QuerierWrapper,
+        pool: &str,
+        contract_addr: &Addr,
+        data: Option<Binary>,
+    ) -> Result<(SeedLiquidityLpToken, Uint128), ContractError>;
+}
+
+pub fn dex_adapter(dex: &SeedLiquidityDex) -> Box<dyn DexAdapter + '_> {
+    match dex {
+        SeedLiquidityDex::Astroport {
+            factory_addr,
+            pair_type,
+        } => Box::new(AstroportAdapter {
+            factory_addr,
+            pair_type,
+        }),
+        SeedLiquidityDex::OsmosisBalancer { swap_fee } => {
+            Box::new(OsmosisBalancerAdapter {
+                swap_fee: *swap_fee,
+            })
+        }
+        SeedLiquidityDex::OsmosisConcentrated {
+            tick_spacing,
+            spread_factor,
+        } => Box::new(OsmosisConcentratedAdapter {
+            tick_spacing: *tick_spacing,
+            spread_factor: *spread_factor,
+        }),
+    }
+}
+
+pub fn validate_seed_liquidity_dex(
+    api: &dyn Api,
+    querier: QuerierWrapper,
+    dex: &SeedLiquidityDex,
+    lock_mode: &LiquidityLockMode,
+    paired_base_denom: &str,
+    paired_base_denom_amount: Uint128,
+) -> Result<(), ContractError> {
+    let fee = match dex {
+        SeedLiquidityDex::Astroport { factory_addr, .. } => {
+            api.addr_validate(factory_addr)?;
+            return Ok(());
+        }
+        SeedLiquidityDex::OsmosisBalancer { swap_fee } => *swap_fee,
+        SeedLiquidityDex::OsmosisConcentrated {
+            tick_spacing,
+            spread_factor,
+        } => {
+            if *tick_spacing == 0 {
+                return Err(ContractError::InvalidSeedLiquidityConfig {
+                    reason: "tick spacing must be greater than 0".to_string(),
+                });
+            }
+            if *lock_mode != (LiquidityLockMode::Burn {}) {
+                return Err(ContractError::InvalidSeedLiquidityConfig {
+                    reason: "concentrated liquidity position only supports Burn lock mode".to_string(),
+                });
+            }
+            *spread_factor
+        }
+    };
+    if fee >= Decimal::one() {
+        return Err(ContractError::InvalidSeedLiquidityConfig {
+            reason: "pool fee must be less than 1".to_string(),
+        });
+    }
+    if pool_creation_fee_in_paired_denom(querier, paired_base_denom)?
+        >= paired_base_denom_amount
+    {
+        return Err(ContractError::InvalidSeedLiquidityConfig {
+            reason: "paired amount must be greater than pool creation fee"
+                .to_string(),
+        });
+    }
+    Ok(())
+}
+
+/// None for concentrated liquidity position, it cannot be transferred
+pub fn lp_transfer_msg(
+    lp_token: &SeedLiquidityLpToken,
+    recipient_addr: &Addr,
+    amount: Uint128,
+) -> StdResult<Option<CosmosMsg>> {
+    Ok(match lp_token {
+        SeedLiquidityLpToken::Cw20 { addr } => Some(
+            WasmMsg::Execute {
+                contract_addr: addr.to_string(),
+                msg: to_json_binary(&Cw20ExecuteMsg::Transfer {
+                    recipient: recipient_addr.to_string(),
+                    amount,
+                })?,
+                funds: vec![],
+            }
+            .into(),
+        ),
+        SeedLiquidityLpToken::Native { denom } => Some(
+            BankMsg::Send {
+                to_address: recipient_addr.to_string(),
+                amount: coins(amount.u128(), denom),
+            }
+            .into(),
+        ),
+        SeedLiquidityLpToken::Position { .. } => None,
+    })
+}
+
+/// None for concentrated liquidity position, coin contract holds it forever instead
+pub fn lp_burn_msg(
+    lp_token: &SeedLiquidityLpToken,
+    amount: Uint128,
+) -> StdResult<Option<CosmosMsg>> {
+    Ok(match lp_token {
+        SeedLiquidityLpToken::Cw20 { addr } => Some(
+            WasmMsg::Execute {
+                contract_addr: addr.to_string(),
+                msg: to_json_binary(&Cw20ExecuteMsg::Burn { amount })?,
+                funds: vec![],
+            }
+            .into(),
+        ),
+        SeedLiquidityLpToken::Native { denom } => Some(
+            BankMsg::Burn {
+                amount: coins(amount.u128(), denom),
+            }
+            .into(),
+        ),
+        SeedLiquidityLpToken::Position { .. } => None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lp_msgs_match_lp_token_kind() {
+        let cw20 = SeedLiquidityLpToken::Cw20 {
+            addr: Addr::unchecked("lp"),
+        };
+        let native = SeedLiquidityLpToken::Native {
+            denom: "factory/pair/lp".to_string(),
+        };
+        let position = SeedLiquidityLpToken::Position { position_id: 1 };
+        let amount = Uint128::new(100);
+        let alice = Addr::unchecked("alice");
+
+        assert_eq!(
+            lp_transfer_msg(&cw20, &alice, amount).unwrap(),
+            Some(
+                WasmMsg::Execute {
+                    contract_addr: "lp".to_string(),
+                    msg: to_json_binary(&Cw20ExecuteMsg::Transfer {
+                        recipient: "alice".to_string(),
+                        amount,
+                    })
+                    .unwrap(),
+                    funds: vec![],
+                }
+                .into()
+            )
+        );
+        assert_eq!(
+            lp_transfer_msg(&native, &alice, amount).unwrap(),
+            Some(
+                BankMsg::Send {
+                    to_address: "alice".to_string(),
+                    amount: coins(100, "factory/pair/lp"),
+                }
+                .into()
+            )
+        );
+        assert_eq!(lp_transfer_msg(&position, &alice, amount).unwrap(), None);
+
+        assert_eq!(
+            lp_burn_msg(&cw20, amount).unwrap(),
+            Some(
+                WasmMsg::Execute {
+                    contract_addr: "lp".to_string(),
+                    msg: to_json_binary(&Cw20ExecuteMsg::Burn { amount })
+                        .unwrap(),
+                    funds: vec![],
+                }
+                .into()
+            )
+        );
+        assert_eq!(
+            lp_burn_msg(&native, amount).unwrap(),
+            Some(
+                BankMsg::Burn {
+                    amount: coins(100, "factory/pair/lp"),
+                }
+                .into()
+            )
+        );
+        assert_eq!(lp_burn_msg(&position, amount).unwrap(), None);
+    }
+}
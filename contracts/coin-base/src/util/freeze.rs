@@ -0,0 +1,184 @@
+use crate::state::{FROZEN_ACCOUNTS, GLOBAL_PAUSE, PENDING_FORCE_TRANSFER};
+use coin::config::Config;
+use cosmwasm_std::{Addr, StdResult, Storage};
+use shared_pkg::error::ContractError;
+
+pub fn is_globally_paused(
+    storage: &dyn Storage,
+    config: &Config,
+) -> StdResult<bool> {
+    // immutable coins have no admin to pause them
+    Ok(config.admin_addr.is_some()
+        && GLOBAL_PAUSE.may_load(storage)?.unwrap_or(false))
+}
+
+pub fn assert_not_globally_paused(
+    storage: &dyn Storage,
+    config: &Config,
+) -> Result<(), ContractError> {
+    if is_globally_paused(storage, config)? {
+        return Err(ContractError::CoinGloballyPaused {});
+    }
+    Ok(())
+}
+
+/// Return true and consume the record if this send is the admin force transfer in flight
+pub fn consume_pending_force_transfer(
+    storage: &mut dyn Storage,
+    from_addr: &Addr,
+    to_addr: &Addr,
+) -> StdResult<bool> {
+    match PENDING_FORCE_TRANSFER.may_load(storage)? {
+        Some((pending_from_addr, pending_to_addr))
+            if pending_from_addr == from_addr && pending_to_addr == to_addr =>
+        {
+            PENDING_FORCE_TRANSFER.remove(storage);
+            Ok(true)
+        }
+        _ => Ok(false),
+    }
+}
+
+/// Error if the send touches a frozen account or coin is paused, admin force transfer is let through
+/// Nothing is blocked once admin is renounced, freezes and pause left in storage stop applying
+pub fn assert_send_not_frozen(
+    storage: &mut dyn Storage,
+    config: &Config,
+    base_denom: &str,
+    denom: &str,
+    contract_addr: &Addr,
+    from_addr: &Addr,
+    to_addr: &Addr,
+) -> Result<(), ContractError> {
+    if denom != base_denom
+        || config.admin_addr.is_none()
+        || consume_pending_force_transfer(storage, from_addr, to_addr)?
+    {
+        return Ok(());
+    }
+    for addr in [from_addr, to_addr] {
+        if FROZEN_ACCOUNTS.has(storage, addr) {
+            return Err(ContractError::AccountFrozen {
+                addr: addr.to_string(),
+            });
+        }
+    }
+    // admin can still force transfer coin into this contract
+    if to_addr != contract_addr {
+        assert_not_globally_paused(storage, config)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::execute::freeze::{
+        freeze_account, set_global_pause, unfreeze_account,
+    };
+    use cosmwasm_std::{testing::MockStorage, Uint128};
+    use osmosis_std::types::cosmos::bank::v1beta1::Metadata as DenomMetadata;
+
+    const BASE_DENOM: &str = "factory/coin/ucoin";
+
+    fn config(admin_addr: Option<&str>) -> Config {
+        Config {
+            admin_addr: admin_addr.map(Addr::unchecked),
+            creator_addr: Addr::unchecked("creator"),
+            denom_metadata: DenomMetadata::default(),
+            max_supply_in_base_denom: Uint128::new(1_000),
+            seed_liquidity_config: None,
+            bonding_curve_config: None,
+        }
+    }
+
+    fn assert_send(
+        storage: &mut dyn Storage,
+        config: &Config,
+        from: &str,
+        to: &str,
+    ) -> Result<(), ContractError> {
+        assert_send_not_frozen(
+            storage,
+            config,
+            BASE_DENOM,
+            BASE_DENOM,
+            &Addr::unchecked("coin"),
+            &Addr::unchecked(from),
+            &Addr::unchecked(to),
+        )
+    }
+
+    #[test]
+    fn frozen_account_cannot_send_or_receive_until_unfrozen() {
+        let mut storage = MockStorage::new();
+        let config = config(Some("admin"));
+        let alice = Addr::unchecked("alice");
+        freeze_account(&mut storage, &alice).unwrap();
+        for (from, to) in [("alice", "bob"), ("bob", "alice")] {
+            assert!(matches!(
+                assert_send(&mut storage, &config, from, to).unwrap_err(),
+                ContractError::AccountFrozen { addr } if addr == "alice"
+            ));
+        }
+        assert_send(&mut storage, &config, "bob", "carol").unwrap();
+        // other denoms are not blocked
+        assert_send_not_frozen(
+            &mut storage,
+            &config,
+            BASE_DENOM,
+            "untrn",
+            &Addr::unchecked("coin"),
+            &alice,
+            &Addr::unchecked("bob"),
+        )
+        .unwrap();
+        unfreeze_account(&mut storage, &alice).unwrap();
+        assert_send(&mut storage, &config, "alice", "bob").unwrap();
+    }
+
+    #[test]
+    fn global_pause_blocks_all_sends_except_into_contract() {
+        let mut storage = MockStorage::new();
+        let config = config(Some("admin"));
+        set_global_pause(&mut storage, true).unwrap();
+        assert!(matches!(
+            assert_send(&mut storage, &config, "alice", "bob").unwrap_err(),
+            ContractError::CoinGloballyPaused {}
+        ));
+        assert_send(&mut storage, &config, "alice", "coin").unwrap();
+        set_global_pause(&mut storage, false).unwrap();
+        assert_send(&mut storage, &config, "alice", "bob").unwrap();
+    }
+
+    #[test]
+    fn admin_force_transfer_goes_through_freeze_and_pause_once() {
+        let mut storage = MockStorage::new();
+        let config = config(Some("admin"));
+        freeze_account(&mut storage, &Addr::unchecked("alice")).unwrap();
+        set_global_pause(&mut storage, true).unwrap();
+        PENDING_FORCE_TRANSFER
+            .save(
+                &mut storage,
+                &(Addr::unchecked("alice"), Addr::unchecked("treasury")),
+            )
+            .unwrap();
+        // a different send does not consume the record
+        assert!(assert_send(&mut storage, &config, "alice", "bob").is_err());
+        assert_send(&mut storage, &config, "alice", "treasury").unwrap();
+        assert!(!PENDING_FORCE_TRANSFER.exists(&storage));
+        assert!(
+            assert_send(&mut storage, &config, "alice", "treasury").is_err()
+        );
+    }
+
+    #[test]
+    fn freeze_and_pause_lift_without_admin() {
+        let mut storage = MockStorage::new();
+        freeze_account(&mut storage, &Addr::unchecked("alice")).unwrap();
+        set_global_pause(&mut storage, true).unwrap();
+        let renounced = config(None);
+        assert!(!is_globally_paused(&storage, &renounced).unwrap());
+        assert_send(&mut storage, &renounced, "alice", "bob").unwrap();
+    }
+}
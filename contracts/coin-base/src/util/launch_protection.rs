@@ -0,0 +1,172 @@
+use crate::{
+    state::{LAST_TRANSFER_HEIGHT, LAUNCH_PROTECTION, LAUNCH_PROTECTION_ADDRS},
+    util::token_factory::register_before_send_hook,
+};
+use coin::launch_protection::{
+    LaunchProtection, LaunchProtectionAddrKind, LaunchProtectionConfig,
+};
+use cosmwasm_std::{
+    Addr, Api, BlockInfo, CosmosMsg, QuerierWrapper, Storage, Uint128,
+};
+use shared_pkg::error::ContractError;
+
+/// Validate and save launch protection, before send hook is registered to enforce it
+pub fn save_launch_protection_config(
+    api: &dyn Api,
+    storage: &mut dyn Storage,
+    block: &BlockInfo,
+    config: LaunchProtectionConfig,
+    base_denom: &str,
+    contract_addr: &Addr,
+) -> Result<Vec<CosmosMsg>, ContractError> {
+    let invalid = |reason: &str| ContractError::InvalidLaunchProtectionConfig {
+        reason: reason.to_string(),
+    };
+    if config.duration_seconds == 0 {
+        return Err(invalid("duration must be greater than 0"));
+    }
+    if config.max_wallet_balance == Some(Uint128::zero())
+        || config.max_tx_amount == Some(Uint128::zero())
+        || config.cooldown_blocks == Some(0)
+    {
+        return Err(invalid("limits must be greater than 0 if set"));
+    }
+    let trading_start_time = match config.trading_start_time {
+        Some(time) if time < block.time => {
+            return Err(invalid("trading start time must not be in the past"))
+        }
+        Some(time) => time,
+        None => block.time,
+    };
+    for addr in &config.exempt_addrs {
+        LAUNCH_PROTECTION_ADDRS.save(
+            storage,
+            &api.addr_validate(addr)?,
+            &LaunchProtectionAddrKind::Exempt {},
+        )?;
+    }
+    for addr in &config.pool_addrs {
+        LAUNCH_PROTECTION_ADDRS.save(
+            storage,
+            &api.addr_validate(addr)?,
+            &LaunchProtectionAddrKind::Pool {},
+        )?;
+    }
+    LAUNCH_PROTECTION.save(
+        storage,
+        &LaunchProtection {
+            max_wallet_balance: config.max_wallet_balance,
+            max_tx_amount: config.max_tx_amount,
+            cooldown_blocks: config.cooldown_blocks,
+            trading_start_time,
+            end_time: trading_start_time.plus_seconds(config.duration_seconds),
+        },
+    )?;
+    Ok(register_before_send_hook(
+        storage,
+        base_denom,
+        contract_addr,
+    )?)
+}
+
+pub fn is_launch_protection_active(
+    launch_protection: &LaunchProtection,
+    block: &BlockInfo,
+) -> bool {
+    block.time < launch_protection.end_time
+}
+
+/// Error if launch protection is not set or already expired
+pub fn load_active_launch_protection(
+    storage: &dyn Storage,
+    block: &BlockInfo,
+) -> Result<LaunchProtection, ContractError> {
+    match LAUNCH_PROTECTION.may_load(storage)? {
+        Some(launch_protection)
+            if is_launch_protection_active(&launch_protection, block) =>
+        {
+            Ok(launch_protection)
+        }
+        _ => Err(ContractError::LaunchProtectionNotActive {}),
+    }
+}
+
+/// Pair created for seed liquidity or bonding curve migration is a pool if launch protection is set
+pub fn add_pool_if_launch_protection_set(
+    storage: &mut dyn Storage,
+    pair_addr: &Addr,
+) -> Result<(), ContractError> {
+    if LAUNCH_PROTECTION.exists(storage) {
+        LAUNCH_PROTECTION_ADDRS.save(
+            storage,
+            pair_addr,
+            &LaunchProtectionAddrKind::Pool {},
+        )?;
+    }
+    Ok(())
+}
+
+/// Error if the send breaks any limit, records the send for cooldown otherwise
+pub fn enforce_launch_protection(
+    storage: &mut dyn Storage,
+    querier: QuerierWrapper,
+    block: &BlockInfo,
+    launch_protection: &LaunchProtection,
+    base_denom: &str,
+    contract_addr: &Addr,
+    from_addr: &Addr,
+    to_addr: &Addr,
+    amount: Uint128,
+) -> Result<(), ContractError> {
+    // seed liquidity, bonding curve trades and claims go through this contract
+    if from_addr == contract_addr || to_addr == contract_addr {
+        return Ok(());
+    }
+    let from_kind = LAUNCH_PROTECTION_ADDRS.may_load(storage, from_addr)?;
+    let to_kind = LAUNCH_PROTECTION_ADDRS.may_load(storage, to_addr)?;
+    if from_kind == Some(LaunchProtectionAddrKind::Exempt {})
+        || to_kind == Some(LaunchProtectionAddrKind::Exempt {})
+    {
+        return Ok(());
+    }
+    if block.time < launch_protection.trading_start_time {
+        return Err(ContractError::TradingNotStarted {
+            trading_start_time: launch_protection.trading_start_time,
+        });
+    }
+    if let Some(max_tx_amount) = launch_protection.max_tx_amount {
+        if amount > max_tx_amount {
+            return Err(ContractError::MaxTxAmountExceeded { max_tx_amount });
+        }
+    }
+    if let Some(max_wallet_balance) = launch_protection.max_wallet_balance {
+        // hook runs before the send, balance does not include amount yet
+        if to_kind.is_none()
+            && querier.query_balance(to_addr, base_denom)?.amount + amount
+                > max_wallet_balance
+        {
+            return Err(ContractError::MaxWalletBalanceExceeded {
+                max_wallet_balance,
+            });
+        }
+    }
+    if let Some(cooldown_blocks) = launch_protection.cooldown_blocks {
+        for (addr, kind) in [(from_addr, from_kind), (to_addr, to_kind)] {
+            if kind.is_some() {
+                continue;
+            }
+            if let Some(height) =
+                LAST_TRANSFER_HEIGHT.may_load(storage, addr)?
+            {
+                if height + cooldown_blocks > block.height {
+                    return Err(ContractError::TransferCooldownNotPassed {
+                        addr: addr.to_string(),
+                        next_transfer_height: height + cooldown_blocks,
+                    });
+                }
+            }
+            LAST_TRANSFER_HEIGHT.save(storage, addr, &block.height)?;
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,175 @@
+use coin::liquidity_lock::{LiquidityLockMode, SeedLiquidityLp};
+use cosmwasm_std::{BlockInfo, Uint128};
+use shared_pkg::error::ContractError;
+
+pub fn validate_liquidity_lock_mode(
+    lock_mode: &LiquidityLockMode,
+    block: &BlockInfo,
+) -> Result<(), ContractError> {
+    match lock_mode {
+        LiquidityLockMode::Creator {} | LiquidityLockMode::Burn {} => Ok(()),
+        LiquidityLockMode::TimeLocked { unlock_time } => {
+            if *unlock_time <= block.time {
+                return Err(ContractError::InvalidLiquidityLockMode {
+                    reason: "unlock time must be in the future".to_string(),
+                });
+            }
+            Ok(())
+        }
+        LiquidityLockMode::LinearVesting {
+            start_time,
+            end_time,
+        } => {
+            if end_time <= start_time || *end_time <= block.time {
+                return Err(ContractError::InvalidLiquidityLockMode {
+                    reason:
+                        "end time must be after start time and in the future"
+                            .to_string(),
+                });
+            }
+            Ok(())
+        }
+    }
+}
+
+/// LP creator can withdraw at current block time
+pub fn calculate_withdrawable_lp_amount(
+    lock_mode: &LiquidityLockMode,
+    seed_liquidity_lp: &SeedLiquidityLp,
+    block: &BlockInfo,
+) -> Uint128 {
+    let unlocked_amount = match lock_mode {
+        // LP is never held by the contract
+        LiquidityLockMode::Creator {} | LiquidityLockMode::Burn {} => {
+            Uint128::zero()
+        }
+        LiquidityLockMode::TimeLocked { unlock_time } => {
+            if block.time >= *unlock_time {
+                seed_liquidity_lp.total_amount
+            } else {
+                Uint128::zero()
+            }
+        }
+        LiquidityLockMode::LinearVesting {
+            start_time,
+            end_time,
+        } => {
+            if block.time <= *start_time {
+                Uint128::zero()
+            } else if block.time >= *end_time {
+                seed_liquidity_lp.total_amount
+            } else {
+                seed_liquidity_lp.total_amount.multiply_ratio(
+                    block.time.seconds() - start_time.seconds(),
+                    end_time.seconds() - start_time.seconds(),
+                )
+            }
+        }
+    };
+    unlocked_amount.saturating_sub(seed_liquidity_lp.withdrawn_amount)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use coin::dex::SeedLiquidityLpToken;
+    use cosmwasm_std::{testing::mock_env, Addr, Timestamp};
+
+    fn block_at(seconds: u64) -> BlockInfo {
+        let mut block = mock_env().block;
+        block.time = Timestamp::from_seconds(seconds);
+        block
+    }
+
+    fn lp(total_amount: u128, withdrawn_amount: u128) -> SeedLiquidityLp {
+        SeedLiquidityLp {
+            pool: "pair".to_string(),
+            lp_token: SeedLiquidityLpToken::Cw20 {
+                addr: Addr::unchecked("lp"),
+            },
+            total_amount: Uint128::new(total_amount),
+            withdrawn_amount: Uint128::new(withdrawn_amount),
+        }
+    }
+
+    fn withdrawable(
+        lock_mode: &LiquidityLockMode,
+        seed_liquidity_lp: &SeedLiquidityLp,
+        seconds: u64,
+    ) -> u128 {
+        calculate_withdrawable_lp_amount(
+            lock_mode,
+            seed_liquidity_lp,
+            &block_at(seconds),
+        )
+        .u128()
+    }
+
+    #[test]
+    fn time_locked_lp_unlocks_all_at_unlock_time() {
+        let lock_mode = LiquidityLockMode::TimeLocked {
+            unlock_time: Timestamp::from_seconds(1_000),
+        };
+        assert_eq!(withdrawable(&lock_mode, &lp(900, 0), 999), 0);
+        assert_eq!(withdrawable(&lock_mode, &lp(900, 0), 1_000), 900);
+        assert_eq!(withdrawable(&lock_mode, &lp(900, 900), 2_000), 0);
+    }
+
+    #[test]
+    fn linear_vesting_lp_unlocks_pro_rata_minus_withdrawn() {
+        let lock_mode = LiquidityLockMode::LinearVesting {
+            start_time: Timestamp::from_seconds(1_000),
+            end_time: Timestamp::from_seconds(1_300),
+        };
+        assert_eq!(withdrawable(&lock_mode, &lp(900, 0), 1_000), 0);
+        assert_eq!(withdrawable(&lock_mode, &lp(900, 0), 1_100), 300);
+        // rounded down
+        assert_eq!(withdrawable(&lock_mode, &lp(1_000, 0), 1_100), 333);
+        assert_eq!(withdrawable(&lock_mode, &lp(900, 300), 1_200), 300);
+        assert_eq!(withdrawable(&lock_mode, &lp(900, 300), 5_000), 600);
+        // withdrawn more than unlocked never underflows
+        assert_eq!(withdrawable(&lock_mode, &lp(900, 900), 1_100), 0);
+    }
+
+    #[test]
+    fn creator_and_burn_lp_is_never_withdrawable() {
+        for lock_mode in
+            [LiquidityLockMode::Creator {}, LiquidityLockMode::Burn {}]
+        {
+            assert_eq!(withdrawable(&lock_mode, &lp(900, 0), 1_000_000), 0);
+        }
+    }
+
+    #[test]
+    fn lock_must_end_in_the_future() {
+        let block = block_at(1_000);
+        let invalid = [
+            LiquidityLockMode::TimeLocked {
+                unlock_time: Timestamp::from_seconds(1_000),
+            },
+            LiquidityLockMode::LinearVesting {
+                start_time: Timestamp::from_seconds(500),
+                end_time: Timestamp::from_seconds(1_000),
+            },
+            LiquidityLockMode::LinearVesting {
+                start_time: Timestamp::from_seconds(2_000),
+                end_time: Timestamp::from_seconds(2_000),
+            },
+        ];
+        for lock_mode in invalid {
+            assert!(matches!(
+                validate_liquidity_lock_mode(&lock_mode, &block).unwrap_err(),
+                ContractError::InvalidLiquidityLockMode { .. }
+            ));
+        }
+        // vesting may have started already
+        validate_liquidity_lock_mode(
+            &LiquidityLockMode::LinearVesting {
+                start_time: Timestamp::from_seconds(500),
+                end_time: Timestamp::from_seconds(1_001),
+            },
+            &block,
+        )
+        .unwrap();
+    }
+}
@@ -1,3 +1,12 @@
+pub(crate) mod airdrop;
 pub(crate) mod assert_helper;
 pub(crate) mod astroport;
+pub(crate) mod bonding_curve;
+pub(crate) mod dex;
+pub(crate) mod freeze;
+pub(crate) mod launch_protection;
+pub(crate) mod liquidity_lock;
+pub(crate) mod osmosis;
 pub(crate) mod token_factory;
+pub(crate) mod transfer_tax;
+pub(crate) mod vesting;
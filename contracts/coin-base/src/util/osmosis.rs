@@ -0,0 +1,321 @@
+use crate::{contract::REPLY_ID_CREATE_PAIR, util::dex::DexAdapter};
+use coin::dex::SeedLiquidityLpToken;
+use cosmwasm_std::{
+    Addr, Attribute, Binary, CosmosMsg, Decimal, QuerierWrapper, ReplyOn,
+    StdError, StdResult, SubMsg, SubMsgResult, Uint128,
+};
+use osmosis_std::types::{
+    cosmos::base::v1beta1::Coin as SdkCoin,
+    osmosis::{
+        concentratedliquidity::{
+            poolmodel::concentrated::v1beta1::{
+                MsgCreateConcentratedPool, MsgCreateConcentratedPoolResponse,
+            },
+            v1beta1::{MsgCreatePosition, MsgCreatePositionResponse},
+        },
+        gamm::{
+            poolmodels::balancer::v1beta1::{
+                MsgCreateBalancerPool, MsgCreateBalancerPoolResponse,
+            },
+            v1beta1::{PoolAsset, PoolParams},
+        },
+        poolmanager::v1beta1::PoolmanagerQuerier,
+    },
+};
+use shared_pkg::error::ContractError;
+use std::str::FromStr;
+
+/// Full range of concentrated liquidity ticks
+const MIN_INITIALIZED_TICK: i64 = -108_000_000;
+const MAX_TICK: i64 = 342_000_000;
+
+pub struct OsmosisBalancerAdapter {
+    pub swap_fee: Decimal,
+}
+
+pub struct OsmosisConcentratedAdapter {
+    pub tick_spacing: u64,
+    pub spread_factor: Decimal,
+}
+
+impl DexAdapter for OsmosisBalancerAdapter {
+    fn create_pool(
+        &self,
+        querier: QuerierWrapper,
+        contract_addr: &Addr,
+        paired_base_denom: &str,
+        base_denom: &str,
+        paired_base_denom_amount: Uint128,
+        pool_supply: Uint128,
+    ) -> Result<(SubMsg, Vec<Attribute>), ContractError> {
+        // balancer pool is created with all seed liquidity in it
+        let paired_liquidity = paired_base_denom_amount
+            - pool_creation_fee_in_paired_denom(querier, paired_base_denom)?;
+        let msg = MsgCreateBalancerPool {
+            sender: contract_addr.to_string(),
+            pool_params: Some(PoolParams {
+                swap_fee: to_osmosis_dec(self.swap_fee),
+                exit_fee: to_osmosis_dec(Decimal::zero()),
+                smooth_weight_change_params: None,
+            }),
+            pool_assets: vec![
+                PoolAsset {
+                    token: Some(SdkCoin {
+                        denom: paired_base_denom.to_string(),
+                        amount: paired_liquidity.to_string(),
+                    }),
+                    weight: "1".to_string(),
+                },
+                PoolAsset {
+                    token: Some(SdkCoin {
+                        denom: base_denom.to_string(),
+                        amount: pool_supply.to_string(),
+                    }),
+                    weight: "1".to_string(),
+                },
+            ],
+            future_pool_governor: "".to_string(),
+        };
+        let submsg = SubMsg {
+            id: REPLY_ID_CREATE_PAIR,
+            msg: msg.into(),
+            gas_limit: None,
+            reply_on: ReplyOn::Always,
+        };
+        let attrs = vec![
+            Attribute::new("action", "create_pool"),
+            Attribute::new("dex", "osmosis_balancer"),
+            Attribute::new("token_1", paired_base_denom),
+            Attribute::new("token_2", base_denom),
+            Attribute::new("token_1_liquidity", paired_liquidity),
+            Attribute::new("token_2_liquidity", pool_supply),
+        ];
+        Ok((submsg, attrs))
+    }
+
+    fn created_pool(
+        &self,
+        _querier: QuerierWrapper,
+        result: SubMsgResult,
+        _paired_base_denom: &str,
+        _base_denom: &str,
+    ) -> Result<String, ContractError> {
+        let resp = MsgCreateBalancerPoolResponse::try_from(reply_data(result)?)
+            .map_err(|_| {
+                ContractError::CannotParseResponseFromOsmosisReply {}
+            })?;
+        Ok(resp.pool_id.to_string())
+    }
+
+    fn provide_liquidity(
+        &self,
+        _querier: QuerierWrapper,
+        _pool: &str,
+        _contract_addr: &Addr,
+        _paired_base_denom: &str,
+        _base_denom: &str,
+        _paired_base_denom_amount: Uint128,
+        _pool_supply: Uint128,
+    ) -> Result<Option<(CosmosMsg, Vec<Attribute>)>, ContractError> {
+        Ok(None)
+    }
+
+    fn received_lp(
+        &self,
+        querier: QuerierWrapper,
+        pool: &str,
+        contract_addr: &Addr,
+        _data: Option<Binary>,
+    ) -> Result<(SeedLiquidityLpToken, Uint128), ContractError> {
+        let denom = format!("gamm/pool/{pool}");
+        let lp_amount = querier.query_balance(contract_addr, &denom)?.amount;
+        Ok((SeedLiquidityLpToken::Native { denom }, lp_amount))
+    }
+}
+
+impl DexAdapter for OsmosisConcentratedAdapter {
+    fn create_pool(
+        &self,
+        _querier: QuerierWrapper,
+        contract_addr: &Addr,
+        paired_base_denom: &str,
+        base_denom: &str,
+        _paired_base_denom_amount: Uint128,
+        _pool_supply: Uint128,
+    ) -> Result<(SubMsg, Vec<Attribute>), ContractError> {
+        // paired denom is the quote denom
+        let msg = MsgCreateConcentratedPool {
+            sender: contract_addr.to_string(),
+            denom0: base_denom.to_string(),
+            denom1: paired_base_denom.to_string(),
+            tick_spacing: self.tick_spacing,
+            spread_factor: to_osmosis_dec(self.spread_factor),
+        };
+        let submsg = SubMsg {
+            id: REPLY_ID_CREATE_PAIR,
+            msg: msg.into(),
+            gas_limit: None,
+            reply_on: ReplyOn::Always,
+        };
+        let attrs = vec![
+            Attribute::new("action", "create_pool"),
+            Attribute::new("dex", "osmosis_concentrated"),
+            Attribute::new("token_1", paired_base_denom),
+            Attribute::new("token_2", base_denom),
+            Attribute::new("tick_spacing", self.tick_spacing.to_string()),
+        ];
+        Ok((submsg, attrs))
+    }
+
+    fn created_pool(
+        &self,
+        _querier: QuerierWrapper,
+        result: SubMsgResult,
+        _paired_base_denom: &str,
+        _base_denom: &str,
+    ) -> Result<String, ContractError> {
+        let resp =
+            MsgCreateConcentratedPoolResponse::try_from(reply_data(result)?)
+                .map_err(|_| {
+                    ContractError::CannotParseResponseFromOsmosisReply {}
+                })?;
+        Ok(resp.pool_id.to_string())
+    }
+
+    fn provide_liquidity(
+        &self,
+        querier: QuerierWrapper,
+        pool: &str,
+        contract_addr: &Addr,
+        paired_base_denom: &str,
+        base_denom: &str,
+        paired_base_denom_amount: Uint128,
+        pool_supply: Uint128,
+    ) -> Result<Option<(CosmosMsg, Vec<Attribute>)>, ContractError> {
+        // pool creation fee is already charged when creating the pool
+        let paired_liquidity = paired_base_denom_amount
+            - pool_creation_fee_in_paired_denom(querier, paired_base_denom)?;
+        // first position sets the pool price, full range so it behaves like XYK
+        let (lower_tick, upper_tick) = full_range_ticks(self.tick_spacing);
+        let mut tokens_provided = vec![
+            SdkCoin {
+                denom: base_denom.to_string(),
+                amount: pool_supply.to_string(),
+            },
+            SdkCoin {
+                denom: paired_base_denom.to_string(),
+                amount: paired_liquidity.to_string(),
+            },
+        ];
+        // sdk coins must be sorted by denom
+        tokens_provided.sort_by(|a, b| a.denom.cmp(&b.denom));
+        let msg = MsgCreatePosition {
+            pool_id: parse_pool_id(pool)?,
+            sender: contract_addr.to_string(),
+            lower_tick,
+            upper_tick,
+            tokens_provided,
+            token_min_amount0: "0".to_string(),
+            token_min_amount1: "0".to_string(),
+        };
+        let attrs = vec![
+            Attribute::new("action", "provide_seed_liquidity"),
+            Attribute::new("lp_receiver", contract_addr.to_string()),
+            Attribute::new("token_1", paired_base_denom),
+            Attribute::new("token_2", base_denom),
+            Attribute::new("token_1_liquidity", paired_liquidity),
+            Attribute::new("token_2_liquidity", pool_supply),
+        ];
+        Ok(Some((msg.into(), attrs)))
+    }
+
+    fn received_lp(
+        &self,
+        _querier: QuerierWrapper,
+        _pool: &str,
+        _contract_addr: &Addr,
+        data: Option<Binary>,
+    ) -> Result<(SeedLiquidityLpToken, Uint128), ContractError> {
+        let resp = MsgCreatePositionResponse::try_from(
+            data.ok_or(ContractError::CannotParseResponseFromOsmosisReply {})?,
+        )
+        .map_err(|_| ContractError::CannotParseResponseFromOsmosisReply {})?;
+        Ok((
+            SeedLiquidityLpToken::Position {
+                position_id: resp.position_id,
+            },
+            Uint128::zero(),
+        ))
+    }
+}
+
+/// Poolmanager charges pool creation fee from coin contract, it is paid from the paired amount
+pub fn pool_creation_fee_in_paired_denom(
+    querier: QuerierWrapper,
+    paired_base_denom: &str,
+) -> Result<Uint128, ContractError> {
+    let params = PoolmanagerQuerier::new(&querier).params()?.params;
+    let mut fee = Uint128::zero();
+    for coin in params.map(|p| p.pool_creation_fee).unwrap_or_default() {
+        if coin.denom != paired_base_denom {
+            return Err(ContractError::InvalidSeedLiquidityConfig {
+                reason: format!(
+                    "pool creation fee must be paid in paired denom {paired_base_denom}"
+                ),
+            });
+        }
+        fee += Uint128::from_str(&coin.amount)?;
+    }
+    Ok(fee)
+}
+
+/// Osmosis proto Dec fields are encoded as atomics with 18 decimal places
+fn to_osmosis_dec(decimal: Decimal) -> String {
+    decimal.atomics().to_string()
+}
+
+fn parse_pool_id(pool: &str) -> StdResult<u64> {
+    pool.parse::<u64>().map_err(|_| {
+        StdError::generic_err(format!("invalid osmosis pool id {pool}"))
+    })
+}
+
+/// Widest tick range that is a multiple of tick spacing
+fn full_range_ticks(tick_spacing: u64) -> (i64, i64) {
+    let tick_spacing = tick_spacing as i64;
+    (
+        MIN_INITIALIZED_TICK / tick_spacing * tick_spacing,
+        MAX_TICK / tick_spacing * tick_spacing,
+    )
+}
+
+fn reply_data(result: SubMsgResult) -> Result<Binary, ContractError> {
+    match result {
+        SubMsgResult::Ok(resp) => resp
+            .data
+            .ok_or(ContractError::CannotParseResponseFromOsmosisReply {}),
+        SubMsgResult::Err(err) => {
+            Err(ContractError::ErrorCreatePoolInOsmosis { err })
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn full_range_ticks_are_multiples_of_tick_spacing() {
+        assert_eq!(full_range_ticks(1), (MIN_INITIALIZED_TICK, MAX_TICK));
+        assert_eq!(full_range_ticks(100), (MIN_INITIALIZED_TICK, MAX_TICK));
+        // rounds towards zero so both ticks stay inside the valid range
+        assert_eq!(full_range_ticks(7), (-107_999_997, 341_999_994));
+    }
+
+    #[test]
+    fn osmosis_dec_is_encoded_as_atomics() {
+        assert_eq!(to_osmosis_dec(Decimal::zero()), "0");
+        assert_eq!(to_osmosis_dec(Decimal::percent(3)), "30000000000000000");
+        assert_eq!(to_osmosis_dec(Decimal::one()), "1000000000000000000");
+    }
+}
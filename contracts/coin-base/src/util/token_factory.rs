@@ -1,11 +1,16 @@
-use crate::state::{CONFIG, DENOM_EXPONENT};
-use coin::{config::Config, msg::SeedLiquidityConfig};
-use cosmwasm_std::{Addr, Api, CosmosMsg, Storage, Uint128};
+use crate::state::{
+    BEFORE_SEND_HOOK_REGISTERED, CONFIG, DENOM_EXPONENT, TOTAL_BURNED,
+};
+use coin::{
+    bonding_curve::BondingCurveConfig, config::Config, msg::SeedLiquidityConfig,
+};
+use cosmwasm_std::{Addr, Api, CosmosMsg, StdResult, Storage, Uint128};
 use osmosis_std::types::{
     cosmos::bank::v1beta1::{DenomUnit, Metadata},
     cosmos::base::v1beta1::Coin as SdkCoin,
     osmosis::tokenfactory::v1beta1::{
-        MsgCreateDenom, MsgMint, MsgSetDenomMetadata,
+        MsgBurn, MsgCreateDenom, MsgMint, MsgSetBeforeSendHook,
+        MsgSetDenomMetadata,
     },
 };
 use shared_pkg::{
@@ -25,6 +30,7 @@ pub fn create_and_mint_token(
     initial_supply_in_base_denom: Uint128,
     max_supply_in_base_denom: Uint128,
     seed_liquidity_config: Option<SeedLiquidityConfig>,
+    bonding_curve_config: Option<BondingCurveConfig>,
     subdenom: &str,
     denom_description: &str,
     denom_name: &str,
@@ -72,14 +78,16 @@ pub fn create_and_mint_token(
         storage,
         &Config {
             admin_addr: admin_addr
-                .map(|addr| api.addr_validate(&addr).unwrap()),
+                .as_ref()
+                .map(|addr| api.addr_validate(addr).unwrap()),
             creator_addr: creator_addr.clone(),
             denom_metadata: metadata.clone(),
             max_supply_in_base_denom,
             seed_liquidity_config,
+            bonding_curve_config,
         },
     )?;
-    let msgs: Vec<CosmosMsg> = vec![
+    let mut msgs: Vec<CosmosMsg> = vec![
         MsgCreateDenom {
             sender: contract_addr.to_string(),
             // e.g. uatom
@@ -91,16 +99,73 @@ pub fn create_and_mint_token(
             metadata: Some(metadata),
         }
         .into(),
-        MsgMint {
-            sender: contract_addr.to_string(),
-            amount: Some(SdkCoin {
-                amount: initial_supply_in_base_denom.to_string(),
-                denom: base_denom.to_string(),
-            }),
-            mint_to_address: contract_addr.to_string(),
-        }
-        .into(),
     ];
+    // admin can freeze accounts and pause the coin, immutable coins never register it for this
+    if admin_addr.is_some() {
+        msgs.extend(register_before_send_hook(
+            storage,
+            &base_denom,
+            contract_addr,
+        )?);
+    }
+    // token factory rejects minting 0, e.g. bonding curve launch has no initial supply
+    if !initial_supply_in_base_denom.is_zero() {
+        msgs.push(
+            MsgMint {
+                sender: contract_addr.to_string(),
+                amount: Some(SdkCoin {
+                    amount: initial_supply_in_base_denom.to_string(),
+                    denom: base_denom.to_string(),
+                }),
+                mint_to_address: contract_addr.to_string(),
+            }
+            .into(),
+        );
+    }
 
     Ok((base_denom, msgs))
 }
+
+/// Register this contract as before send hook of the base denom, only the first call returns a message
+/// Transfer tax and launch protection share the hook
+pub fn register_before_send_hook(
+    storage: &mut dyn Storage,
+    base_denom: &str,
+    contract_addr: &Addr,
+) -> StdResult<Vec<CosmosMsg>> {
+    if BEFORE_SEND_HOOK_REGISTERED
+        .may_load(storage)?
+        .unwrap_or(false)
+    {
+        return Ok(vec![]);
+    }
+    BEFORE_SEND_HOOK_REGISTERED.save(storage, &true)?;
+    Ok(vec![MsgSetBeforeSendHook {
+        sender: contract_addr.to_string(),
+        denom: base_denom.to_string(),
+        cosmwasm_address: contract_addr.to_string(),
+    }
+    .into()])
+}
+
+/// Burn from an address and add the amount to total burned
+pub fn burn_msg(
+    storage: &mut dyn Storage,
+    amount: Uint128,
+    base_denom: &str,
+    contract_addr: &Addr,
+    burn_from_addr: &Addr,
+) -> StdResult<CosmosMsg> {
+    let total_burned =
+        TOTAL_BURNED.may_load(storage)?.unwrap_or_default() + amount;
+    TOTAL_BURNED.save(storage, &total_burned)?;
+    Ok(MsgBurn {
+        sender: contract_addr.to_string(),
+        amount: Some(SdkCoin {
+            amount: amount.to_string(),
+            denom: base_denom.to_string(),
+        }),
+        burn_from_address: burn_from_addr.to_string(),
+    }
+    .into())
+}
@@ -0,0 +1,125 @@
+use crate::{
+    state::{TAXED_ADDRS, TAX_EXEMPT_ADDRS, TRANSFER_TAX},
+    util::token_factory::register_before_send_hook,
+};
+use coin::transfer_tax::{
+    TaxDestination, TransferTax, TransferTaxConfig, MAX_TRANSFER_TAX_BPS,
+};
+use cosmwasm_std::{Addr, Api, CosmosMsg, Empty, Storage, Uint128};
+use shared_pkg::error::ContractError;
+
+pub const TAX_BPS_DENOMINATOR: u64 = 10_000;
+
+/// Save tax rates and destination, before send hook is registered the first time tax is set
+pub fn save_transfer_tax(
+    api: &dyn Api,
+    storage: &mut dyn Storage,
+    buy_tax_bps: u64,
+    sell_tax_bps: u64,
+    destination: TaxDestination,
+    base_denom: &str,
+    contract_addr: &Addr,
+) -> Result<Vec<CosmosMsg>, ContractError> {
+    if buy_tax_bps > MAX_TRANSFER_TAX_BPS || sell_tax_bps > MAX_TRANSFER_TAX_BPS
+    {
+        return Err(ContractError::TransferTaxTooHigh {
+            max_bps: MAX_TRANSFER_TAX_BPS,
+        });
+    }
+    if let TaxDestination::Treasury { addr } = &destination {
+        // sweeping to treasury must not be taxed again
+        TAX_EXEMPT_ADDRS.save(storage, &api.addr_validate(addr)?, &Empty {})?;
+    }
+    if !TRANSFER_TAX.exists(storage) {
+        TAX_EXEMPT_ADDRS.save(storage, contract_addr, &Empty {})?;
+    }
+    let msgs = register_before_send_hook(storage, base_denom, contract_addr)?;
+    TRANSFER_TAX.save(
+        storage,
+        &TransferTax {
+            buy_tax_bps,
+            sell_tax_bps,
+            destination,
+            renounced: false,
+        },
+    )?;
+    Ok(msgs)
+}
+
+pub fn save_transfer_tax_config(
+    api: &dyn Api,
+    storage: &mut dyn Storage,
+    config: TransferTaxConfig,
+    base_denom: &str,
+    contract_addr: &Addr,
+) -> Result<Vec<CosmosMsg>, ContractError> {
+    for addr in &config.taxed_addrs {
+        TAXED_ADDRS.save(storage, &api.addr_validate(addr)?, &Empty {})?;
+    }
+    for addr in &config.exempt_addrs {
+        TAX_EXEMPT_ADDRS.save(storage, &api.addr_validate(addr)?, &Empty {})?;
+    }
+    save_transfer_tax(
+        api,
+        storage,
+        config.buy_tax_bps,
+        config.sell_tax_bps,
+        config.destination,
+        base_denom,
+        contract_addr,
+    )
+}
+
+/// Error if transfer tax is not set or already renounced
+pub fn load_mutable_transfer_tax(
+    storage: &dyn Storage,
+) -> Result<TransferTax, ContractError> {
+    let transfer_tax = TRANSFER_TAX
+        .may_load(storage)?
+        .ok_or(ContractError::TransferTaxNotSet {})?;
+    if transfer_tax.renounced {
+        return Err(ContractError::TransferTaxRenounced {});
+    }
+    Ok(transfer_tax)
+}
+
+/// Pair created for seed liquidity or bonding curve migration is taxed if transfer tax is set
+pub fn tax_pair_if_transfer_tax_set(
+    storage: &mut dyn Storage,
+    pair_addr: &Addr,
+) -> Result<(), ContractError> {
+    if TRANSFER_TAX.exists(storage) {
+        TAXED_ADDRS.save(storage, pair_addr, &Empty {})?;
+    }
+    Ok(())
+}
+
+/// Address paying the tax and tax amount, None if the send is not taxed
+pub fn calculate_transfer_tax(
+    storage: &dyn Storage,
+    transfer_tax: &TransferTax,
+    from_addr: &Addr,
+    to_addr: &Addr,
+    amount: Uint128,
+) -> Result<Option<(Addr, Uint128)>, ContractError> {
+    if TAX_EXEMPT_ADDRS.has(storage, from_addr)
+        || TAX_EXEMPT_ADDRS.has(storage, to_addr)
+    {
+        return Ok(None);
+    }
+    let (payer_addr, tax_bps) = match (
+        TAXED_ADDRS.has(storage, from_addr),
+        TAXED_ADDRS.has(storage, to_addr),
+    ) {
+        // buy, recipient pays
+        (true, false) => (to_addr, transfer_tax.buy_tax_bps),
+        // sell, sender pays
+        (false, true) => (from_addr, transfer_tax.sell_tax_bps),
+        _ => return Ok(None),
+    };
+    let tax = amount.multiply_ratio(tax_bps, TAX_BPS_DENOMINATOR);
+    if tax.is_zero() {
+        return Ok(None);
+    }
+    Ok(Some((payer_addr.clone(), tax)))
+}
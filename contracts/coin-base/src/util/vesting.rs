@@ -0,0 +1,162 @@
+use crate::state::VESTING_SCHEDULES;
+use coin::vesting::{VestingAllocation, VestingSchedule};
+use cosmwasm_std::{Api, BlockInfo, Order, StdResult, Storage, Uint128};
+use shared_pkg::error::ContractError;
+
+/// Validate allocations and convert them to schedules starting at current block time
+pub fn build_vesting_schedules(
+    api: &dyn Api,
+    block: &BlockInfo,
+    allocations: &[VestingAllocation],
+    one_denom_in_base_denom: Uint128,
+) -> Result<Vec<VestingSchedule>, ContractError> {
+    let invalid = |reason: &str| ContractError::InvalidVestingAllocation {
+        reason: reason.to_string(),
+    };
+    let mut schedules: Vec<VestingSchedule> = vec![];
+    for allocation in allocations {
+        let recipient = api.addr_validate(&allocation.recipient)?;
+        if schedules.iter().any(|s| s.recipient == recipient) {
+            return Err(invalid("recipient can only have one allocation"));
+        }
+        if allocation.amount_in_denom.is_zero() {
+            return Err(invalid("amount must be greater than 0"));
+        }
+        if allocation.duration_seconds == 0
+            || allocation.cliff_seconds > allocation.duration_seconds
+        {
+            return Err(invalid(
+                "duration must be greater than 0 and not less than cliff",
+            ));
+        }
+        schedules.push(VestingSchedule {
+            recipient,
+            total_amount: allocation.amount_in_denom * one_denom_in_base_denom,
+            claimed_amount: Uint128::zero(),
+            start_time: block.time,
+            cliff_time: block.time.plus_seconds(allocation.cliff_seconds),
+            end_time: block.time.plus_seconds(allocation.duration_seconds),
+        });
+    }
+    Ok(schedules)
+}
+
+/// Coin recipient can claim at current block time
+pub fn calculate_claimable_vested_amount(
+    schedule: &VestingSchedule,
+    block: &BlockInfo,
+) -> Uint128 {
+    let vested_amount = if block.time < schedule.cliff_time {
+        Uint128::zero()
+    } else if block.time >= schedule.end_time {
+        schedule.total_amount
+    } else {
+        schedule.total_amount.multiply_ratio(
+            block.time.seconds() - schedule.start_time.seconds(),
+            schedule.end_time.seconds() - schedule.start_time.seconds(),
+        )
+    };
+    vested_amount.saturating_sub(schedule.claimed_amount)
+}
+
+/// Total allocated to vesting at instantiate, it is part of the supply but never sold or pooled
+pub fn total_vesting_amount(storage: &dyn Storage) -> StdResult<Uint128> {
+    VESTING_SCHEDULES
+        .range(storage, None, None, Order::Ascending)
+        .try_fold(Uint128::zero(), |total, item| {
+            Ok(total + item?.1.total_amount)
+        })
+}
+
+/// Vesting coin still held by this contract, it must not be deposited to a pool
+pub fn total_unclaimed_vesting_amount(
+    storage: &dyn Storage,
+) -> StdResult<Uint128> {
+    VESTING_SCHEDULES
+        .range(storage, None, None, Order::Ascending)
+        .try_fold(Uint128::zero(), |total, item| {
+            let schedule = item?.1;
+            Ok(total + schedule.total_amount - schedule.claimed_amount)
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cosmwasm_std::testing::{mock_env, MockApi};
+
+    fn allocation(
+        recipient: &str,
+        amount_in_denom: u128,
+        cliff_seconds: u64,
+        duration_seconds: u64,
+    ) -> VestingAllocation {
+        VestingAllocation {
+            recipient: recipient.to_string(),
+            amount_in_denom: Uint128::new(amount_in_denom),
+            cliff_seconds,
+            duration_seconds,
+        }
+    }
+
+    fn block_after(seconds: u64) -> BlockInfo {
+        let mut block = mock_env().block;
+        block.time = block.time.plus_seconds(seconds);
+        block
+    }
+
+    #[test]
+    fn vested_amount_is_zero_before_cliff_then_linear() {
+        let block = mock_env().block;
+        let mut schedule = build_vesting_schedules(
+            &MockApi::default(),
+            &block,
+            &[allocation("alice", 1_000, 100, 1_000)],
+            Uint128::new(1_000_000),
+        )
+        .unwrap()
+        .remove(0);
+        assert_eq!(schedule.total_amount, Uint128::new(1_000_000_000));
+
+        let claimable = |schedule: &VestingSchedule, seconds: u64| {
+            calculate_claimable_vested_amount(schedule, &block_after(seconds))
+        };
+        assert_eq!(claimable(&schedule, 0), Uint128::zero());
+        assert_eq!(claimable(&schedule, 99), Uint128::zero());
+        // vested amount at cliff becomes claimable at once
+        assert_eq!(claimable(&schedule, 100), Uint128::new(100_000_000));
+        assert_eq!(claimable(&schedule, 500), Uint128::new(500_000_000));
+        assert_eq!(claimable(&schedule, 1_000), Uint128::new(1_000_000_000));
+        assert_eq!(claimable(&schedule, 5_000), Uint128::new(1_000_000_000));
+
+        schedule.claimed_amount = Uint128::new(500_000_000);
+        assert_eq!(claimable(&schedule, 500), Uint128::zero());
+        assert_eq!(claimable(&schedule, 750), Uint128::new(250_000_000));
+        assert_eq!(claimable(&schedule, 1_000), Uint128::new(500_000_000));
+    }
+
+    #[test]
+    fn invalid_allocations_are_rejected() {
+        let build = |allocations: &[VestingAllocation]| {
+            build_vesting_schedules(
+                &MockApi::default(),
+                &mock_env().block,
+                allocations,
+                Uint128::new(1_000_000),
+            )
+        };
+        build(&[allocation("alice", 1, 0, 1), allocation("bob", 1, 10, 10)])
+            .unwrap();
+        for allocations in [
+            vec![allocation("alice", 1, 0, 1), allocation("alice", 1, 0, 1)],
+            vec![allocation("alice", 0, 0, 1)],
+            vec![allocation("alice", 1, 0, 0)],
+            vec![allocation("alice", 1, 11, 10)],
+        ] {
+            assert!(matches!(
+                build(&allocations).unwrap_err(),
+                ContractError::InvalidVestingAllocation { .. }
+            ));
+        }
+    }
+}
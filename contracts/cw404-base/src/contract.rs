@@ -1,49 +1,88 @@
 use crate::{
     execute::{
+        airdrop::{claim_airdrop, close_airdrop, create_airdrop},
         config::update_config,
-        ft::{burn_ft, force_transfer_ft, mint_ft},
+        ft::{burn_ft, force_transfer_ft, mint_ft, self_burn_ft},
+        hook::{add_nft_hook, remove_nft_hook},
+        metadata::{freeze_metadata, set_token_metadata, upload_trait_layers},
+        mint_group::{remove_mint_group, set_mint_group},
         nft::{
             approve_all_nft, approve_nft, burn_nft, revoke_all_nft, revoke_nft,
             send_nft, transfer_nft,
         },
+        ownership::save_admin_addr,
+        pause::{pause, unpause},
+        role::{grant_role, revoke_role},
     },
+    migrate::v0_2_0::migrate_to_v0_2_0,
     query::{
+        airdrop::{query_airdrop, query_airdrop_claimed},
         config::query_config,
         ft::{query_balance, query_supply},
+        hook::query_nft_hooks,
+        metadata::{query_metadata_frozen, query_trait_layers},
         nft::{
             query_all_nft_infos, query_all_nfts, query_all_nfts_operators,
             query_nft_approval, query_nft_approvals, query_nft_contract_info,
             query_nft_info, query_nft_num_tokens, query_nft_operator,
-            query_nft_owner, query_nfts, query_recycled_nft,
+            query_nft_owner, query_nft_status, query_nfts, query_recycled_nft,
             query_recycled_nfts,
         },
+        pause::query_pause_status,
+        role::{query_has_role, query_role_members},
+        seed_liquidity::query_seed_liquidity,
+    },
+    reply::{
+        create_pair_reply, nft_transfer_reply, provide_seed_liquidity_reply,
     },
     state::{
         CONFIG, CURRENT_NFT_SUPPLY, DENOM_EXPONENT, FEE_DENOM, MAX_NFT_SUPPLY,
-        MINT_GROUPS,
+        MINT_GROUPS, ROLE_MEMBERS, SEED_LIQUIDITY_CONFIG,
+        TRAIT_METADATA_CONFIG, TRAIT_SEED,
     },
     sudo::ft::block_before_send,
     util::{
+        airdrop::attach_airdrop,
         assert_helper::{
+            assert_has_role, assert_not_paused,
             assert_only_admin_can_call_this_function,
-            assert_only_admin_or_minter_can_mint,
         },
+        astroport::create_pair,
+        metadata::{generate_trait_seed, save_trait_layers},
         nft::parse_token_id_from_string_to_uint128,
         token_factory::create_and_mint_token,
     },
 };
 use cosmwasm_std::{
-    entry_point, to_json_binary, Binary, Deps, DepsMut, Env, MessageInfo,
-    Reply, Response, StdResult, Uint128,
+    entry_point, to_json_binary, Binary, Deps, DepsMut, Empty, Env,
+    MessageInfo, Reply, Response, StdResult, Uint128,
 };
 use cw2::set_contract_version;
-use cw404::msg::{ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg, SudoMsg};
-use cw_utils::{may_pay, nonpayable};
-use shared_pkg::error::ContractError;
+use cw404::{
+    msg::{ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg, SudoMsg},
+    pause::PauseAction,
+    role::Role,
+};
+use cw_utils::{may_pay, must_pay, nonpayable};
+use semver::Version;
+use shared_pkg::{
+    burn::{query_burn_leaderboard, query_burned_amount},
+    error::ContractError,
+    migration::assert_can_migrate,
+    ownership::{
+        accept_admin, cancel_proposal, propose_new_admin, query_ownership,
+        renounce_admin,
+    },
+};
 
 pub const CONTRACT_NAME: &str = env!("CARGO_PKG_NAME");
 pub const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
 
+pub const REPLY_ID_NFT_HOOK: u64 = 0;
+pub const REPLY_ID_CREATE_PAIR: u64 = 1;
+pub const REPLY_ID_PROVIDE_SEED_LIQUIDITY: u64 = 2;
+pub const REPLY_ID_NFT_TRANSFER: u64 = 3;
+
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn instantiate(
     deps: DepsMut,
@@ -51,7 +90,12 @@ pub fn instantiate(
     info: MessageInfo,
     msg: InstantiateMsg,
 ) -> Result<Response, ContractError> {
-    nonpayable(&info)?;
+    match &msg.seed_liquidity_config {
+        Some(cfg) => {
+            must_pay(&info, cfg.paired_base_denom.as_str())?;
+        }
+        None => nonpayable(&info)?,
+    };
     set_contract_version(
         deps.storage,
         format!("crates.io:{CONTRACT_NAME}"),
@@ -60,6 +104,32 @@ pub fn instantiate(
     let contract_info = env.contract.clone();
     let contract_addr = contract_info.address;
 
+    if let Some(cfg) = &msg.seed_liquidity_config {
+        if cfg.nft_supply.is_zero() || cfg.nft_supply > msg.max_nft_supply {
+            return Err(ContractError::InvalidSeedLiquidityConfig {
+                reason: "NFT supply for liquidity must be greater than 0 and not exceed max NFT supply".to_string(),
+            });
+        }
+        deps.api.addr_validate(&cfg.astroport_factory_addr)?;
+        SEED_LIQUIDITY_CONFIG.save(deps.storage, cfg)?;
+    }
+    if let Some(airdrop) = &msg.airdrop {
+        let one_denom_in_base_denom = Uint128::from(10u128.pow(DENOM_EXPONENT));
+        let seed_liquidity_nft_supply = msg
+            .seed_liquidity_config
+            .as_ref()
+            .map(|cfg| cfg.nft_supply)
+            .unwrap_or_default();
+        if seed_liquidity_nft_supply * one_denom_in_base_denom
+            + airdrop.total_amount
+            > msg.max_nft_supply * one_denom_in_base_denom
+        {
+            return Err(ContractError::InvalidAirdropConfig {
+                reason: "seed liquidity NFT supply plus airdrop must not exceed max NFT supply".to_string(),
+            });
+        }
+    }
+
     MAX_NFT_SUPPLY.save(deps.storage, &msg.max_nft_supply)?;
     CURRENT_NFT_SUPPLY.save(deps.storage, &Uint128::zero())?;
 
@@ -76,12 +146,29 @@ pub fn instantiate(
         )?;
     }
 
+    TRAIT_SEED.save(
+        deps.storage,
+        &generate_trait_seed(&contract_addr, &env.block),
+    )?;
+    if let Some(trait_metadata) = msg.trait_metadata {
+        TRAIT_METADATA_CONFIG.save(deps.storage, &trait_metadata.config)?;
+        save_trait_layers(deps.storage, trait_metadata.trait_layers)?;
+    }
+
+    ROLE_MEMBERS.save(
+        deps.storage,
+        (
+            Role::Minter {}.as_str(),
+            &deps.api.addr_validate(&msg.minter_addr)?,
+        ),
+        &Empty {},
+    )?;
+
     let create_and_mint_token_msgs = create_and_mint_token(
         deps.api,
         deps.storage,
         &contract_addr,
         msg.admin_addr.clone(),
-        &deps.api.addr_validate(&msg.minter_addr)?,
         &deps.api.addr_validate(&msg.creator_addr)?,
         &deps.api.addr_validate(&msg.royalty_payment_addr)?,
         msg.royalty_percentage,
@@ -93,8 +180,39 @@ pub fn instantiate(
         msg.denom_uri_hash.as_str(),
     )?;
 
+    let mut mint_airdrop_msgs = vec![];
+    if let Some(airdrop_config) = msg.airdrop {
+        let base_denom = CONFIG.load(deps.storage)?.denom_metadata.base;
+        let (_, mint_msg) = attach_airdrop(
+            deps.api,
+            deps.storage,
+            &env.block,
+            airdrop_config,
+            &base_denom,
+            &contract_addr,
+        )?;
+        mint_airdrop_msgs.push(mint_msg);
+    }
+
+    // pair is created after denom is created, reserved supply is minted in reply
+    let (create_pair_submsg, create_pair_attributes) =
+        match &msg.seed_liquidity_config {
+            Some(cfg) => {
+                let (submsg, attributes) = create_pair(
+                    &deps.api.addr_validate(&cfg.astroport_factory_addr)?,
+                    &cfg.paired_base_denom,
+                    &CONFIG.load(deps.storage)?.denom_metadata.base,
+                )?;
+                (vec![submsg], attributes)
+            }
+            None => (vec![], vec![]),
+        };
+
     Ok(Response::new()
         .add_messages(create_and_mint_token_msgs)
+        .add_messages(mint_airdrop_msgs)
+        .add_submessages(create_pair_submsg)
+        .add_attributes(create_pair_attributes)
         .add_attribute("action", "instantiate")
         .add_attribute("contract_addr", env.contract.address)
         .add_attribute("subdenom", msg.subdenom)
@@ -125,26 +243,184 @@ pub fn execute(
     let base_denom = config_ref.denom_metadata.base.as_str();
     match msg {
         ExecuteMsg::UpdateConfig {
-            new_admin_addr,
-            new_minter_addr,
             new_royalty_payment_addr,
             new_royalty_percentage,
         } => {
             nonpayable(info_ref)?;
-            assert_only_admin_can_call_this_function(
+            assert_has_role(
+                deps.storage,
                 sender_addr_ref,
                 &config_ref.admin_addr,
-                "update_admin",
+                &Role::RoyaltyManager {},
             )?;
             update_config(
                 deps.api,
                 deps.storage,
-                new_admin_addr,
-                new_minter_addr,
                 new_royalty_payment_addr,
                 new_royalty_percentage,
             )
         }
+        ExecuteMsg::ProposeNewAdmin {
+            new_admin_addr,
+            expiry,
+        } => {
+            nonpayable(info_ref)?;
+            assert_only_admin_can_call_this_function(
+                sender_addr_ref,
+                &config_ref.admin_addr,
+                "propose_new_admin",
+            )?;
+            propose_new_admin(
+                deps.api,
+                deps.storage,
+                &env.block,
+                new_admin_addr,
+                expiry,
+            )
+        }
+        ExecuteMsg::AcceptAdmin {} => {
+            nonpayable(info_ref)?;
+            accept_admin(
+                deps.storage,
+                &env.block,
+                sender_addr_ref,
+                save_admin_addr,
+            )
+        }
+        ExecuteMsg::CancelProposal {} => {
+            nonpayable(info_ref)?;
+            assert_only_admin_can_call_this_function(
+                sender_addr_ref,
+                &config_ref.admin_addr,
+                "cancel_proposal",
+            )?;
+            cancel_proposal(deps.storage)
+        }
+        ExecuteMsg::RenounceAdmin {} => {
+            nonpayable(info_ref)?;
+            assert_only_admin_can_call_this_function(
+                sender_addr_ref,
+                &config_ref.admin_addr,
+                "renounce_admin",
+            )?;
+            renounce_admin(deps.storage, save_admin_addr)
+        }
+        ExecuteMsg::GrantRole { role, addr } => {
+            nonpayable(info_ref)?;
+            assert_only_admin_can_call_this_function(
+                sender_addr_ref,
+                &config_ref.admin_addr,
+                "grant_role",
+            )?;
+            grant_role(deps.storage, role, &deps.api.addr_validate(&addr)?)
+        }
+        ExecuteMsg::RevokeRole { role, addr } => {
+            nonpayable(info_ref)?;
+            assert_only_admin_can_call_this_function(
+                sender_addr_ref,
+                &config_ref.admin_addr,
+                "revoke_role",
+            )?;
+            revoke_role(deps.storage, role, &deps.api.addr_validate(&addr)?)
+        }
+        ExecuteMsg::SetMintGroup { mint_group } => {
+            nonpayable(info_ref)?;
+            assert_has_role(
+                deps.storage,
+                sender_addr_ref,
+                &config_ref.admin_addr,
+                &Role::MintGroupManager {},
+            )?;
+            set_mint_group(deps.storage, mint_group)
+        }
+        ExecuteMsg::RemoveMintGroup { name } => {
+            nonpayable(info_ref)?;
+            assert_has_role(
+                deps.storage,
+                sender_addr_ref,
+                &config_ref.admin_addr,
+                &Role::MintGroupManager {},
+            )?;
+            remove_mint_group(deps.storage, name.as_str())
+        }
+        ExecuteMsg::Pause { action } => {
+            nonpayable(info_ref)?;
+            assert_has_role(
+                deps.storage,
+                sender_addr_ref,
+                &config_ref.admin_addr,
+                &Role::Pauser {},
+            )?;
+            pause(deps.storage, action)
+        }
+        ExecuteMsg::Unpause { action } => {
+            nonpayable(info_ref)?;
+            assert_only_admin_can_call_this_function(
+                sender_addr_ref,
+                &config_ref.admin_addr,
+                "unpause",
+            )?;
+            unpause(deps.storage, action)
+        }
+        ExecuteMsg::UploadTraitLayers {
+            trait_metadata_config,
+            trait_layers,
+        } => {
+            nonpayable(info_ref)?;
+            assert_has_role(
+                deps.storage,
+                sender_addr_ref,
+                &config_ref.admin_addr,
+                &Role::MetadataManager {},
+            )?;
+            upload_trait_layers(
+                deps.storage,
+                trait_metadata_config,
+                trait_layers,
+            )
+        }
+        ExecuteMsg::SetTokenMetadata { entries } => {
+            nonpayable(info_ref)?;
+            assert_has_role(
+                deps.storage,
+                sender_addr_ref,
+                &config_ref.admin_addr,
+                &Role::MetadataManager {},
+            )?;
+            set_token_metadata(deps.storage, entries)
+        }
+        ExecuteMsg::FreezeMetadata {} => {
+            nonpayable(info_ref)?;
+            assert_has_role(
+                deps.storage,
+                sender_addr_ref,
+                &config_ref.admin_addr,
+                &Role::MetadataManager {},
+            )?;
+            freeze_metadata(deps.storage)
+        }
+        ExecuteMsg::AddNftHook { hook_addr, watch } => {
+            nonpayable(info_ref)?;
+            assert_only_admin_can_call_this_function(
+                sender_addr_ref,
+                &config_ref.admin_addr,
+                "add_nft_hook",
+            )?;
+            add_nft_hook(
+                deps.storage,
+                &deps.api.addr_validate(&hook_addr)?,
+                watch,
+            )
+        }
+        ExecuteMsg::RemoveNftHook { hook_addr } => {
+            nonpayable(info_ref)?;
+            assert_only_admin_can_call_this_function(
+                sender_addr_ref,
+                &config_ref.admin_addr,
+                "remove_nft_hook",
+            )?;
+            remove_nft_hook(deps.storage, &deps.api.addr_validate(&hook_addr)?)
+        }
         // ======== FT (cosmos sdk native coin) functions ==========
         ExecuteMsg::MintFt {
             amount,
@@ -153,10 +429,12 @@ pub fn execute(
             merkle_proof,
         } => {
             let user_paid_amount = may_pay(info_ref, FEE_DENOM)?;
-            assert_only_admin_or_minter_can_mint(
+            assert_not_paused(deps.storage, &PauseAction::Minting {})?;
+            assert_has_role(
+                deps.storage,
                 sender_addr_ref,
                 &config_ref.admin_addr,
-                &config_ref.minter_addr,
+                &Role::Minter {},
             )?;
             mint_ft(
                 deps.storage,
@@ -190,6 +468,18 @@ pub fn execute(
                 contract_addr_ref,
             )
         }
+        ExecuteMsg::SelfBurnFt {} => {
+            let amount = must_pay(info_ref, base_denom)?;
+            self_burn_ft(
+                deps.storage,
+                deps.querier,
+                amount,
+                one_denom_in_base_denom,
+                base_denom,
+                contract_addr_ref,
+                sender_addr_ref,
+            )
+        }
         ExecuteMsg::ForceTransferFt { amount, from, to } => {
             nonpayable(info_ref)?;
             assert_only_admin_can_call_this_function(
@@ -209,6 +499,49 @@ pub fn execute(
                 &deps.api.addr_validate(&to)?,
             )
         }
+        ExecuteMsg::CreateAirdrop { airdrop } => {
+            nonpayable(info_ref)?;
+            assert_only_admin_can_call_this_function(
+                sender_addr_ref,
+                &config_ref.admin_addr,
+                "create_airdrop",
+            )?;
+            create_airdrop(
+                deps.api,
+                deps.storage,
+                deps.querier,
+                &env.block,
+                airdrop,
+                one_denom_in_base_denom,
+                base_denom,
+                contract_addr_ref,
+            )
+        }
+        ExecuteMsg::ClaimAirdrop {
+            index,
+            amount,
+            merkle_proof,
+        } => {
+            nonpayable(info_ref)?;
+            claim_airdrop(
+                deps.storage,
+                &env.block,
+                base_denom,
+                sender_addr_ref,
+                index,
+                amount,
+                merkle_proof,
+            )
+        }
+        ExecuteMsg::CloseAirdrop {} => {
+            nonpayable(info_ref)?;
+            close_airdrop(
+                deps.storage,
+                &env.block,
+                base_denom,
+                contract_addr_ref,
+            )
+        }
         // ======== NFT (cw721) functions ==========
         ExecuteMsg::Approve {
             spender,
@@ -258,6 +591,7 @@ pub fn execute(
             token_id,
         } => {
             nonpayable(info_ref)?;
+            assert_not_paused(deps.storage, &PauseAction::NftTransfers {})?;
             transfer_nft(
                 deps.storage,
                 &env.block,
@@ -275,6 +609,7 @@ pub fn execute(
             msg,
         } => {
             nonpayable(info_ref)?;
+            assert_not_paused(deps.storage, &PauseAction::NftTransfers {})?;
             send_nft(
                 deps.storage,
                 &env.block,
@@ -310,6 +645,30 @@ pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
     match msg {
         // ======== general functions ==========
         QueryMsg::Config {} => to_json_binary(&query_config(config_ref)?),
+        QueryMsg::Ownership {} => to_json_binary(&query_ownership(
+            deps.storage,
+            config_ref.admin_addr.clone(),
+        )?),
+        QueryMsg::HasRole { role, addr } => to_json_binary(&query_has_role(
+            deps.storage,
+            config_ref,
+            &role,
+            &deps.api.addr_validate(&addr)?,
+        )?),
+        QueryMsg::RoleMembers {
+            role,
+            start_after,
+            limit,
+        } => to_json_binary(&query_role_members(
+            deps.api,
+            deps.storage,
+            &role,
+            start_after,
+            limit,
+        )?),
+        QueryMsg::PauseStatus {} => {
+            to_json_binary(&query_pause_status(deps.storage)?)
+        }
         QueryMsg::RecycledNftTokenIds {
             start_after_idx,
             limit,
@@ -321,6 +680,25 @@ pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
         QueryMsg::RecycledNftInfo { token_id } => {
             to_json_binary(&query_recycled_nft(deps.storage, token_id)?)
         }
+        QueryMsg::NftStatus { token_id } => {
+            to_json_binary(&query_nft_status(deps.storage, token_id)?)
+        }
+        QueryMsg::TraitLayers {} => {
+            to_json_binary(&query_trait_layers(deps.storage)?)
+        }
+        QueryMsg::MetadataFrozen {} => {
+            to_json_binary(&query_metadata_frozen(deps.storage)?)
+        }
+        QueryMsg::NftHooks {} => {
+            to_json_binary(&query_nft_hooks(deps.storage)?)
+        }
+        QueryMsg::SeedLiquidity {} => {
+            to_json_binary(&query_seed_liquidity(deps.storage)?)
+        }
+        QueryMsg::Airdrop {} => to_json_binary(&query_airdrop(deps.storage)?),
+        QueryMsg::AirdropClaimed { index } => {
+            to_json_binary(&query_airdrop_claimed(deps.storage, index)?)
+        }
         QueryMsg::Supply {} => to_json_binary({
             &query_supply(
                 deps.querier,
@@ -335,6 +713,20 @@ pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
             base_denom,
             one_denom_in_base_denom,
         )?),
+        QueryMsg::BurnedAmount { addr } => {
+            to_json_binary(&query_burned_amount(
+                deps.storage,
+                &deps.api.addr_validate(&addr)?,
+            )?)
+        }
+        QueryMsg::BurnLeaderboard { start_after, limit } => {
+            to_json_binary(&query_burn_leaderboard(
+                deps.api,
+                deps.storage,
+                start_after,
+                limit,
+            )?)
+        }
         // ======== NFT functions ==========
         QueryMsg::OwnerOf {
             token_id,
@@ -427,20 +819,46 @@ pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
 
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn migrate(
-    _deps: DepsMut,
-    _env: Env,
+    deps: DepsMut,
+    env: Env,
     _msg: MigrateMsg,
 ) -> Result<Response, ContractError> {
-    Ok(Response::new())
+    let contract_name = format!("crates.io:{CONTRACT_NAME}");
+    let stored_version =
+        assert_can_migrate(deps.storage, &contract_name, CONTRACT_VERSION)?;
+    if stored_version < Version::new(0, 2, 0) {
+        migrate_to_v0_2_0(deps.storage, &env)?;
+    }
+    set_contract_version(deps.storage, contract_name, CONTRACT_VERSION)?;
+    Ok(Response::new()
+        .add_attribute("action", "migrate")
+        .add_attribute("from_version", stored_version.to_string())
+        .add_attribute("to_version", CONTRACT_VERSION))
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn reply(
-    _deps: DepsMut,
-    _env: Env,
-    _msg: Reply,
+    deps: DepsMut,
+    env: Env,
+    msg: Reply,
 ) -> Result<Response, ContractError> {
-    Ok(Response::new())
+    match msg.id {
+        // hook contract failed, swallow the error so NFT ownership change still goes through
+        REPLY_ID_NFT_HOOK => {
+            Ok(Response::new().add_attribute("action", "nft_hook_failed"))
+        }
+        REPLY_ID_CREATE_PAIR => create_pair_reply(
+            deps.querier,
+            deps.storage,
+            msg,
+            &env.contract.address,
+        ),
+        REPLY_ID_PROVIDE_SEED_LIQUIDITY => {
+            provide_seed_liquidity_reply(deps.storage, &env.contract.address)
+        }
+        REPLY_ID_NFT_TRANSFER => nft_transfer_reply(deps.storage),
+        _ => Err(ContractError::UnknownReplyId { reply_id: msg.id }),
+    }
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
@@ -464,3 +882,226 @@ pub fn sudo(
         ),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cosmwasm_std::{
+        from_json,
+        testing::{
+            mock_dependencies, mock_env, mock_info, MockApi, MockQuerier,
+            MockStorage,
+        },
+        Addr, OwnedDeps, Uint64,
+    };
+    use cw404::{
+        config::Config,
+        msg::{HasRoleResponse, PauseStatusResponse, RoleMembersResponse},
+    };
+    use osmosis_std::types::cosmos::bank::v1beta1::Metadata as DenomMetadata;
+
+    fn setup() -> OwnedDeps<MockStorage, MockApi, MockQuerier> {
+        let mut deps = mock_dependencies();
+        CONFIG
+            .save(
+                &mut deps.storage,
+                &Config {
+                    admin_addr: Some(Addr::unchecked("admin")),
+                    creator_addr: Addr::unchecked("creator"),
+                    denom_metadata: DenomMetadata {
+                        base: "factory/cw404/ucoin".to_string(),
+                        ..DenomMetadata::default()
+                    },
+                    royalty_payment_addr: Addr::unchecked("creator"),
+                    royalty_percentage: Uint64::new(5),
+                },
+            )
+            .unwrap();
+        deps
+    }
+
+    fn exec(
+        deps: &mut OwnedDeps<MockStorage, MockApi, MockQuerier>,
+        sender: &str,
+        msg: ExecuteMsg,
+    ) -> Result<Response, ContractError> {
+        execute(deps.as_mut(), mock_env(), mock_info(sender, &[]), msg)
+    }
+
+    fn grant(role: Role, addr: &str) -> ExecuteMsg {
+        ExecuteMsg::GrantRole {
+            role,
+            addr: addr.to_string(),
+        }
+    }
+
+    fn update_royalty() -> ExecuteMsg {
+        ExecuteMsg::UpdateConfig {
+            new_royalty_payment_addr: None,
+            new_royalty_percentage: Some(Uint64::new(10)),
+        }
+    }
+
+    fn has_role(
+        deps: &OwnedDeps<MockStorage, MockApi, MockQuerier>,
+        role: Role,
+        addr: &str,
+    ) -> bool {
+        let res: HasRoleResponse = from_json(
+            query(
+                deps.as_ref(),
+                mock_env(),
+                QueryMsg::HasRole {
+                    role,
+                    addr: addr.to_string(),
+                },
+            )
+            .unwrap(),
+        )
+        .unwrap();
+        res.has_role
+    }
+
+    #[test]
+    fn only_admin_grants_and_revokes_roles() {
+        let mut deps = setup();
+        assert!(matches!(
+            exec(&mut deps, "alice", grant(Role::Pauser {}, "alice"))
+                .unwrap_err(),
+            ContractError::OnlyAdminCanCallThisFunction { .. }
+        ));
+        exec(&mut deps, "admin", grant(Role::Pauser {}, "alice")).unwrap();
+        exec(&mut deps, "admin", grant(Role::Pauser {}, "bob")).unwrap();
+        assert!(has_role(&deps, Role::Pauser {}, "alice"));
+        assert!(!has_role(&deps, Role::Minter {}, "alice"));
+        // admin holds every role without being listed
+        assert!(has_role(&deps, Role::Minter {}, "admin"));
+        let members: RoleMembersResponse = from_json(
+            query(
+                deps.as_ref(),
+                mock_env(),
+                QueryMsg::RoleMembers {
+                    role: Role::Pauser {},
+                    start_after: None,
+                    limit: None,
+                },
+            )
+            .unwrap(),
+        )
+        .unwrap();
+        assert_eq!(
+            members.members,
+            vec![Addr::unchecked("alice"), Addr::unchecked("bob")]
+        );
+
+        assert!(matches!(
+            exec(
+                &mut deps,
+                "alice",
+                ExecuteMsg::RevokeRole {
+                    role: Role::Pauser {},
+                    addr: "bob".to_string(),
+                },
+            )
+            .unwrap_err(),
+            ContractError::OnlyAdminCanCallThisFunction { .. }
+        ));
+        exec(
+            &mut deps,
+            "admin",
+            ExecuteMsg::RevokeRole {
+                role: Role::Pauser {},
+                addr: "alice".to_string(),
+            },
+        )
+        .unwrap();
+        assert!(!has_role(&deps, Role::Pauser {}, "alice"));
+        assert!(has_role(&deps, Role::Pauser {}, "bob"));
+    }
+
+    #[test]
+    fn execute_is_gated_by_role() {
+        let mut deps = setup();
+        assert!(matches!(
+            exec(&mut deps, "alice", update_royalty()).unwrap_err(),
+            ContractError::MissingRole { role } if role == "royalty_manager"
+        ));
+        // another role does not help
+        exec(&mut deps, "admin", grant(Role::Pauser {}, "alice")).unwrap();
+        assert!(matches!(
+            exec(&mut deps, "alice", update_royalty()).unwrap_err(),
+            ContractError::MissingRole { .. }
+        ));
+        exec(&mut deps, "admin", grant(Role::RoyaltyManager {}, "alice"))
+            .unwrap();
+        exec(&mut deps, "alice", update_royalty()).unwrap();
+        exec(&mut deps, "admin", update_royalty()).unwrap();
+        assert!(matches!(
+            exec(
+                &mut deps,
+                "alice",
+                ExecuteMsg::RemoveMintGroup {
+                    name: "public".to_string(),
+                },
+            )
+            .unwrap_err(),
+            ContractError::MissingRole { role } if role == "mint_group_manager"
+        ));
+    }
+
+    #[test]
+    fn pauser_pauses_and_only_admin_unpauses() {
+        let mut deps = setup();
+        let pause = |action| ExecuteMsg::Pause { action };
+        let unpause = |action| ExecuteMsg::Unpause { action };
+        let mint_ft = || ExecuteMsg::MintFt {
+            amount: Uint128::new(1),
+            recipient: "admin".to_string(),
+            mint_group_name: "public".to_string(),
+            merkle_proof: None,
+        };
+        assert!(matches!(
+            exec(&mut deps, "alice", pause(PauseAction::Minting {}))
+                .unwrap_err(),
+            ContractError::MissingRole { role } if role == "pauser"
+        ));
+        exec(&mut deps, "admin", grant(Role::Pauser {}, "alice")).unwrap();
+        exec(&mut deps, "alice", pause(PauseAction::Minting {})).unwrap();
+        exec(&mut deps, "alice", pause(PauseAction::NftTransfers {})).unwrap();
+        let status: PauseStatusResponse = from_json(
+            query(deps.as_ref(), mock_env(), QueryMsg::PauseStatus {}).unwrap(),
+        )
+        .unwrap();
+        assert!(status.minting_paused && status.nft_transfers_paused);
+
+        // admin holds every role but is still blocked by pause
+        assert!(matches!(
+            exec(&mut deps, "admin", mint_ft()).unwrap_err(),
+            ContractError::Paused { action } if action == "minting"
+        ));
+        assert!(matches!(
+            exec(
+                &mut deps,
+                "alice",
+                ExecuteMsg::TransferNft {
+                    recipient: "bob".to_string(),
+                    token_id: "1".to_string(),
+                },
+            )
+            .unwrap_err(),
+            ContractError::Paused { action } if action == "nft_transfers"
+        ));
+
+        assert!(matches!(
+            exec(&mut deps, "alice", unpause(PauseAction::Minting {}))
+                .unwrap_err(),
+            ContractError::OnlyAdminCanCallThisFunction { .. }
+        ));
+        exec(&mut deps, "admin", unpause(PauseAction::Minting {})).unwrap();
+        let status: PauseStatusResponse = from_json(
+            query(deps.as_ref(), mock_env(), QueryMsg::PauseStatus {}).unwrap(),
+        )
+        .unwrap();
+        assert!(!status.minting_paused && status.nft_transfers_paused);
+    }
+}
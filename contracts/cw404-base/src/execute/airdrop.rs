@@ -0,0 +1,154 @@
+use crate::{
+    state::{AIRDROP, AIRDROP_CLAIMED_BITMAP, MAX_NFT_SUPPLY},
+    util::{
+        airdrop::attach_airdrop,
+        assert_helper::assert_max_base_denom_supply_not_reached,
+    },
+};
+use cosmwasm_std::{
+    coins, Addr, Api, BankMsg, BlockInfo, CosmosMsg, QuerierWrapper, Response,
+    Storage, Uint128,
+};
+use osmosis_std::types::{
+    cosmos::base::v1beta1::Coin as SdkCoin,
+    osmosis::tokenfactory::v1beta1::MsgBurn,
+};
+use shared_pkg::{
+    airdrop::{
+        airdrop_claim_bit, airdrop_unclaimed_amount, assert_can_claim_airdrop,
+        AirdropConfig,
+    },
+    error::ContractError,
+};
+
+pub fn create_airdrop(
+    api: &dyn Api,
+    storage: &mut dyn Storage,
+    querier: QuerierWrapper,
+    block: &BlockInfo,
+    airdrop_config: AirdropConfig,
+    one_denom_in_base_denom: Uint128,
+    base_denom: &str,
+    contract_addr: &Addr,
+) -> Result<Response, ContractError> {
+    assert_max_base_denom_supply_not_reached(
+        querier.query_supply(base_denom)?.amount,
+        MAX_NFT_SUPPLY.load(storage)? * one_denom_in_base_denom,
+        airdrop_config.total_amount,
+    )?;
+    let (airdrop, mint_msg) = attach_airdrop(
+        api,
+        storage,
+        block,
+        airdrop_config,
+        base_denom,
+        contract_addr,
+    )?;
+    Ok(Response::new()
+        .add_message(mint_msg)
+        .add_attribute("token_type", "ft")
+        .add_attribute("action", "create_airdrop")
+        .add_attribute("round", airdrop.round.to_string())
+        .add_attribute("total_amount", airdrop.total_amount)
+        .add_attribute("expiration", airdrop.expiration.to_string()))
+}
+
+/// NFTs are minted to claimer by before send hook
+pub fn claim_airdrop(
+    storage: &mut dyn Storage,
+    block: &BlockInfo,
+    base_denom: &str,
+    claimer_addr: &Addr,
+    index: u32,
+    amount: Uint128,
+    merkle_proof: Vec<Vec<u8>>,
+) -> Result<Response, ContractError> {
+    let mut airdrop = AIRDROP
+        .may_load(storage)?
+        .ok_or(ContractError::NoAirdrop {})?;
+    let (word_index, bit) = airdrop_claim_bit(index);
+    let bitmap_key = (airdrop.round, word_index);
+    let claimed_bitmap_word = AIRDROP_CLAIMED_BITMAP
+        .may_load(storage, bitmap_key)?
+        .unwrap_or_default();
+    assert_can_claim_airdrop(
+        &airdrop,
+        block,
+        claimed_bitmap_word,
+        index,
+        claimer_addr,
+        amount,
+        merkle_proof,
+    )?;
+    AIRDROP_CLAIMED_BITMAP.save(
+        storage,
+        bitmap_key,
+        &(claimed_bitmap_word | bit),
+    )?;
+    airdrop.claimed_amount += amount;
+    AIRDROP.save(storage, &airdrop)?;
+    Ok(Response::new()
+        .add_message(BankMsg::Send {
+            to_address: claimer_addr.to_string(),
+            amount: coins(amount.u128(), base_denom),
+        })
+        .add_attribute("token_type", "ft")
+        .add_attribute("action", "claim_airdrop")
+        .add_attribute("round", airdrop.round.to_string())
+        .add_attribute("index", index.to_string())
+        .add_attribute("claimer", claimer_addr)
+        .add_attribute("amount", amount))
+}
+
+/// Return unclaimed amount to unclaimed recipient or burn it after expiration
+pub fn close_airdrop(
+    storage: &mut dyn Storage,
+    block: &BlockInfo,
+    base_denom: &str,
+    contract_addr: &Addr,
+) -> Result<Response, ContractError> {
+    let mut airdrop = AIRDROP
+        .may_load(storage)?
+        .ok_or(ContractError::NoAirdrop {})?;
+    if airdrop.closed {
+        return Err(ContractError::AirdropExpired {});
+    }
+    if block.time < airdrop.expiration {
+        return Err(ContractError::AirdropNotExpired {});
+    }
+    let unclaimed_amount = airdrop_unclaimed_amount(&airdrop);
+    airdrop.closed = true;
+    AIRDROP.save(storage, &airdrop)?;
+    let mut msgs: Vec<CosmosMsg> = vec![];
+    if !unclaimed_amount.is_zero() {
+        msgs.push(match &airdrop.unclaimed_recipient {
+            Some(recipient_addr) => BankMsg::Send {
+                to_address: recipient_addr.to_string(),
+                amount: coins(unclaimed_amount.u128(), base_denom),
+            }
+            .into(),
+            None => MsgBurn {
+                sender: contract_addr.to_string(),
+                amount: Some(SdkCoin {
+                    amount: unclaimed_amount.to_string(),
+                    denom: base_denom.to_string(),
+                }),
+                burn_from_address: contract_addr.to_string(),
+            }
+            .into(),
+        });
+    }
+    Ok(Response::new()
+        .add_messages(msgs)
+        .add_attribute("token_type", "ft")
+        .add_attribute("action", "close_airdrop")
+        .add_attribute("round", airdrop.round.to_string())
+        .add_attribute("unclaimed_amount", unclaimed_amount)
+        .add_attribute(
+            "unclaimed_recipient",
+            match airdrop.unclaimed_recipient {
+                Some(addr) => addr.to_string(),
+                None => "burned".to_string(),
+            },
+        ))
+}
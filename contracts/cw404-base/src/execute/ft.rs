@@ -1,8 +1,14 @@
 use crate::{
     state::FEE_DENOM,
-    util::nft::{
-        assert_can_mint, batch_burn_nft, batch_mint_nft,
-        calculate_nft_to_burn_for_ft_burn, calculate_nft_to_mint_for_ft_mint,
+    util::{
+        hook::{
+            nft_burn_changes, nft_mint_changes, notify_nft_ownership_changes,
+        },
+        nft::{
+            assert_can_mint, batch_burn_nft, batch_mint_nft,
+            calculate_nft_to_burn_for_ft_burn,
+            calculate_nft_to_mint_for_ft_mint, is_nft_exempt,
+        },
     },
 };
 use cosmwasm_std::{
@@ -13,7 +19,7 @@ use osmosis_std::types::{
     cosmos::base::v1beta1::Coin as SdkCoin,
     osmosis::tokenfactory::v1beta1::{MsgBurn, MsgForceTransfer, MsgMint},
 };
-use shared_pkg::error::ContractError;
+use shared_pkg::{burn::record_self_burn, error::ContractError};
 
 pub fn mint_ft(
     storage: &mut dyn Storage,
@@ -42,14 +48,24 @@ pub fn mint_ft(
         mint_group_name,
         merkle_proof,
     )?;
-    let mint_nft_amount = calculate_nft_to_mint_for_ft_mint(
-        querier,
-        contract_addr,
-        base_denom,
-        mint_amount,
-        one_denom_in_base_denom,
+    // exempt contract passes FT to recipient without holding NFTs, before send hook mints them to recipient
+    let mint_nft_amount = if is_nft_exempt(storage, contract_addr)? {
+        Uint128::zero()
+    } else {
+        calculate_nft_to_mint_for_ft_mint(
+            querier,
+            contract_addr,
+            base_denom,
+            mint_amount,
+            one_denom_in_base_denom,
+        )?
+    };
+    let minted_token_ids =
+        batch_mint_nft(storage, base_uri, contract_addr, mint_nft_amount)?;
+    let (nft_events, nft_hook_submsgs) = notify_nft_ownership_changes(
+        storage,
+        nft_mint_changes(contract_addr, &minted_token_ids),
     )?;
-    batch_mint_nft(storage, base_uri, contract_addr, mint_nft_amount)?;
     let mint_ft_msg = MsgMint {
         sender: contract_addr.to_string(),
         amount: Some(SdkCoin {
@@ -72,6 +88,8 @@ pub fn mint_ft(
     Ok(Response::new()
         .add_message(mint_ft_msg)
         .add_messages(bank_msgs)
+        .add_submessages(nft_hook_submsgs)
+        .add_events(nft_events)
         .add_attribute("token_type", "ft")
         .add_attribute("action", "mint_ft")
         .add_attribute("amount", mint_amount)
@@ -86,14 +104,23 @@ pub fn burn_ft(
     base_denom: &str,
     contract_addr: &Addr,
 ) -> Result<Response, ContractError> {
-    let burn_nft_amount = calculate_nft_to_burn_for_ft_burn(
-        querier,
-        contract_addr,
-        base_denom,
-        amount,
-        one_denom_in_base_denom,
+    let burn_nft_amount = if is_nft_exempt(storage, contract_addr)? {
+        Uint128::zero()
+    } else {
+        calculate_nft_to_burn_for_ft_burn(
+            querier,
+            contract_addr,
+            base_denom,
+            amount,
+            one_denom_in_base_denom,
+        )?
+    };
+    let burned_token_ids =
+        batch_burn_nft(storage, contract_addr, burn_nft_amount)?;
+    let (nft_events, nft_hook_submsgs) = notify_nft_ownership_changes(
+        storage,
+        nft_burn_changes(contract_addr, &burned_token_ids),
     )?;
-    batch_burn_nft(storage, contract_addr, burn_nft_amount)?;
     let msg = MsgBurn {
         sender: contract_addr.to_string(),
         amount: Some(SdkCoin {
@@ -105,12 +132,37 @@ pub fn burn_ft(
     };
     Ok(Response::new()
         .add_message(msg)
+        .add_submessages(nft_hook_submsgs)
+        .add_events(nft_events)
         .add_attribute("token_type", "ft")
         .add_attribute("action", "burn_ft")
         .add_attribute("amount", amount)
         .add_attribute("burn_nft_amount", burn_nft_amount))
 }
 
+/// FT is already paid into this contract, before send hook recycled burner's NFTs on the way in
+pub fn self_burn_ft(
+    storage: &mut dyn Storage,
+    querier: QuerierWrapper,
+    amount: Uint128,
+    one_denom_in_base_denom: Uint128,
+    base_denom: &str,
+    contract_addr: &Addr,
+    burner_addr: &Addr,
+) -> Result<Response, ContractError> {
+    let total_self_burned = record_self_burn(storage, burner_addr, amount)?;
+    Ok(burn_ft(
+        storage,
+        querier,
+        amount,
+        one_denom_in_base_denom,
+        base_denom,
+        contract_addr,
+    )?
+    .add_attribute("burner", burner_addr)
+    .add_attribute("total_self_burned", total_self_burned))
+}
+
 pub fn force_transfer_ft(
     storage: &mut dyn Storage,
     querier: QuerierWrapper,
@@ -122,22 +174,35 @@ pub fn force_transfer_ft(
     from_addr: &Addr,
     to_addr: &Addr,
 ) -> Result<Response, ContractError> {
-    let burn_nft_amount = calculate_nft_to_burn_for_ft_burn(
-        querier,
-        from_addr,
-        base_denom,
-        amount,
-        one_denom_in_base_denom,
-    )?;
-    batch_burn_nft(storage, from_addr, burn_nft_amount)?;
-    let mint_nft_amount = calculate_nft_to_mint_for_ft_mint(
-        querier,
-        to_addr,
-        base_denom,
-        amount,
-        one_denom_in_base_denom,
-    )?;
-    batch_mint_nft(storage, base_uri, to_addr, mint_nft_amount)?;
+    let burn_nft_amount = if is_nft_exempt(storage, from_addr)? {
+        Uint128::zero()
+    } else {
+        calculate_nft_to_burn_for_ft_burn(
+            querier,
+            from_addr,
+            base_denom,
+            amount,
+            one_denom_in_base_denom,
+        )?
+    };
+    let burned_token_ids = batch_burn_nft(storage, from_addr, burn_nft_amount)?;
+    let mint_nft_amount = if is_nft_exempt(storage, to_addr)? {
+        Uint128::zero()
+    } else {
+        calculate_nft_to_mint_for_ft_mint(
+            querier,
+            to_addr,
+            base_denom,
+            amount,
+            one_denom_in_base_denom,
+        )?
+    };
+    let minted_token_ids =
+        batch_mint_nft(storage, base_uri, to_addr, mint_nft_amount)?;
+    let mut nft_changes = nft_burn_changes(from_addr, &burned_token_ids);
+    nft_changes.extend(nft_mint_changes(to_addr, &minted_token_ids));
+    let (nft_events, nft_hook_submsgs) =
+        notify_nft_ownership_changes(storage, nft_changes)?;
     let msg = MsgForceTransfer {
         sender: contract_addr.to_string(),
         amount: Some(SdkCoin {
@@ -149,6 +214,8 @@ pub fn force_transfer_ft(
     };
     Ok(Response::new()
         .add_message(msg)
+        .add_submessages(nft_hook_submsgs)
+        .add_events(nft_events)
         .add_attribute("token_type", "ft")
         .add_attribute("action", "force_transfer_ft")
         .add_attribute("amount", amount)
@@ -0,0 +1,41 @@
+use crate::state::{MAX_NFT_HOOKS, NFT_HOOKS};
+use cosmwasm_std::{Addr, Order, Response, Storage};
+use cw404::hook::NftHookWatch;
+use shared_pkg::error::ContractError;
+
+pub fn add_nft_hook(
+    storage: &mut dyn Storage,
+    hook_addr: &Addr,
+    watch: NftHookWatch,
+) -> Result<Response, ContractError> {
+    if NFT_HOOKS.has(storage, hook_addr) {
+        return Err(ContractError::NftHookAlreadyExists {
+            hook_addr: hook_addr.to_string(),
+        });
+    }
+    let hook_count = NFT_HOOKS
+        .keys(storage, None, None, Order::Ascending)
+        .count() as u32;
+    if hook_count >= MAX_NFT_HOOKS {
+        return Err(ContractError::TooManyNftHooks { max: MAX_NFT_HOOKS });
+    }
+    NFT_HOOKS.save(storage, hook_addr, &watch)?;
+    Ok(Response::new()
+        .add_attribute("action", "add_nft_hook")
+        .add_attribute("hook_addr", hook_addr))
+}
+
+pub fn remove_nft_hook(
+    storage: &mut dyn Storage,
+    hook_addr: &Addr,
+) -> Result<Response, ContractError> {
+    if !NFT_HOOKS.has(storage, hook_addr) {
+        return Err(ContractError::NftHookNotFound {
+            hook_addr: hook_addr.to_string(),
+        });
+    }
+    NFT_HOOKS.remove(storage, hook_addr);
+    Ok(Response::new()
+        .add_attribute("action", "remove_nft_hook")
+        .add_attribute("hook_addr", hook_addr))
+}
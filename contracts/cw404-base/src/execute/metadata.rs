@@ -0,0 +1,77 @@
+use crate::{
+    state::{
+        MAX_NFT_SUPPLY, METADATA_FROZEN, NFTS, PRESET_NFT_METADATA,
+        RECYCLED_NFTS, TRAIT_LAYERS_LOCKED, TRAIT_METADATA_CONFIG,
+    },
+    util::{
+        assert_helper::assert_metadata_not_frozen, metadata::save_trait_layers,
+    },
+};
+use cosmwasm_std::{Response, Storage};
+use cw404::nft_metadata::{NftMetadataEntry, TraitLayer, TraitMetadataConfig};
+use shared_pkg::error::ContractError;
+
+pub fn upload_trait_layers(
+    storage: &mut dyn Storage,
+    trait_metadata_config: Option<TraitMetadataConfig>,
+    trait_layers: Vec<TraitLayer>,
+) -> Result<Response, ContractError> {
+    assert_metadata_not_frozen(storage)?;
+    if TRAIT_LAYERS_LOCKED.may_load(storage)?.unwrap_or(false) {
+        return Err(ContractError::TraitLayersLocked {});
+    }
+    if let Some(trait_metadata_config) = trait_metadata_config {
+        TRAIT_METADATA_CONFIG.save(storage, &trait_metadata_config)?;
+    }
+    let trait_layer_count = trait_layers.len();
+    save_trait_layers(storage, trait_layers)?;
+    Ok(Response::new()
+        .add_attribute("action", "upload_trait_layers")
+        .add_attribute("trait_layer_count", trait_layer_count.to_string()))
+}
+
+pub fn set_token_metadata(
+    storage: &mut dyn Storage,
+    entries: Vec<NftMetadataEntry>,
+) -> Result<Response, ContractError> {
+    assert_metadata_not_frozen(storage)?;
+    let max_nft_supply = MAX_NFT_SUPPLY.load(storage)?;
+    let entry_count = entries.len();
+    for entry in entries {
+        let token_id = entry.token_id.u128();
+        if token_id == 0 || entry.token_id > max_nft_supply {
+            return Err(ContractError::NftTokenIdOutOfRange {
+                nft_token_id: entry.token_id,
+                max_nft_supply,
+            });
+        }
+        if let Some(mut nft) = NFTS().may_load(storage, token_id)? {
+            nft.token_uri = entry.token_uri;
+            nft.extension = entry.extension;
+            NFTS().save(storage, token_id, &nft)?;
+        } else if let Some(mut nft) =
+            RECYCLED_NFTS.may_load(storage, token_id)?
+        {
+            nft.token_uri = entry.token_uri;
+            nft.extension = entry.extension;
+            RECYCLED_NFTS.save(storage, token_id, &nft)?;
+        } else {
+            PRESET_NFT_METADATA.save(
+                storage,
+                token_id,
+                &(entry.token_uri, entry.extension),
+            )?;
+        }
+    }
+    Ok(Response::new()
+        .add_attribute("action", "set_token_metadata")
+        .add_attribute("entry_count", entry_count.to_string()))
+}
+
+pub fn freeze_metadata(
+    storage: &mut dyn Storage,
+) -> Result<Response, ContractError> {
+    assert_metadata_not_frozen(storage)?;
+    METADATA_FROZEN.save(storage, &true)?;
+    Ok(Response::new().add_attribute("action", "freeze_metadata"))
+}
@@ -0,0 +1,29 @@
+use crate::state::MINT_GROUPS;
+use cosmwasm_std::{Response, Storage};
+use cw404::mint_group::MintGroup;
+use shared_pkg::error::ContractError;
+
+pub fn set_mint_group(
+    storage: &mut dyn Storage,
+    mint_group: MintGroup,
+) -> Result<Response, ContractError> {
+    MINT_GROUPS.save(storage, mint_group.name.as_str(), &mint_group)?;
+    Ok(Response::new()
+        .add_attribute("action", "set_mint_group")
+        .add_attribute("name", mint_group.name))
+}
+
+pub fn remove_mint_group(
+    storage: &mut dyn Storage,
+    name: &str,
+) -> Result<Response, ContractError> {
+    if !MINT_GROUPS.has(storage, name) {
+        return Err(ContractError::MintGroupNotFound {
+            name: name.to_string(),
+        });
+    }
+    MINT_GROUPS.remove(storage, name);
+    Ok(Response::new()
+        .add_attribute("action", "remove_mint_group")
+        .add_attribute("name", name))
+}
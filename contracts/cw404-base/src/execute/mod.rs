@@ -1,3 +1,10 @@
+pub(crate) mod airdrop;
 pub(crate) mod config;
 pub(crate) mod ft;
+pub(crate) mod hook;
+pub(crate) mod metadata;
+pub(crate) mod mint_group;
 pub(crate) mod nft;
+pub(crate) mod ownership;
+pub(crate) mod pause;
+pub(crate) mod role;
@@ -1,11 +1,14 @@
 use crate::{
-    state::{
-        CURRENT_NFT_SUPPLY, NFTS, NFT_OPERATORS, RECYCLED_NFTS,
-        RECYCLED_NFT_IDS,
-    },
+    state::{CURRENT_NFT_SUPPLY, NFTS, NFT_OPERATORS},
     util::{
         assert_helper::assert_can_send,
-        nft::{transfer_nft_helper, update_approvals},
+        hook::{
+            nft_burn_changes, nft_transfer_change, notify_nft_ownership_changes,
+        },
+        nft::{
+            nft_transfer_force_transfer_submsg, recycle_nft,
+            transfer_nft_helper, update_approvals,
+        },
     },
 };
 use cosmwasm_std::{Addr, Binary, BlockInfo, Response, Storage, Uint128};
@@ -13,7 +16,7 @@ use cw721::Cw721ReceiveMsg;
 use cw_utils::Expiration;
 use osmosis_std::types::{
     cosmos::base::v1beta1::Coin as SdkCoin,
-    osmosis::tokenfactory::v1beta1::{MsgBurn, MsgForceTransfer},
+    osmosis::tokenfactory::v1beta1::MsgBurn,
 };
 use shared_pkg::error::ContractError;
 
@@ -105,18 +108,34 @@ pub fn transfer_nft(
     base_denom: &str,
     contract_addr: &Addr,
 ) -> Result<Response, ContractError> {
-    transfer_nft_helper(storage, block, sender_addr, recipient_addr, token_id)?;
-    let msg = MsgForceTransfer {
-        sender: contract_addr.to_string(),
-        amount: Some(SdkCoin {
-            amount: one_denom_in_base_denom.to_string(),
-            denom: base_denom.to_string(),
-        }),
-        transfer_from_address: sender_addr.to_string(),
-        transfer_to_address: recipient_addr.to_string(),
-    };
+    let old_owner_addr = transfer_nft_helper(
+        storage,
+        block,
+        sender_addr,
+        recipient_addr,
+        token_id,
+    )?;
+    let (nft_events, nft_hook_submsgs) = notify_nft_ownership_changes(
+        storage,
+        vec![nft_transfer_change(
+            &old_owner_addr,
+            recipient_addr,
+            token_id,
+        )],
+    )?;
+    // sender can be an approved spender, FT backing the NFT is held by the owner
+    let force_transfer_submsg = nft_transfer_force_transfer_submsg(
+        storage,
+        contract_addr,
+        &old_owner_addr,
+        recipient_addr,
+        one_denom_in_base_denom,
+        base_denom,
+    )?;
     Ok(Response::new()
-        .add_message(msg)
+        .add_submessage(force_transfer_submsg)
+        .add_submessages(nft_hook_submsgs)
+        .add_events(nft_events)
         .add_attribute("action", "transfer_nft")
         .add_attribute("sender", sender_addr)
         .add_attribute("recipient", recipient_addr)
@@ -134,30 +153,40 @@ pub fn send_nft(
     recipient_contract_addr: &Addr,
     msg: Binary,
 ) -> Result<Response, ContractError> {
-    transfer_nft_helper(
+    let old_owner_addr = transfer_nft_helper(
         storage,
         block,
         sender_addr,
         recipient_contract_addr,
         token_id,
     )?;
+    let (nft_events, nft_hook_submsgs) = notify_nft_ownership_changes(
+        storage,
+        vec![nft_transfer_change(
+            &old_owner_addr,
+            recipient_contract_addr,
+            token_id,
+        )],
+    )?;
+    // FT backing the NFT moves with it, so old owner's FT moves cannot burn it
+    let force_transfer_submsg = nft_transfer_force_transfer_submsg(
+        storage,
+        contract_addr,
+        &old_owner_addr,
+        recipient_contract_addr,
+        one_denom_in_base_denom,
+        base_denom,
+    )?;
     let send = Cw721ReceiveMsg {
         sender: sender_addr.to_string(),
         token_id: token_id.to_string(),
         msg,
     };
-    let msg = MsgForceTransfer {
-        sender: contract_addr.to_string(),
-        amount: Some(SdkCoin {
-            amount: one_denom_in_base_denom.to_string(),
-            denom: base_denom.to_string(),
-        }),
-        transfer_from_address: sender_addr.to_string(),
-        transfer_to_address: contract_addr.to_string(),
-    };
     Ok(Response::new()
-        .add_message(msg)
+        .add_submessage(force_transfer_submsg)
         .add_message(send.into_cosmos_msg(recipient_contract_addr.clone())?)
+        .add_submessages(nft_hook_submsgs)
+        .add_events(nft_events)
         .add_attribute("action", "send_nft")
         .add_attribute("sender", sender_addr)
         .add_attribute("recipient", recipient_contract_addr)
@@ -175,10 +204,12 @@ pub fn burn_nft(
 ) -> Result<Response, ContractError> {
     let current_nft_supply = CURRENT_NFT_SUPPLY.load(storage)?;
     assert_can_send(storage, block, sender_addr, token_id)?;
-    let burned_nft = NFTS().load(storage, token_id)?;
-    RECYCLED_NFT_IDS.push_back(storage, &token_id)?;
-    RECYCLED_NFTS.save(storage, token_id, &burned_nft)?;
-    NFTS().remove(storage, token_id)?;
+    let owner_addr = NFTS().load(storage, token_id)?.owner;
+    recycle_nft(storage, token_id)?;
+    let (nft_events, nft_hook_submsgs) = notify_nft_ownership_changes(
+        storage,
+        nft_burn_changes(&owner_addr, &[token_id]),
+    )?;
     let msg = MsgBurn {
         sender: contract_addr.to_string(),
         amount: Some(SdkCoin {
@@ -191,6 +222,8 @@ pub fn burn_nft(
     CURRENT_NFT_SUPPLY.save(storage, &updated_nft_supply)?;
     Ok(Response::new()
         .add_message(msg)
+        .add_submessages(nft_hook_submsgs)
+        .add_events(nft_events)
         .add_attribute("action", "burn")
         .add_attribute("sender", sender_addr)
         .add_attribute("token_id", token_id.to_string()))
@@ -0,0 +1,24 @@
+use crate::state::PAUSED_ACTIONS;
+use cosmwasm_std::{Empty, Response, Storage};
+use cw404::pause::PauseAction;
+use shared_pkg::error::ContractError;
+
+pub fn pause(
+    storage: &mut dyn Storage,
+    action: PauseAction,
+) -> Result<Response, ContractError> {
+    PAUSED_ACTIONS.save(storage, action.as_str(), &Empty {})?;
+    Ok(Response::new()
+        .add_attribute("action", "pause")
+        .add_attribute("paused_action", action.as_str()))
+}
+
+pub fn unpause(
+    storage: &mut dyn Storage,
+    action: PauseAction,
+) -> Result<Response, ContractError> {
+    PAUSED_ACTIONS.remove(storage, action.as_str());
+    Ok(Response::new()
+        .add_attribute("action", "unpause")
+        .add_attribute("unpaused_action", action.as_str()))
+}
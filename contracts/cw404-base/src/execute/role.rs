@@ -0,0 +1,28 @@
+use crate::state::ROLE_MEMBERS;
+use cosmwasm_std::{Addr, Empty, Response, Storage};
+use cw404::role::Role;
+use shared_pkg::error::ContractError;
+
+pub fn grant_role(
+    storage: &mut dyn Storage,
+    role: Role,
+    addr: &Addr,
+) -> Result<Response, ContractError> {
+    ROLE_MEMBERS.save(storage, (role.as_str(), addr), &Empty {})?;
+    Ok(Response::new()
+        .add_attribute("action", "grant_role")
+        .add_attribute("role", role.as_str())
+        .add_attribute("addr", addr))
+}
+
+pub fn revoke_role(
+    storage: &mut dyn Storage,
+    role: Role,
+    addr: &Addr,
+) -> Result<Response, ContractError> {
+    ROLE_MEMBERS.remove(storage, (role.as_str(), addr));
+    Ok(Response::new()
+        .add_attribute("action", "revoke_role")
+        .add_attribute("role", role.as_str())
+        .add_attribute("addr", addr))
+}
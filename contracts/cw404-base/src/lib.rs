@@ -1,6 +1,8 @@
 pub mod contract;
 mod execute;
+mod migrate;
 mod query;
+mod reply;
 mod state;
 mod sudo;
 mod util;
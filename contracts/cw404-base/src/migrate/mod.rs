@@ -0,0 +1 @@
+pub(crate) mod v0_2_0;
@@ -0,0 +1,73 @@
+use crate::{
+    state::{
+        CONFIG, RECYCLED_NFT_IDS, RECYCLED_NFT_POP_COUNT, RECYCLED_NFT_SEQS,
+        ROLE_MEMBERS, TRAIT_SEED,
+    },
+    util::metadata::generate_trait_seed,
+};
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, Empty, Env, StdResult, Storage, Uint64};
+use cw404::{config::Config, role::Role};
+use cw_storage_plus::Item;
+use osmosis_std::types::cosmos::bank::v1beta1::Metadata as DenomMetadata;
+use shared_pkg::error::ContractError;
+
+/// Config before minter moved to role registry
+#[cw_serde]
+struct ConfigV0_1 {
+    admin_addr: Option<Addr>,
+    minter_addr: Addr,
+    creator_addr: Addr,
+    denom_metadata: DenomMetadata,
+    royalty_payment_addr: Addr,
+    royalty_percentage: Uint64,
+}
+
+const CONFIG_V0_1: Item<ConfigV0_1> = Item::new("CONFIG");
+
+pub fn migrate_to_v0_2_0(
+    storage: &mut dyn Storage,
+    env: &Env,
+) -> Result<(), ContractError> {
+    // minter in config becomes a member of minter role
+    let config = CONFIG_V0_1.load(storage)?;
+    ROLE_MEMBERS.save(
+        storage,
+        (Role::Minter {}.as_str(), &config.minter_addr),
+        &Empty {},
+    )?;
+    CONFIG.save(
+        storage,
+        &Config {
+            admin_addr: config.admin_addr,
+            creator_addr: config.creator_addr,
+            denom_metadata: config.denom_metadata,
+            royalty_payment_addr: config.royalty_payment_addr,
+            royalty_percentage: config.royalty_percentage,
+        },
+    )?;
+
+    // NFTs recycled before queue positions were tracked, nothing was popped since tracking started
+    let pop_count = RECYCLED_NFT_POP_COUNT.may_load(storage)?.unwrap_or(0);
+    let token_ids = RECYCLED_NFT_IDS
+        .iter(storage)?
+        .collect::<StdResult<Vec<u128>>>()?;
+    for (idx, token_id) in token_ids.into_iter().enumerate() {
+        if !RECYCLED_NFT_SEQS.has(storage, token_id) {
+            RECYCLED_NFT_SEQS.save(
+                storage,
+                token_id,
+                &(pop_count + idx as u64),
+            )?;
+        }
+    }
+
+    // collections created before trait metadata get a seed in case trait layers are uploaded later
+    if !TRAIT_SEED.exists(storage) {
+        TRAIT_SEED.save(
+            storage,
+            &generate_trait_seed(&env.contract.address, &env.block),
+        )?;
+    }
+    Ok(())
+}
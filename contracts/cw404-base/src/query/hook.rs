@@ -0,0 +1,11 @@
+use crate::state::NFT_HOOKS;
+use cosmwasm_std::{Order, StdResult, Storage};
+use cw404::{hook::NftHook, msg::NftHooksResponse};
+
+pub fn query_nft_hooks(storage: &dyn Storage) -> StdResult<NftHooksResponse> {
+    let nft_hooks = NFT_HOOKS
+        .range(storage, None, None, Order::Ascending)
+        .map(|item| item.map(|(hook_addr, watch)| NftHook { hook_addr, watch }))
+        .collect::<StdResult<Vec<_>>>()?;
+    Ok(NftHooksResponse { nft_hooks })
+}
@@ -0,0 +1,24 @@
+use crate::{
+    state::{METADATA_FROZEN, TRAIT_LAYERS_LOCKED, TRAIT_METADATA_CONFIG},
+    util::metadata::load_trait_layers,
+};
+use cosmwasm_std::{StdResult, Storage};
+use cw404::msg::{MetadataFrozenResponse, TraitLayersResponse};
+
+pub fn query_trait_layers(
+    storage: &dyn Storage,
+) -> StdResult<TraitLayersResponse> {
+    Ok(TraitLayersResponse {
+        trait_metadata_config: TRAIT_METADATA_CONFIG.may_load(storage)?,
+        trait_layers: load_trait_layers(storage)?,
+        locked: TRAIT_LAYERS_LOCKED.may_load(storage)?.unwrap_or(false),
+    })
+}
+
+pub fn query_metadata_frozen(
+    storage: &dyn Storage,
+) -> StdResult<MetadataFrozenResponse> {
+    Ok(MetadataFrozenResponse {
+        frozen: METADATA_FROZEN.may_load(storage)?.unwrap_or(false),
+    })
+}
@@ -1,3 +1,9 @@
+pub(crate) mod airdrop;
 pub(crate) mod config;
 pub(crate) mod ft;
+pub(crate) mod hook;
+pub(crate) mod metadata;
 pub(crate) mod nft;
+pub(crate) mod pause;
+pub(crate) mod role;
+pub(crate) mod seed_liquidity;
@@ -3,12 +3,14 @@ use crate::{
         CURRENT_NFT_SUPPLY, DEFAULT_LIMIT, MAX_LIMIT, NFTS, NFT_OPERATORS,
         RECYCLED_NFTS, RECYCLED_NFT_IDS,
     },
-    util::nft::humanize_approvals,
+    util::nft::{humanize_approvals, recycled_nft_queue_position},
 };
 use cosmwasm_std::{
     Addr, Api, BlockInfo, Env, Order, StdError, StdResult, Storage, Uint128,
 };
-use cw404::msg::RecycledNftTokenIdsResponse;
+use cw404::msg::{
+    NftStatus, NftStatusResponse, RecycledNft, RecycledNftTokenIdsResponse,
+};
 use cw721::{
     AllNftInfoResponse, Approval, ApprovalResponse, ApprovalsResponse,
     ContractInfoResponse, NftInfoResponse, NumTokensResponse, OperatorResponse,
@@ -25,14 +27,33 @@ pub fn query_recycled_nfts(
     limit: Option<u32>,
 ) -> StdResult<RecycledNftTokenIdsResponse> {
     let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT);
-    let start = start_after_idx.unwrap_or(0);
-    let mut recycled_nft_token_ids: Vec<Uint128> = vec![];
-    for i in start..start + limit {
-        recycled_nft_token_ids
-            .push(Uint128::from(RECYCLED_NFT_IDS.get(storage, i)?.unwrap()));
+    let queue_length = RECYCLED_NFT_IDS.len(storage)?;
+    let start = start_after_idx.map_or(0, |idx| idx.saturating_add(1));
+    let end = start.saturating_add(limit).min(queue_length);
+    let mut recycled_nfts: Vec<RecycledNft> = vec![];
+    for queue_position in start..end {
+        let token_id = RECYCLED_NFT_IDS
+            .get(storage, queue_position)?
+            .ok_or_else(|| {
+                StdError::not_found(format!(
+                    "recycled NFT at queue position {}",
+                    queue_position
+                ))
+            })?;
+        recycled_nfts.push(RecycledNft {
+            queue_position,
+            token_id: Uint128::from(token_id),
+            nft: RECYCLED_NFTS.load(storage, token_id)?,
+        });
     }
     Ok(RecycledNftTokenIdsResponse {
-        recycled_nft_token_ids,
+        queue_length,
+        recycled_nfts,
+        next: if start < end && end < queue_length {
+            Some(end - 1)
+        } else {
+            None
+        },
     })
 }
 
@@ -47,6 +68,22 @@ pub fn query_recycled_nft(
     })
 }
 
+pub fn query_nft_status(
+    storage: &dyn Storage,
+    token_id: Uint128,
+) -> StdResult<NftStatusResponse> {
+    let status = match NFTS().may_load(storage, token_id.u128())? {
+        Some(nft) => NftStatus::Live {
+            owner: nft.owner.to_string(),
+        },
+        None => match recycled_nft_queue_position(storage, token_id.u128())? {
+            Some(queue_position) => NftStatus::Recycled { queue_position },
+            None => NftStatus::NeverMinted {},
+        },
+    };
+    Ok(NftStatusResponse { token_id, status })
+}
+
 pub fn query_nft_owner(
     storage: &dyn Storage,
     block: &BlockInfo,
@@ -131,7 +168,7 @@ pub fn query_nft_operator(
     operator_addr: &Addr,
     include_expired: Option<bool>,
 ) -> StdResult<OperatorResponse> {
-    match NFT_OPERATORS.may_load(storage, (&owner_addr, &operator_addr))? {
+    match NFT_OPERATORS.may_load(storage, (owner_addr, operator_addr))? {
         Some(expires) => {
             if !include_expired.unwrap_or(false) && expires.is_expired(block) {
                 Err(StdError::not_found("Approval not found"))
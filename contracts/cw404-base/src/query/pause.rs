@@ -0,0 +1,14 @@
+use crate::state::PAUSED_ACTIONS;
+use cosmwasm_std::{StdResult, Storage};
+use cw404::{msg::PauseStatusResponse, pause::PauseAction};
+
+pub fn query_pause_status(
+    storage: &dyn Storage,
+) -> StdResult<PauseStatusResponse> {
+    Ok(PauseStatusResponse {
+        minting_paused: PAUSED_ACTIONS
+            .has(storage, PauseAction::Minting {}.as_str()),
+        nft_transfers_paused: PAUSED_ACTIONS
+            .has(storage, PauseAction::NftTransfers {}.as_str()),
+    })
+}
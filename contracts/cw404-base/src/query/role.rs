@@ -0,0 +1,41 @@
+use crate::{
+    state::{DEFAULT_LIMIT, MAX_LIMIT, ROLE_MEMBERS},
+    util::assert_helper::has_role,
+};
+use cosmwasm_std::{Addr, Api, Order, StdResult, Storage};
+use cw404::{
+    config::Config,
+    msg::{HasRoleResponse, RoleMembersResponse},
+    role::Role,
+};
+use cw_storage_plus::Bound;
+use cw_utils::maybe_addr;
+
+pub fn query_has_role(
+    storage: &dyn Storage,
+    config: &Config,
+    role: &Role,
+    addr: &Addr,
+) -> StdResult<HasRoleResponse> {
+    Ok(HasRoleResponse {
+        has_role: has_role(storage, addr, &config.admin_addr, role),
+    })
+}
+
+pub fn query_role_members(
+    api: &dyn Api,
+    storage: &dyn Storage,
+    role: &Role,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<RoleMembersResponse> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let start_addr = maybe_addr(api, start_after)?;
+    let start = start_addr.as_ref().map(Bound::exclusive);
+    let members = ROLE_MEMBERS
+        .prefix(role.as_str())
+        .keys(storage, start, None, Order::Ascending)
+        .take(limit)
+        .collect::<StdResult<Vec<Addr>>>()?;
+    Ok(RoleMembersResponse { members })
+}
@@ -0,0 +1,12 @@
+use crate::state::{SEED_LIQUIDITY_CONFIG, SEED_LIQUIDITY_PAIR};
+use cosmwasm_std::{StdResult, Storage};
+use cw404::msg::SeedLiquidityResponse;
+
+pub fn query_seed_liquidity(
+    storage: &dyn Storage,
+) -> StdResult<SeedLiquidityResponse> {
+    Ok(SeedLiquidityResponse {
+        seed_liquidity_config: SEED_LIQUIDITY_CONFIG.may_load(storage)?,
+        pair_addr: SEED_LIQUIDITY_PAIR.may_load(storage)?,
+    })
+}
@@ -0,0 +1,104 @@
+use crate::{
+    contract::REPLY_ID_PROVIDE_SEED_LIQUIDITY,
+    state::{
+        AIRDROP, CONFIG, DENOM_EXPONENT, NFT_EXEMPT_ADDRS,
+        PENDING_NFT_TRANSFER, SEED_LIQUIDITY_CONFIG, SEED_LIQUIDITY_PAIR,
+    },
+    util::astroport::{provide_liquidity_msg, query_pair_info},
+};
+use cosmwasm_std::{
+    Addr, Empty, QuerierWrapper, Reply, ReplyOn, Response, Storage, SubMsg,
+    SubMsgResult, Uint128,
+};
+use osmosis_std::types::{
+    cosmos::base::v1beta1::Coin as SdkCoin,
+    osmosis::tokenfactory::v1beta1::MsgMint,
+};
+use shared_pkg::error::ContractError;
+
+/// FT backing the NFT transfer moved, drop the pending record if before send hook did not consume it
+pub fn nft_transfer_reply(
+    storage: &mut dyn Storage,
+) -> Result<Response, ContractError> {
+    PENDING_NFT_TRANSFER.remove(storage);
+    Ok(Response::new())
+}
+
+/// Mint reserved NFT supply as FT and provide it with paid funds to the new pair
+pub fn create_pair_reply(
+    querier: QuerierWrapper,
+    storage: &mut dyn Storage,
+    msg: Reply,
+    contract_addr: &Addr,
+) -> Result<Response, ContractError> {
+    if let SubMsgResult::Err(err) = msg.result {
+        return Err(ContractError::ErrorCreatePairInAstroport { err });
+    }
+    let config = CONFIG.load(storage)?;
+    let seed_liquidity_config = SEED_LIQUIDITY_CONFIG
+        .may_load(storage)?
+        .ok_or(ContractError::CannotFindSeedLiquidityConfig {})?;
+    let base_denom = config.denom_metadata.base.as_str();
+    let pair_addr = query_pair_info(
+        querier,
+        &seed_liquidity_config.astroport_factory_addr,
+        &seed_liquidity_config.paired_base_denom,
+        base_denom,
+    )?
+    .contract_addr;
+
+    // pair holds FT without NFTs, so it does not end up with thousands of NFTs
+    NFT_EXEMPT_ADDRS.save(storage, &pair_addr, &Empty {})?;
+    // exempt until liquidity is provided so reserved FT does not mint and burn NFTs on the way
+    NFT_EXEMPT_ADDRS.save(storage, contract_addr, &Empty {})?;
+    SEED_LIQUIDITY_PAIR.save(storage, &pair_addr)?;
+
+    let pool_supply = seed_liquidity_config.nft_supply
+        * Uint128::from(10u128.pow(DENOM_EXPONENT));
+    let mint_msg = MsgMint {
+        sender: contract_addr.to_string(),
+        amount: Some(SdkCoin {
+            amount: pool_supply.to_string(),
+            denom: base_denom.to_string(),
+        }),
+        mint_to_address: contract_addr.to_string(),
+    };
+    let provide_liquidity_submsg = SubMsg {
+        id: REPLY_ID_PROVIDE_SEED_LIQUIDITY,
+        msg: provide_liquidity_msg(
+            &seed_liquidity_config.paired_base_denom,
+            &pair_addr,
+            base_denom,
+            &config.creator_addr,
+            seed_liquidity_config.paired_base_denom_amount,
+            pool_supply,
+        )?,
+        gas_limit: None,
+        reply_on: ReplyOn::Success,
+    };
+    Ok(Response::new()
+        .add_message(mint_msg)
+        .add_submessage(provide_liquidity_submsg)
+        .add_attribute("action", "create_seed_liquidity_pair")
+        .add_attribute("pair_addr", pair_addr)
+        .add_attribute("lp_receiver", config.creator_addr)
+        .add_attribute("token_1", seed_liquidity_config.paired_base_denom)
+        .add_attribute("token_2", base_denom)
+        .add_attribute(
+            "token_1_liquidity",
+            seed_liquidity_config.paired_base_denom_amount,
+        )
+        .add_attribute("token_2_liquidity", pool_supply))
+}
+
+/// Reserved FT is in the pair now, this contract goes back to NFT bookkeeping
+/// unless it holds airdrop FT attached at instantiate
+pub fn provide_seed_liquidity_reply(
+    storage: &mut dyn Storage,
+    contract_addr: &Addr,
+) -> Result<Response, ContractError> {
+    if !AIRDROP.exists(storage) {
+        NFT_EXEMPT_ADDRS.remove(storage, contract_addr);
+    }
+    Ok(Response::new().add_attribute("action", "provide_seed_liquidity"))
+}
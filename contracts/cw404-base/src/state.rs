@@ -1,21 +1,48 @@
-use cosmwasm_std::{Addr, Uint128};
-use cw404::{config::Config, mint_group::MintGroup};
+use cosmwasm_std::{Addr, Empty, Uint128};
+use cw404::{
+    config::Config,
+    hook::NftHookWatch,
+    mint_group::MintGroup,
+    msg::SeedLiquidityConfig,
+    nft_metadata::{TraitLayer, TraitMetadataConfig},
+};
 use cw721_base::state::TokenInfo as NftTokenInfo;
 use cw721_metadata_onchain::Extension as NftExtension;
 use cw_storage_plus::{
     Deque, Index, IndexList, IndexedMap, Item, Map, MultiIndex,
 };
 use cw_utils::Expiration;
+use shared_pkg::airdrop::Airdrop;
 
 pub const DEFAULT_LIMIT: u32 = 10;
 pub const MAX_LIMIT: u32 = 100;
 pub const DENOM_EXPONENT: u32 = 6;
 pub const FEE_DENOM: &str = "untrn";
+pub const MAX_NFT_HOOKS: u32 = 10;
 
 pub const CONFIG: Item<Config> = Item::new("CONFIG");
+/// Key is (role, member), admin holds every role without being listed
+pub const ROLE_MEMBERS: Map<(&str, &Addr), Empty> = Map::new("ROLE_MEMBERS");
+/// Key is paused action
+pub const PAUSED_ACTIONS: Map<&str, Empty> = Map::new("PAUSED_ACTIONS");
 
 pub const MINT_GROUPS: Map<&str, MintGroup> = Map::new("MINT_GROUPS");
 
+/// If exists, newly minted NFTs get metadata generated from TRAIT_LAYERS
+pub const TRAIT_METADATA_CONFIG: Item<TraitMetadataConfig> =
+    Item::new("TRAIT_METADATA_CONFIG");
+/// Key is layer index, layer order decides the dna in NFT image
+pub const TRAIT_LAYERS: Map<u32, TraitLayer> = Map::new("TRAIT_LAYERS");
+/// Set to true when the first NFT gets its traits, so assigned metadata never changes
+pub const TRAIT_LAYERS_LOCKED: Item<bool> = Item::new("TRAIT_LAYERS_LOCKED");
+/// Set at instantiation so the same trait layers give different traits in different collections
+pub const TRAIT_SEED: Item<Vec<u8>> = Item::new("TRAIT_SEED");
+/// Token URI and extension set by admin for NFTs not minted yet, used when they get minted
+pub const PRESET_NFT_METADATA: Map<u128, (Option<String>, NftExtension)> =
+    Map::new("PRESET_NFT_METADATA");
+/// Once true, NFT metadata can no longer be changed
+pub const METADATA_FROZEN: Item<bool> = Item::new("METADATA_FROZEN");
+
 /// 1 NFT = 1 denom (e.g. ATOM) = 1 * 10 ** exponent base denom (uatom)
 /// e.g. 1 ATOM = 1_000_000 uatom when exponent = 6, ATOM is both denom (FT) and NFT
 pub const MAX_NFT_SUPPLY: Item<Uint128> = Item::new("MAX_NFT_SUPPLY");
@@ -28,6 +55,35 @@ pub const CURRENT_NFT_SUPPLY: Item<Uint128> = Item::new("CURRENT_NFT_SUPPLY");
 pub const RECYCLED_NFT_IDS: Deque<u128> = Deque::new("RECYCLED_NFT_IDS");
 pub const RECYCLED_NFTS: Map<u128, NftTokenInfo<NftExtension>> =
     Map::new("RECYCLED_NFTS");
+/// Sequence number assigned to each recycled NFT ID when pushed to the queue
+/// queue position = sequence number - RECYCLED_NFT_POP_COUNT
+pub const RECYCLED_NFT_SEQS: Map<u128, u64> = Map::new("RECYCLED_NFT_SEQS");
+/// Total number of NFT IDs ever popped from the front of the recycle queue
+pub const RECYCLED_NFT_POP_COUNT: Item<u64> =
+    Item::new("RECYCLED_NFT_POP_COUNT");
+
+/// Only exists if collection is launched with seed liquidity
+pub const SEED_LIQUIDITY_CONFIG: Item<SeedLiquidityConfig> =
+    Item::new("SEED_LIQUIDITY_CONFIG");
+/// Astroport pair created for seed liquidity
+pub const SEED_LIQUIDITY_PAIR: Item<Addr> = Item::new("SEED_LIQUIDITY_PAIR");
+/// Addresses holding FT without NFTs, e.g. DEX pools, FT moves in and out of them do not mint or burn NFTs
+pub const NFT_EXEMPT_ADDRS: Map<&Addr, Empty> = Map::new("NFT_EXEMPT_ADDRS");
+
+/// Latest airdrop attached at instantiate or by admin
+pub const AIRDROP: Item<Airdrop> = Item::new("AIRDROP");
+/// Key is (airdrop round, word index), each bit marks a claimed leaf index
+pub const AIRDROP_CLAIMED_BITMAP: Map<(u64, u32), u64> =
+    Map::new("AIRDROP_CLAIMED_BITMAP");
+
+/// Contracts notified on NFT ownership changes, key is hook contract address
+pub const NFT_HOOKS: Map<&Addr, NftHookWatch> = Map::new("NFT_HOOKS");
+
+/// (from, to) of the FT move backing the NFT transfer in flight
+/// Before send hook consumes it instead of burning and minting NFTs again
+/// Set right before the force transfer and cleared in its reply, never outlives the execute
+pub const PENDING_NFT_TRANSFER: Item<(Addr, Addr)> =
+    Item::new("PENDING_NFT_TRANSFER");
 
 /// Stored as (granter, operator) giving operator full control over granter's account
 pub const NFT_OPERATORS: Map<(&Addr, &Addr), Expiration> =
@@ -1,6 +1,10 @@
-use crate::util::nft::{
-    batch_burn_nft, batch_mint_nft, calculate_nft_to_burn_for_ft_burn,
-    calculate_nft_to_mint_for_ft_mint,
+use crate::util::{
+    hook::{nft_burn_changes, nft_mint_changes, nft_ownership_change_events},
+    nft::{
+        batch_burn_nft, batch_mint_nft, calculate_nft_to_burn_for_ft_burn,
+        calculate_nft_to_mint_for_ft_mint, consume_pending_nft_transfer,
+        is_nft_exempt,
+    },
 };
 use cosmwasm_std::{Addr, QuerierWrapper, Response, Storage, Uint128};
 use osmosis_std::types::cosmos::bank::v1beta1::Metadata;
@@ -37,23 +41,48 @@ pub fn block_before_send(
         metadata,
         one_denom_in_base_denom,
     );
-    let burn_nft_amount = calculate_nft_to_burn_for_ft_burn(
-        querier,
-        from_addr,
-        base_denom.as_str(),
-        base_amount,
-        one_denom_in_base_denom,
-    )?;
-    batch_burn_nft(storage, from_addr, burn_nft_amount)?;
-    let mint_nft_amount = calculate_nft_to_mint_for_ft_mint(
-        querier,
-        to_addr,
-        base_denom.as_str(),
-        base_amount,
-        one_denom_in_base_denom,
-    )?;
-    batch_mint_nft(storage, &metadata.uri, to_addr, mint_nft_amount)?;
+    // NFT already moved in transfer_nft or send_nft, no need to burn and mint
+    if base_amount == one_denom_in_base_denom
+        && consume_pending_nft_transfer(storage, from_addr, to_addr)?
+    {
+        return Ok(Response::new()
+            .add_attribute("token_type", "ft")
+            .add_attribute("action", "block_before_send")
+            .add_attribute("from", from_addr)
+            .add_attribute("to", to_addr)
+            .add_attribute("amount_in_base_denom", base_amount)
+            .add_attribute("base_denom", base_denom)
+            .add_attribute("backs_nft_transfer", "true"));
+    }
+    let burn_nft_amount = if is_nft_exempt(storage, from_addr)? {
+        Uint128::zero()
+    } else {
+        calculate_nft_to_burn_for_ft_burn(
+            querier,
+            from_addr,
+            base_denom.as_str(),
+            base_amount,
+            one_denom_in_base_denom,
+        )?
+    };
+    let burned_token_ids = batch_burn_nft(storage, from_addr, burn_nft_amount)?;
+    let mint_nft_amount = if is_nft_exempt(storage, to_addr)? {
+        Uint128::zero()
+    } else {
+        calculate_nft_to_mint_for_ft_mint(
+            querier,
+            to_addr,
+            base_denom.as_str(),
+            base_amount,
+            one_denom_in_base_denom,
+        )?
+    };
+    let minted_token_ids =
+        batch_mint_nft(storage, &metadata.uri, to_addr, mint_nft_amount)?;
+    let mut nft_changes = nft_burn_changes(from_addr, &burned_token_ids);
+    nft_changes.extend(nft_mint_changes(to_addr, &minted_token_ids));
     Ok(Response::new()
+        .add_events(nft_ownership_change_events(&nft_changes))
         .add_attribute("token_type", "ft")
         .add_attribute("action", "block_before_send")
         .add_attribute("from", from_addr)
@@ -0,0 +1,41 @@
+use crate::state::{AIRDROP, NFT_EXEMPT_ADDRS};
+use cosmwasm_std::{Addr, Api, BlockInfo, CosmosMsg, Empty, Storage};
+use osmosis_std::types::{
+    cosmos::base::v1beta1::Coin as SdkCoin,
+    osmosis::tokenfactory::v1beta1::MsgMint,
+};
+use shared_pkg::{
+    airdrop::{new_airdrop, Airdrop, AirdropConfig},
+    error::ContractError,
+};
+
+/// Save new airdrop, its total amount is minted to and held by this contract
+/// Contract stays exempt from NFT bookkeeping once it holds airdrop FT, claims mint NFTs to claimers
+pub fn attach_airdrop(
+    api: &dyn Api,
+    storage: &mut dyn Storage,
+    block: &BlockInfo,
+    airdrop_config: AirdropConfig,
+    base_denom: &str,
+    contract_addr: &Addr,
+) -> Result<(Airdrop, CosmosMsg), ContractError> {
+    let round = match AIRDROP.may_load(storage)? {
+        Some(airdrop) if !airdrop.closed => {
+            return Err(ContractError::AirdropAlreadyActive {})
+        }
+        Some(airdrop) => airdrop.round + 1,
+        None => 1,
+    };
+    let airdrop = new_airdrop(api, block, airdrop_config, round)?;
+    AIRDROP.save(storage, &airdrop)?;
+    NFT_EXEMPT_ADDRS.save(storage, contract_addr, &Empty {})?;
+    let mint_msg = MsgMint {
+        sender: contract_addr.to_string(),
+        amount: Some(SdkCoin {
+            amount: airdrop.total_amount.to_string(),
+            denom: base_denom.to_string(),
+        }),
+        mint_to_address: contract_addr.to_string(),
+    };
+    Ok((airdrop, mint_msg.into()))
+}
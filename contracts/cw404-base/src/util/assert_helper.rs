@@ -1,5 +1,8 @@
-use crate::state::{NFTS, NFT_OPERATORS};
+use crate::state::{
+    METADATA_FROZEN, NFTS, NFT_OPERATORS, PAUSED_ACTIONS, ROLE_MEMBERS,
+};
 use cosmwasm_std::{Addr, BlockInfo, Storage, Uint128};
+use cw404::{pause::PauseAction, role::Role};
 use shared_pkg::error::ContractError;
 
 pub fn assert_only_admin_can_call_this_function(
@@ -24,18 +27,41 @@ pub fn assert_only_admin_can_call_this_function(
     Ok(())
 }
 
-pub fn assert_only_admin_or_minter_can_mint(
+pub fn has_role(
+    storage: &dyn Storage,
+    addr: &Addr,
+    admin: &Option<Addr>,
+    role: &Role,
+) -> bool {
+    admin.as_ref() == Some(addr)
+        || ROLE_MEMBERS.has(storage, (role.as_str(), addr))
+}
+
+/// Admin passes every role check
+pub fn assert_has_role(
+    storage: &dyn Storage,
     sender: &Addr,
     admin: &Option<Addr>,
-    minter: &Addr,
+    role: &Role,
 ) -> Result<(), ContractError> {
-    if admin.is_some() && sender == admin.as_ref().unwrap() {
-        return Ok(());
+    if !has_role(storage, sender, admin, role) {
+        return Err(ContractError::MissingRole {
+            role: role.as_str().to_string(),
+        });
     }
-    if sender == minter {
-        return Ok(());
+    Ok(())
+}
+
+pub fn assert_not_paused(
+    storage: &dyn Storage,
+    action: &PauseAction,
+) -> Result<(), ContractError> {
+    if PAUSED_ACTIONS.has(storage, action.as_str()) {
+        return Err(ContractError::Paused {
+            action: action.as_str().to_string(),
+        });
     }
-    Err(ContractError::OnlyAdminOrMinterCanMint {})
+    Ok(())
 }
 
 pub fn assert_max_base_denom_supply_not_reached(
@@ -53,6 +79,15 @@ pub fn assert_max_base_denom_supply_not_reached(
     Ok(())
 }
 
+pub fn assert_metadata_not_frozen(
+    storage: &dyn Storage,
+) -> Result<(), ContractError> {
+    if METADATA_FROZEN.may_load(storage)?.unwrap_or(false) {
+        return Err(ContractError::MetadataFrozen {});
+    }
+    Ok(())
+}
+
 /// returns true if the sender can transfer ownership of the token
 pub fn assert_can_send(
     storage: &dyn Storage,
@@ -0,0 +1,107 @@
+use crate::contract::REPLY_ID_CREATE_PAIR;
+use astroport::{
+    asset::{Asset, AssetInfo, PairInfo},
+    factory::{ExecuteMsg::CreatePair, PairType, QueryMsg::Pair},
+    pair::ExecuteMsg::ProvideLiquidity,
+};
+use cosmwasm_std::{
+    coin, to_json_binary, Addr, Attribute, CosmosMsg, QuerierWrapper, ReplyOn,
+    StdResult, SubMsg, Uint128, WasmMsg,
+};
+use shared_pkg::error::ContractError;
+
+pub fn create_pair(
+    astroport_factory_addr: &Addr,
+    paired_base_denom: &str,
+    base_denom: &str,
+) -> Result<(SubMsg, Vec<Attribute>), ContractError> {
+    let msg: SubMsg = SubMsg {
+        id: REPLY_ID_CREATE_PAIR,
+        msg: CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: astroport_factory_addr.to_string(),
+            msg: to_json_binary(&CreatePair {
+                pair_type: PairType::Xyk {},
+                asset_infos: vec![
+                    AssetInfo::NativeToken {
+                        denom: paired_base_denom.to_string(),
+                    },
+                    AssetInfo::NativeToken {
+                        denom: base_denom.to_string(),
+                    },
+                ],
+                init_params: None,
+            })?,
+            funds: vec![],
+        }),
+        gas_limit: None,
+        reply_on: ReplyOn::Always,
+    };
+
+    let attrs = vec![
+        Attribute::new("action", "create_pair"),
+        Attribute::new("token_1", paired_base_denom.to_string()),
+        Attribute::new("token_2", base_denom),
+    ];
+    Ok((msg, attrs))
+}
+
+pub fn query_pair_info(
+    querier: QuerierWrapper,
+    astroport_factory_addr: &str,
+    paired_base_denom: &str,
+    base_denom: &str,
+) -> StdResult<PairInfo> {
+    querier.query_wasm_smart(
+        astroport_factory_addr,
+        &Pair {
+            asset_infos: vec![
+                AssetInfo::NativeToken {
+                    denom: paired_base_denom.to_string(),
+                },
+                AssetInfo::NativeToken {
+                    denom: base_denom.to_string(),
+                },
+            ],
+        },
+    )
+}
+
+pub fn provide_liquidity_msg(
+    paired_base_denom: &str,
+    pair_addr: &Addr,
+    base_denom: &str,
+    receiver_addr: &Addr,
+    paired_base_denom_amount: Uint128,
+    base_denom_amount: Uint128,
+) -> StdResult<CosmosMsg> {
+    Ok(WasmMsg::Execute {
+        contract_addr: pair_addr.to_string(),
+        msg: to_json_binary(&ProvideLiquidity {
+            assets: vec![
+                Asset {
+                    info: AssetInfo::NativeToken {
+                        denom: paired_base_denom.to_string(),
+                    },
+                    amount: paired_base_denom_amount,
+                },
+                Asset {
+                    info: AssetInfo::NativeToken {
+                        denom: base_denom.to_string(),
+                    },
+                    amount: base_denom_amount,
+                },
+            ],
+            slippage_tolerance: None,
+            auto_stake: Some(false),
+            receiver: Some(receiver_addr.to_string()),
+        })?,
+        funds: vec![
+            coin(
+                paired_base_denom_amount.u128(),
+                paired_base_denom.to_string(),
+            ),
+            coin(base_denom_amount.u128(), base_denom),
+        ],
+    }
+    .into())
+}
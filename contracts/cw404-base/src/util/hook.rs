@@ -0,0 +1,200 @@
+use crate::{contract::REPLY_ID_NFT_HOOK, state::NFT_HOOKS};
+use cosmwasm_std::{
+    to_json_binary, Addr, CosmosMsg, Event, Order, ReplyOn, StdResult, Storage,
+    SubMsg, WasmMsg,
+};
+use cw404::hook::{NftHookExecuteMsg, NftHookWatch, NftOwnershipChange};
+
+/// Gas each hook contract gets, running out fails only the hook since it replies on error
+pub const NFT_HOOK_GAS_LIMIT: u64 = 300_000;
+
+pub fn nft_mint_changes(
+    owner_addr: &Addr,
+    token_ids: &[u128],
+) -> Vec<NftOwnershipChange> {
+    token_ids
+        .iter()
+        .map(|token_id| NftOwnershipChange {
+            token_id: token_id.to_string(),
+            old_owner: None,
+            new_owner: Some(owner_addr.to_string()),
+        })
+        .collect()
+}
+
+pub fn nft_burn_changes(
+    owner_addr: &Addr,
+    token_ids: &[u128],
+) -> Vec<NftOwnershipChange> {
+    token_ids
+        .iter()
+        .map(|token_id| NftOwnershipChange {
+            token_id: token_id.to_string(),
+            old_owner: Some(owner_addr.to_string()),
+            new_owner: None,
+        })
+        .collect()
+}
+
+pub fn nft_transfer_change(
+    old_owner_addr: &Addr,
+    new_owner_addr: &Addr,
+    token_id: u128,
+) -> NftOwnershipChange {
+    NftOwnershipChange {
+        token_id: token_id.to_string(),
+        old_owner: Some(old_owner_addr.to_string()),
+        new_owner: Some(new_owner_addr.to_string()),
+    }
+}
+
+fn nft_ownership_change_event(change: &NftOwnershipChange) -> Event {
+    let event = match (&change.old_owner, &change.new_owner) {
+        (None, Some(new_owner)) => {
+            Event::new("mint").add_attribute("owner", new_owner)
+        }
+        (Some(old_owner), None) => {
+            Event::new("burn").add_attribute("owner", old_owner)
+        }
+        _ => Event::new("transfer")
+            .add_attribute(
+                "sender",
+                change.old_owner.clone().unwrap_or_default(),
+            )
+            .add_attribute(
+                "recipient",
+                change.new_owner.clone().unwrap_or_default(),
+            ),
+    };
+    event.add_attribute("token_id", &change.token_id)
+}
+
+fn hook_watches_change(
+    watch: &NftHookWatch,
+    change: &NftOwnershipChange,
+) -> bool {
+    match watch {
+        NftHookWatch::All {} => true,
+        NftHookWatch::TokenIds { token_ids } => token_ids
+            .iter()
+            .any(|token_id| token_id.to_string() == change.token_id),
+        NftHookWatch::Owners { owners } => owners.iter().any(|owner| {
+            change.old_owner.as_ref() == Some(owner)
+                || change.new_owner.as_ref() == Some(owner)
+        }),
+    }
+}
+
+/// Only events, for NFT ownership changes in before send hook
+/// Token factory caps gas of before send hook, so hook contracts are not called from there
+pub fn nft_ownership_change_events(
+    changes: &[NftOwnershipChange],
+) -> Vec<Event> {
+    changes.iter().map(nft_ownership_change_event).collect()
+}
+
+/// Build wasm events and hook messages for NFT ownership changes
+/// A failing hook contract does not revert the change
+pub fn notify_nft_ownership_changes(
+    storage: &dyn Storage,
+    changes: Vec<NftOwnershipChange>,
+) -> StdResult<(Vec<Event>, Vec<SubMsg>)> {
+    let events = nft_ownership_change_events(&changes);
+    let mut hook_submsgs: Vec<SubMsg> = vec![];
+    if changes.is_empty() {
+        return Ok((events, hook_submsgs));
+    }
+    for item in NFT_HOOKS.range(storage, None, None, Order::Ascending) {
+        let (hook_addr, watch) = item?;
+        let watched_changes: Vec<NftOwnershipChange> = changes
+            .iter()
+            .filter(|change| hook_watches_change(&watch, change))
+            .cloned()
+            .collect();
+        if watched_changes.is_empty() {
+            continue;
+        }
+        hook_submsgs.push(SubMsg {
+            id: REPLY_ID_NFT_HOOK,
+            msg: CosmosMsg::Wasm(WasmMsg::Execute {
+                contract_addr: hook_addr.to_string(),
+                msg: to_json_binary(&NftHookExecuteMsg::NftOwnershipChanged {
+                    changes: watched_changes,
+                })?,
+                funds: vec![],
+            }),
+            gas_limit: Some(NFT_HOOK_GAS_LIMIT),
+            reply_on: ReplyOn::Error,
+        });
+    }
+    Ok((events, hook_submsgs))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cosmwasm_std::{testing::MockStorage, Uint128};
+
+    #[test]
+    fn hooks_get_only_watched_changes_with_gas_limit() {
+        let mut storage = MockStorage::new();
+        let watches = [
+            ("all_hook", NftHookWatch::All {}),
+            (
+                "token_hook",
+                NftHookWatch::TokenIds {
+                    token_ids: vec![Uint128::new(2)],
+                },
+            ),
+            (
+                "owner_hook",
+                NftHookWatch::Owners {
+                    owners: vec!["carol".to_string()],
+                },
+            ),
+        ];
+        for (hook_addr, watch) in watches {
+            NFT_HOOKS
+                .save(&mut storage, &Addr::unchecked(hook_addr), &watch)
+                .unwrap();
+        }
+        let alice = Addr::unchecked("alice");
+        let mut changes = nft_mint_changes(&alice, &[1, 2]);
+        changes.push(nft_transfer_change(&alice, &Addr::unchecked("bob"), 1));
+
+        let (events, submsgs) =
+            notify_nft_ownership_changes(&storage, changes.clone()).unwrap();
+        assert_eq!(
+            events
+                .iter()
+                .map(|event| event.ty.as_str())
+                .collect::<Vec<_>>(),
+            vec!["mint", "mint", "transfer"]
+        );
+        // owner_hook watches nobody involved
+        assert_eq!(submsgs.len(), 2);
+        let expected = [
+            ("all_hook", changes.clone()),
+            ("token_hook", vec![changes[1].clone()]),
+        ];
+        for (submsg, (hook_addr, watched_changes)) in
+            submsgs.iter().zip(expected)
+        {
+            assert_eq!(submsg.gas_limit, Some(NFT_HOOK_GAS_LIMIT));
+            assert_eq!(submsg.reply_on, ReplyOn::Error);
+            assert_eq!(
+                submsg.msg,
+                CosmosMsg::Wasm(WasmMsg::Execute {
+                    contract_addr: hook_addr.to_string(),
+                    msg: to_json_binary(
+                        &NftHookExecuteMsg::NftOwnershipChanged {
+                            changes: watched_changes,
+                        }
+                    )
+                    .unwrap(),
+                    funds: vec![],
+                })
+            );
+        }
+    }
+}
@@ -0,0 +1,124 @@
+use crate::state::TRAIT_LAYERS;
+use cosmwasm_std::{Addr, BlockInfo, Order, StdResult, Storage};
+use cw404::nft_metadata::{TraitLayer, TraitMetadataConfig};
+use cw721_metadata_onchain::{Metadata as NftMetadata, Trait as NftTrait};
+use sha3::{Digest, Keccak256};
+use shared_pkg::error::ContractError;
+
+pub fn generate_trait_seed(contract_addr: &Addr, block: &BlockInfo) -> Vec<u8> {
+    let mut hasher = Keccak256::new();
+    hasher.update(contract_addr.as_bytes());
+    hasher.update(block.height.to_be_bytes());
+    hasher.update(block.time.nanos().to_be_bytes());
+    hasher.finalize().to_vec()
+}
+
+pub fn load_trait_layers(storage: &dyn Storage) -> StdResult<Vec<TraitLayer>> {
+    TRAIT_LAYERS
+        .range(storage, None, None, Order::Ascending)
+        .map(|item| item.map(|(_, trait_layer)| trait_layer))
+        .collect()
+}
+
+/// Add new trait layers, values of an existing trait type are appended to that layer
+pub fn save_trait_layers(
+    storage: &mut dyn Storage,
+    trait_layers: Vec<TraitLayer>,
+) -> Result<(), ContractError> {
+    let mut existing_trait_layers = load_trait_layers(storage)?;
+    for trait_layer in trait_layers {
+        if let Some(trait_value) =
+            trait_layer.values.iter().find(|value| value.weight == 0)
+        {
+            return Err(ContractError::TraitValueWeightIsZero {
+                trait_type: trait_layer.trait_type,
+                value: trait_value.value.clone(),
+            });
+        }
+        match existing_trait_layers
+            .iter()
+            .position(|layer| layer.trait_type == trait_layer.trait_type)
+        {
+            Some(idx) => {
+                let existing_trait_layer = &mut existing_trait_layers[idx];
+                existing_trait_layer.values.extend(trait_layer.values);
+                TRAIT_LAYERS.save(storage, idx as u32, existing_trait_layer)?;
+            }
+            None => {
+                if trait_layer.values.is_empty() {
+                    return Err(ContractError::TraitLayerHasNoValue {
+                        trait_type: trait_layer.trait_type,
+                    });
+                }
+                TRAIT_LAYERS.save(
+                    storage,
+                    existing_trait_layers.len() as u32,
+                    &trait_layer,
+                )?;
+                existing_trait_layers.push(trait_layer);
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Pick a value in the layer by weight, the same seed, token ID and layer always give the same value
+fn pick_trait_value_idx(
+    trait_seed: &[u8],
+    token_id: u128,
+    layer_idx: usize,
+    trait_layer: &TraitLayer,
+) -> usize {
+    let total_weight: u64 = trait_layer
+        .values
+        .iter()
+        .map(|value| value.weight as u64)
+        .sum();
+    let mut hasher = Keccak256::new();
+    hasher.update(trait_seed);
+    hasher.update(token_id.to_be_bytes());
+    hasher.update((layer_idx as u32).to_be_bytes());
+    let hash = hasher.finalize();
+    let mut roll =
+        u64::from_be_bytes(hash[0..8].try_into().unwrap()) % total_weight;
+    for (idx, value) in trait_layer.values.iter().enumerate() {
+        if roll < value.weight as u64 {
+            return idx;
+        }
+        roll -= value.weight as u64;
+    }
+    trait_layer.values.len() - 1
+}
+
+pub fn generate_nft_metadata(
+    trait_metadata_config: &TraitMetadataConfig,
+    trait_layers: &[TraitLayer],
+    trait_seed: &[u8],
+    token_id: u128,
+) -> NftMetadata {
+    let mut dna: Vec<String> = vec![];
+    let mut attributes: Vec<NftTrait> = vec![];
+    for (layer_idx, trait_layer) in trait_layers.iter().enumerate() {
+        let value_idx =
+            pick_trait_value_idx(trait_seed, token_id, layer_idx, trait_layer);
+        dna.push(value_idx.to_string());
+        attributes.push(NftTrait {
+            display_type: None,
+            trait_type: trait_layer.trait_type.clone(),
+            value: trait_layer.values[value_idx].value.clone(),
+        });
+    }
+    NftMetadata {
+        name: Some(format!(
+            "{}{}",
+            trait_metadata_config.name_prefix, token_id
+        )),
+        image: Some(format!(
+            "{}/{}",
+            trait_metadata_config.image_base_uri,
+            dna.join("-")
+        )),
+        attributes: Some(attributes),
+        ..NftMetadata::default()
+    }
+}
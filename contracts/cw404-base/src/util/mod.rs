@@ -1,3 +1,7 @@
+pub(crate) mod airdrop;
 pub(crate) mod assert_helper;
+pub(crate) mod astroport;
+pub(crate) mod hook;
+pub(crate) mod metadata;
 pub(crate) mod nft;
 pub(crate) mod token_factory;
@@ -1,14 +1,23 @@
-use super::assert_helper::{
-    assert_can_send, assert_can_update_approvals,
-    assert_max_base_denom_supply_not_reached,
+use super::{
+    assert_helper::{
+        assert_can_send, assert_can_update_approvals,
+        assert_max_base_denom_supply_not_reached,
+    },
+    metadata::{generate_nft_metadata, load_trait_layers},
 };
-use crate::state::{
-    CURRENT_NFT_SUPPLY, MAX_NFT_SUPPLY, MINT_GROUPS, NFTS, RECYCLED_NFTS,
-    RECYCLED_NFT_IDS,
+use crate::{
+    contract::REPLY_ID_NFT_TRANSFER,
+    state::{
+        CURRENT_NFT_SUPPLY, MAX_NFT_SUPPLY, MINT_GROUPS, NFTS,
+        NFT_EXEMPT_ADDRS, PENDING_NFT_TRANSFER, PRESET_NFT_METADATA,
+        RECYCLED_NFTS, RECYCLED_NFT_IDS, RECYCLED_NFT_POP_COUNT,
+        RECYCLED_NFT_SEQS, TRAIT_LAYERS_LOCKED, TRAIT_METADATA_CONFIG,
+        TRAIT_SEED,
+    },
 };
 use cosmwasm_std::{
     Addr, BlockInfo, Order, QuerierWrapper, StdError, StdResult, Storage,
-    Uint128,
+    SubMsg, Uint128,
 };
 use cw721::Approval as Cw721Approval;
 use cw721_base::state::{
@@ -18,8 +27,14 @@ use cw721_metadata_onchain::{
     Extension as NftExtension, Metadata as NftMetadata,
 };
 use cw_utils::Expiration;
-use sha3::{Digest, Keccak256};
-use shared_pkg::error::ContractError;
+use osmosis_std::types::{
+    cosmos::base::v1beta1::Coin as SdkCoin,
+    osmosis::tokenfactory::v1beta1::MsgForceTransfer,
+};
+use shared_pkg::{
+    error::ContractError,
+    merkle::{keccak256, verify_merkle_proof},
+};
 
 fn humanize_approval(approval: &Cw721BaseApproval) -> Cw721Approval {
     Cw721Approval {
@@ -88,7 +103,7 @@ pub fn assert_can_mint(
                     paid: user_paid_amount,
                 });
             }
-            if mg.merkle_root.is_some() {
+            if let Some(merkle_root) = mg.merkle_root {
                 if merkle_proof.is_none() {
                     return Err(
                         ContractError::MerkleProofRequiredForMintGroup {
@@ -96,22 +111,13 @@ pub fn assert_can_mint(
                         },
                     );
                 }
-                let mut hasher = Keccak256::new();
-                hasher.update(recipient_addr.to_string().as_bytes());
-                let recipient_hash = hasher.finalize().to_vec();
-                let mut calculated_root_hash: Vec<u8> = recipient_hash;
-                for proof_hash in merkle_proof.unwrap().into_iter() {
-                    let mut hasher = Keccak256::new();
-                    if calculated_root_hash < proof_hash {
-                        hasher.update(&calculated_root_hash);
-                        hasher.update(&proof_hash);
-                    } else {
-                        hasher.update(&proof_hash);
-                        hasher.update(&calculated_root_hash);
-                    }
-                    calculated_root_hash = hasher.finalize().to_vec();
-                }
-                if calculated_root_hash != mg.merkle_root.unwrap() {
+                let recipient_hash =
+                    keccak256(recipient_addr.to_string().as_bytes());
+                if !verify_merkle_proof(
+                    recipient_hash,
+                    merkle_proof.unwrap(),
+                    &merkle_root,
+                ) {
                     return Err(
                         ContractError::InvalidMerkleProofForMintGroup {
                             name: mint_group_name.to_string(),
@@ -152,6 +158,11 @@ pub fn calculate_nft_to_mint_for_ft_mint(
     Ok(mint_amount)
 }
 
+/// Exempt addresses hold FT without NFTs, e.g. seed liquidity pair
+pub fn is_nft_exempt(storage: &dyn Storage, addr: &Addr) -> StdResult<bool> {
+    Ok(NFT_EXEMPT_ADDRS.has(storage, addr))
+}
+
 pub fn calculate_nft_to_burn_for_ft_burn(
     querier: QuerierWrapper,
     owner_addr: &Addr,
@@ -173,30 +184,59 @@ pub fn batch_mint_nft(
     base_uri: &str,
     owner_addr: &Addr,
     amount: Uint128,
-) -> Result<(), ContractError> {
+) -> Result<Vec<u128>, ContractError> {
     let current_nft_supply = CURRENT_NFT_SUPPLY.load(storage)?;
+    let mut minted_token_ids: Vec<u128> = vec![];
+    let trait_metadata_config = TRAIT_METADATA_CONFIG.may_load(storage)?;
+    let trait_layers = match trait_metadata_config {
+        Some(_) => load_trait_layers(storage)?,
+        None => vec![],
+    };
+    let trait_seed = TRAIT_SEED.may_load(storage)?.unwrap_or_default();
+    let mut traits_generated = false;
     for i in 0..amount.u128() {
         let (nft_token_id, nft) = if RECYCLED_NFT_IDS.is_empty(storage)? {
             let nft_token_id =
                 (current_nft_supply + Uint128::from(1 + i)).u128();
+            let (token_uri, extension) =
+                match PRESET_NFT_METADATA.may_load(storage, nft_token_id)? {
+                    Some(preset_nft_metadata) => {
+                        PRESET_NFT_METADATA.remove(storage, nft_token_id);
+                        preset_nft_metadata
+                    }
+                    None => (
+                        Some(format!(
+                            "{}/{}",
+                            base_uri,
+                            current_nft_supply + Uint128::from(1 + i)
+                        )),
+                        Some(match &trait_metadata_config {
+                            Some(cfg) => {
+                                traits_generated = true;
+                                generate_nft_metadata(
+                                    cfg,
+                                    &trait_layers,
+                                    &trait_seed,
+                                    nft_token_id,
+                                )
+                            }
+                            None => NftMetadata::default(),
+                        }),
+                    ),
+                };
             let nft = NftTokenInfo {
                 owner: owner_addr.clone(),
                 approvals: vec![],
-                token_uri: Some(format!(
-                    "{}/{}",
-                    base_uri,
-                    current_nft_supply + Uint128::from(1 + i)
-                )),
-                extension: Some(NftMetadata {
-                    // TODO: add metadata
-                    ..NftMetadata::default()
-                }),
+                token_uri,
+                extension,
             };
             (nft_token_id, nft)
         } else {
-            let recycled_nft_id = RECYCLED_NFT_IDS.pop_front(storage)?.unwrap();
-            let recycled_nft = RECYCLED_NFTS.load(storage, recycled_nft_id)?;
-            RECYCLED_NFTS.remove(storage, recycled_nft_id);
+            let (recycled_nft_id, mut recycled_nft) =
+                pop_recycled_nft(storage)?;
+            // recycled NFT still has the owner and approvals from before it got burned
+            recycled_nft.owner = owner_addr.clone();
+            recycled_nft.approvals = vec![];
             (recycled_nft_id, recycled_nft)
         };
         NFTS().update(storage, nft_token_id, |old| match old {
@@ -205,17 +245,22 @@ pub fn batch_mint_nft(
             }),
             None => Ok(nft),
         })?;
+        minted_token_ids.push(nft_token_id);
+    }
+    if traits_generated {
+        // traits are picked from layers so layers must not change anymore
+        TRAIT_LAYERS_LOCKED.save(storage, &true)?;
     }
     let updated_nft_supply = current_nft_supply + amount;
     CURRENT_NFT_SUPPLY.save(storage, &updated_nft_supply)?;
-    Ok(())
+    Ok(minted_token_ids)
 }
 
 pub fn batch_burn_nft(
     storage: &mut dyn Storage,
     owner_addr: &Addr,
     amount: Uint128,
-) -> Result<(), ContractError> {
+) -> Result<Vec<u128>, ContractError> {
     let current_nft_supply = CURRENT_NFT_SUPPLY.load(storage)?;
     let token_ids: Vec<u128> = NFTS()
         .idx
@@ -230,17 +275,98 @@ pub fn batch_burn_nft(
             try_to_burn: amount,
         });
     }
-    for token_id in token_ids {
-        RECYCLED_NFT_IDS.push_back(storage, &token_id)?;
-        let recycled_nft = NFTS().load(storage, token_id)?;
-        RECYCLED_NFTS.save(storage, token_id, &recycled_nft)?;
-        NFTS().remove(storage, token_id)?;
+    for token_id in token_ids.iter() {
+        recycle_nft(storage, *token_id)?;
     }
     let updated_nft_supply: Uint128 = current_nft_supply - amount;
     CURRENT_NFT_SUPPLY.save(storage, &updated_nft_supply)?;
+    Ok(token_ids)
+}
+
+/// Force transfer 1 denom of FT backing an NFT transfer from old owner to new owner
+/// Reply clears the pending record whether or not the before send hook consumed it
+pub fn nft_transfer_force_transfer_submsg(
+    storage: &mut dyn Storage,
+    contract_addr: &Addr,
+    from_addr: &Addr,
+    to_addr: &Addr,
+    one_denom_in_base_denom: Uint128,
+    base_denom: &str,
+) -> StdResult<SubMsg> {
+    PENDING_NFT_TRANSFER
+        .save(storage, &(from_addr.clone(), to_addr.clone()))?;
+    let msg = MsgForceTransfer {
+        sender: contract_addr.to_string(),
+        amount: Some(SdkCoin {
+            amount: one_denom_in_base_denom.to_string(),
+            denom: base_denom.to_string(),
+        }),
+        transfer_from_address: from_addr.to_string(),
+        transfer_to_address: to_addr.to_string(),
+    };
+    Ok(SubMsg::reply_on_success(msg, REPLY_ID_NFT_TRANSFER))
+}
+
+/// Return true and consume the record if this FT move backs the NFT transfer in flight
+pub fn consume_pending_nft_transfer(
+    storage: &mut dyn Storage,
+    from_addr: &Addr,
+    to_addr: &Addr,
+) -> StdResult<bool> {
+    match PENDING_NFT_TRANSFER.may_load(storage)? {
+        Some((pending_from_addr, pending_to_addr))
+            if pending_from_addr == from_addr && pending_to_addr == to_addr =>
+        {
+            PENDING_NFT_TRANSFER.remove(storage);
+            Ok(true)
+        }
+        _ => Ok(false),
+    }
+}
+
+/// Move a live NFT to the end of the recycle queue
+pub fn recycle_nft(
+    storage: &mut dyn Storage,
+    token_id: u128,
+) -> Result<(), ContractError> {
+    let nft = NFTS().load(storage, token_id)?;
+    let pop_count = RECYCLED_NFT_POP_COUNT.may_load(storage)?.unwrap_or(0);
+    let queue_length = RECYCLED_NFT_IDS.len(storage)?;
+    RECYCLED_NFT_SEQS.save(
+        storage,
+        token_id,
+        &(pop_count + queue_length as u64),
+    )?;
+    RECYCLED_NFT_IDS.push_back(storage, &token_id)?;
+    RECYCLED_NFTS.save(storage, token_id, &nft)?;
+    NFTS().remove(storage, token_id)?;
     Ok(())
 }
 
+/// Take the NFT at the front of the recycle queue, caller must make sure the queue is not empty
+pub fn pop_recycled_nft(
+    storage: &mut dyn Storage,
+) -> Result<(u128, NftTokenInfo<NftExtension>), ContractError> {
+    let token_id = RECYCLED_NFT_IDS.pop_front(storage)?.unwrap();
+    let nft = RECYCLED_NFTS.load(storage, token_id)?;
+    RECYCLED_NFTS.remove(storage, token_id);
+    RECYCLED_NFT_SEQS.remove(storage, token_id);
+    let pop_count = RECYCLED_NFT_POP_COUNT.may_load(storage)?.unwrap_or(0);
+    RECYCLED_NFT_POP_COUNT.save(storage, &(pop_count + 1))?;
+    Ok((token_id, nft))
+}
+
+/// Position of a recycled NFT in the recycle queue, 0 is the front
+pub fn recycled_nft_queue_position(
+    storage: &dyn Storage,
+    token_id: u128,
+) -> StdResult<Option<u32>> {
+    let pop_count = RECYCLED_NFT_POP_COUNT.may_load(storage)?.unwrap_or(0);
+    Ok(RECYCLED_NFT_SEQS
+        .may_load(storage, token_id)?
+        .map(|seq| (seq - pop_count) as u32))
+}
+
 pub fn update_approvals(
     storage: &mut dyn Storage,
     block: &BlockInfo,
@@ -279,13 +405,15 @@ pub fn transfer_nft_helper(
     sender_addr: &Addr,
     recipient_addr: &Addr,
     token_id: u128,
-) -> Result<NftTokenInfo<NftExtension>, ContractError> {
+) -> Result<Addr, ContractError> {
     let mut nft = NFTS().load(storage, token_id)?;
     // ensure we have permissions
     assert_can_send(storage, block, sender_addr, token_id)?;
+    // sender can be an approved spender or operator, not necessarily the owner
+    let old_owner_addr = nft.owner;
     // set owner and remove existing approvals
     nft.owner = recipient_addr.clone();
     nft.approvals = vec![];
     NFTS().save(storage, token_id, &nft)?;
-    Ok(nft)
+    Ok(old_owner_addr)
 }
@@ -20,7 +20,6 @@ pub fn create_and_mint_token(
     storage: &mut dyn Storage,
     contract_addr: &Addr,
     admin_addr: Option<String>,
-    minter_addr: &Addr,
     creator_addr: &Addr,
     royalty_payment_addr: &Addr,
     royalty_percentage: Uint64,
@@ -72,7 +71,6 @@ pub fn create_and_mint_token(
         &Config {
             admin_addr: admin_addr
                 .map(|addr| api.addr_validate(&addr).unwrap()),
-            minter_addr: minter_addr.clone(),
             creator_addr: creator_addr.clone(),
             denom_metadata: metadata.clone(),
             royalty_payment_addr: royalty_payment_addr.clone(),
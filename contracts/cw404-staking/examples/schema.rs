@@ -0,0 +1,10 @@
+use cosmwasm_schema::write_api;
+use cw404_staking_pkg::msg::{ExecuteMsg, InstantiateMsg, QueryMsg};
+
+fn main() {
+    write_api! {
+        instantiate: InstantiateMsg,
+        execute: ExecuteMsg,
+        query: QueryMsg,
+    }
+}
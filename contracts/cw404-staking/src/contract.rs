@@ -0,0 +1,611 @@
+use crate::{
+    execute::{
+        config::update_config,
+        ownership::save_admin_addr,
+        reward::claim,
+        stake::{stake_nft, unstake_nfts, withdraw_nfts},
+    },
+    query::{
+        config::query_config,
+        reward::{query_pending_rewards, query_reward_state},
+        stake::{query_staked_nft, query_staked_nfts},
+    },
+    state::{CONFIG, REWARD_STATE},
+    util::assert_helper::assert_only_admin_can_call_this_function,
+};
+use cosmwasm_std::{
+    entry_point, to_json_binary, Binary, Deps, DepsMut, Env, MessageInfo,
+    Response, StdResult, Uint128,
+};
+use cw2::set_contract_version;
+use cw404_staking_pkg::{
+    config::{Config, RewardState},
+    msg::{ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg},
+};
+use cw_utils::nonpayable;
+use shared_pkg::{
+    error::ContractError,
+    migration::assert_can_migrate,
+    ownership::{
+        accept_admin, cancel_proposal, propose_new_admin, query_ownership,
+        renounce_admin,
+    },
+};
+
+pub const CONTRACT_NAME: &str = env!("CARGO_PKG_NAME");
+pub const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn instantiate(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: InstantiateMsg,
+) -> Result<Response, ContractError> {
+    nonpayable(&info)?;
+    set_contract_version(
+        deps.storage,
+        format!("crates.io:{CONTRACT_NAME}"),
+        CONTRACT_VERSION,
+    )?;
+    let admin_addr = match msg.admin_addr {
+        Some(admin) => Some(deps.api.addr_validate(&admin)?),
+        None => None,
+    };
+    CONFIG.save(
+        deps.storage,
+        &Config {
+            admin_addr: admin_addr.clone(),
+            collection_addr: deps.api.addr_validate(&msg.collection_addr)?,
+            reward_denom: msg.reward_denom.clone(),
+            reward_per_nft_per_second: msg.reward_per_nft_per_second,
+            unstake_cooldown_seconds: msg.unstake_cooldown_seconds,
+        },
+    )?;
+    REWARD_STATE.save(
+        deps.storage,
+        &RewardState {
+            reward_per_nft: Uint128::zero(),
+            last_updated_time: env.block.time,
+            total_staked_nfts: 0,
+        },
+    )?;
+    Ok(Response::new()
+        .add_attribute("action", "instantiate")
+        .add_attribute("contract_addr", env.contract.address)
+        .add_attribute(
+            "admin_addr",
+            match admin_addr {
+                Some(addr) => addr.to_string(),
+                None => "None".to_string(),
+            },
+        )
+        .add_attribute("collection_addr", msg.collection_addr)
+        .add_attribute("reward_denom", msg.reward_denom)
+        .add_attribute(
+            "reward_per_nft_per_second",
+            msg.reward_per_nft_per_second,
+        )
+        .add_attribute(
+            "unstake_cooldown_seconds",
+            msg.unstake_cooldown_seconds.to_string(),
+        ))
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn execute(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: ExecuteMsg,
+) -> Result<Response, ContractError> {
+    let info_ref = &info;
+    nonpayable(info_ref)?;
+
+    let sender_addr_ref = &info.clone().sender;
+    let config_ref = &CONFIG.load(deps.storage)?;
+    match msg {
+        ExecuteMsg::UpdateConfig {
+            new_reward_per_nft_per_second,
+            new_unstake_cooldown_seconds,
+        } => {
+            assert_only_admin_can_call_this_function(
+                sender_addr_ref,
+                &config_ref.admin_addr,
+                "update_config",
+            )?;
+            update_config(
+                deps.storage,
+                &env.block,
+                new_reward_per_nft_per_second,
+                new_unstake_cooldown_seconds,
+            )
+        }
+        ExecuteMsg::ProposeNewAdmin {
+            new_admin_addr,
+            expiry,
+        } => {
+            assert_only_admin_can_call_this_function(
+                sender_addr_ref,
+                &config_ref.admin_addr,
+                "propose_new_admin",
+            )?;
+            propose_new_admin(
+                deps.api,
+                deps.storage,
+                &env.block,
+                new_admin_addr,
+                expiry,
+            )
+        }
+        ExecuteMsg::AcceptAdmin {} => accept_admin(
+            deps.storage,
+            &env.block,
+            sender_addr_ref,
+            save_admin_addr,
+        ),
+        ExecuteMsg::CancelProposal {} => {
+            assert_only_admin_can_call_this_function(
+                sender_addr_ref,
+                &config_ref.admin_addr,
+                "cancel_proposal",
+            )?;
+            cancel_proposal(deps.storage)
+        }
+        ExecuteMsg::RenounceAdmin {} => {
+            assert_only_admin_can_call_this_function(
+                sender_addr_ref,
+                &config_ref.admin_addr,
+                "renounce_admin",
+            )?;
+            renounce_admin(deps.storage, save_admin_addr)
+        }
+        // sender is the collection, staker is the sender in Cw721ReceiveMsg
+        ExecuteMsg::ReceiveNft(receive_msg) => stake_nft(
+            deps.storage,
+            &env.block,
+            config_ref,
+            sender_addr_ref,
+            &deps.api.addr_validate(&receive_msg.sender)?,
+            receive_msg.token_id,
+        ),
+        ExecuteMsg::Claim {} => {
+            claim(deps.storage, &env.block, config_ref, sender_addr_ref)
+        }
+        ExecuteMsg::Unstake { token_ids } => unstake_nfts(
+            deps.storage,
+            &env.block,
+            config_ref,
+            sender_addr_ref,
+            token_ids,
+        ),
+        ExecuteMsg::WithdrawNfts { token_ids } => withdraw_nfts(
+            deps.storage,
+            &env.block,
+            config_ref,
+            sender_addr_ref,
+            token_ids,
+        ),
+    }
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
+    let config_ref = &CONFIG.load(deps.storage)?;
+    match msg {
+        QueryMsg::Config {} => to_json_binary(&query_config(config_ref)?),
+        QueryMsg::Ownership {} => to_json_binary(&query_ownership(
+            deps.storage,
+            config_ref.admin_addr.clone(),
+        )?),
+        QueryMsg::RewardState {} => to_json_binary(&query_reward_state(
+            deps.storage,
+            &env.block,
+            config_ref,
+        )?),
+        QueryMsg::PendingRewards { staker } => {
+            to_json_binary(&query_pending_rewards(
+                deps.storage,
+                &env.block,
+                config_ref,
+                &deps.api.addr_validate(&staker)?,
+            )?)
+        }
+        QueryMsg::StakedNft { token_id } => {
+            to_json_binary(&query_staked_nft(deps.storage, token_id)?)
+        }
+        QueryMsg::StakedNfts {
+            staker,
+            start_after,
+            limit,
+        } => to_json_binary(&query_staked_nfts(
+            deps.storage,
+            &deps.api.addr_validate(&staker)?,
+            start_after,
+            limit,
+        )?),
+    }
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn migrate(
+    deps: DepsMut,
+    _env: Env,
+    _msg: MigrateMsg,
+) -> Result<Response, ContractError> {
+    let contract_name = format!("crates.io:{CONTRACT_NAME}");
+    let stored_version =
+        assert_can_migrate(deps.storage, &contract_name, CONTRACT_VERSION)?;
+    set_contract_version(deps.storage, contract_name, CONTRACT_VERSION)?;
+    Ok(Response::new()
+        .add_attribute("action", "migrate")
+        .add_attribute("from_version", stored_version.to_string())
+        .add_attribute("to_version", CONTRACT_VERSION))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cosmwasm_std::{
+        coins, from_json,
+        testing::{
+            mock_dependencies, mock_env, mock_info, MockApi, MockQuerier,
+            MockStorage,
+        },
+        Addr, BankMsg, Binary, CosmosMsg, OwnedDeps, WasmMsg,
+    };
+    use cw404_staking_pkg::msg::PendingRewardsResponse;
+    use cw721::{Cw721ExecuteMsg, Cw721ReceiveMsg};
+    use shared_pkg::ownership::OwnershipResponse;
+
+    const COLLECTION: &str = "collection";
+    const REWARD_DENOM: &str = "untrn";
+
+    type MockDeps = OwnedDeps<MockStorage, MockApi, MockQuerier>;
+
+    /// 10 reward per NFT per second, env time is in seconds after instantiation
+    fn setup(unstake_cooldown_seconds: u64) -> MockDeps {
+        let mut deps = mock_dependencies();
+        instantiate(
+            deps.as_mut(),
+            env_at(0),
+            mock_info("creator", &[]),
+            InstantiateMsg {
+                admin_addr: Some("admin".to_string()),
+                collection_addr: COLLECTION.to_string(),
+                reward_denom: REWARD_DENOM.to_string(),
+                reward_per_nft_per_second: Uint128::new(10),
+                unstake_cooldown_seconds,
+            },
+        )
+        .unwrap();
+        deps
+    }
+
+    fn env_at(seconds: u64) -> Env {
+        let mut env = mock_env();
+        env.block.time = env.block.time.plus_seconds(seconds);
+        env
+    }
+
+    fn exec(
+        deps: &mut MockDeps,
+        seconds: u64,
+        sender: &str,
+        msg: ExecuteMsg,
+    ) -> Result<Response, ContractError> {
+        execute(deps.as_mut(), env_at(seconds), mock_info(sender, &[]), msg)
+    }
+
+    fn stake(deps: &mut MockDeps, seconds: u64, staker: &str, token_id: &str) {
+        exec(
+            deps,
+            seconds,
+            COLLECTION,
+            ExecuteMsg::ReceiveNft(Cw721ReceiveMsg {
+                sender: staker.to_string(),
+                token_id: token_id.to_string(),
+                msg: Binary::default(),
+            }),
+        )
+        .unwrap();
+    }
+
+    fn token_ids(token_ids: &[&str]) -> Vec<String> {
+        token_ids
+            .iter()
+            .map(|token_id| token_id.to_string())
+            .collect()
+    }
+
+    fn pending_rewards(deps: &MockDeps, seconds: u64, staker: &str) -> u128 {
+        let resp: PendingRewardsResponse = from_json(
+            query(
+                deps.as_ref(),
+                env_at(seconds),
+                QueryMsg::PendingRewards {
+                    staker: staker.to_string(),
+                },
+            )
+            .unwrap(),
+        )
+        .unwrap();
+        resp.pending_rewards.u128()
+    }
+
+    fn returned_nfts(resp: &Response) -> Vec<(String, String)> {
+        resp.messages
+            .iter()
+            .map(|submsg| match &submsg.msg {
+                CosmosMsg::Wasm(WasmMsg::Execute {
+                    contract_addr,
+                    msg,
+                    ..
+                }) => {
+                    assert_eq!(contract_addr, COLLECTION);
+                    match from_json(msg).unwrap() {
+                        Cw721ExecuteMsg::TransferNft {
+                            recipient,
+                            token_id,
+                        } => (recipient, token_id),
+                        _ => panic!("unexpected collection msg"),
+                    }
+                }
+                _ => panic!("unexpected msg"),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn rewards_are_shared_per_staked_nft() {
+        let mut deps = setup(0);
+        stake(&mut deps, 0, "alice", "1");
+        stake(&mut deps, 100, "bob", "2");
+        stake(&mut deps, 100, "bob", "3");
+        // alice has the only NFT for 100 seconds, then 1 of 3 for 100 seconds
+        assert_eq!(pending_rewards(&deps, 200, "alice"), 2_000);
+        assert_eq!(pending_rewards(&deps, 200, "bob"), 2_000);
+        assert_eq!(pending_rewards(&deps, 200, "carol"), 0);
+        assert_eq!(
+            REWARD_STATE.load(&deps.storage).unwrap().total_staked_nfts,
+            3
+        );
+    }
+
+    #[test]
+    fn only_configured_collection_can_stake() {
+        let mut deps = setup(0);
+        let err = exec(
+            &mut deps,
+            0,
+            "other_collection",
+            ExecuteMsg::ReceiveNft(Cw721ReceiveMsg {
+                sender: "alice".to_string(),
+                token_id: "1".to_string(),
+                msg: Binary::default(),
+            }),
+        )
+        .unwrap_err();
+        assert!(matches!(
+            err,
+            ContractError::NftFromUnexpectedCollection { .. }
+        ));
+    }
+
+    #[test]
+    fn claim_pays_unclaimed_rewards_once() {
+        let mut deps = setup(0);
+        stake(&mut deps, 0, "alice", "1");
+        let resp = exec(&mut deps, 50, "alice", ExecuteMsg::Claim {}).unwrap();
+        assert_eq!(
+            resp.messages[0].msg,
+            CosmosMsg::Bank(BankMsg::Send {
+                to_address: "alice".to_string(),
+                amount: coins(500, REWARD_DENOM),
+            })
+        );
+        assert!(matches!(
+            exec(&mut deps, 50, "alice", ExecuteMsg::Claim {}).unwrap_err(),
+            ContractError::NoRewardsToClaim {}
+        ));
+        assert_eq!(pending_rewards(&deps, 60, "alice"), 100);
+    }
+
+    #[test]
+    fn unstake_without_cooldown_returns_nft_and_stops_rewards() {
+        let mut deps = setup(0);
+        stake(&mut deps, 0, "alice", "1");
+        stake(&mut deps, 0, "alice", "2");
+        assert!(matches!(
+            exec(
+                &mut deps,
+                10,
+                "bob",
+                ExecuteMsg::Unstake {
+                    token_ids: token_ids(&["1"]),
+                },
+            )
+            .unwrap_err(),
+            ContractError::NftNotStakedBySender { .. }
+        ));
+        let resp = exec(
+            &mut deps,
+            10,
+            "alice",
+            ExecuteMsg::Unstake {
+                token_ids: token_ids(&["1"]),
+            },
+        )
+        .unwrap();
+        assert_eq!(
+            returned_nfts(&resp),
+            vec![("alice".to_string(), "1".to_string())]
+        );
+        // 2 NFTs for 10 seconds, then only NFT 2 for 10 seconds
+        assert_eq!(pending_rewards(&deps, 20, "alice"), 300);
+        assert_eq!(
+            REWARD_STATE.load(&deps.storage).unwrap().total_staked_nfts,
+            1
+        );
+    }
+
+    #[test]
+    fn unstaked_nft_is_withdrawn_after_cooldown() {
+        let mut deps = setup(100);
+        stake(&mut deps, 0, "alice", "1");
+        let resp = exec(
+            &mut deps,
+            10,
+            "alice",
+            ExecuteMsg::Unstake {
+                token_ids: token_ids(&["1"]),
+            },
+        )
+        .unwrap();
+        assert!(resp.messages.is_empty());
+        // no rewards during cooldown
+        assert_eq!(pending_rewards(&deps, 50, "alice"), 100);
+        assert!(matches!(
+            exec(
+                &mut deps,
+                20,
+                "alice",
+                ExecuteMsg::Unstake {
+                    token_ids: token_ids(&["1"]),
+                },
+            )
+            .unwrap_err(),
+            ContractError::NftAlreadyUnstaked { .. }
+        ));
+        assert!(matches!(
+            exec(
+                &mut deps,
+                109,
+                "alice",
+                ExecuteMsg::WithdrawNfts {
+                    token_ids: token_ids(&["1"]),
+                },
+            )
+            .unwrap_err(),
+            ContractError::NftInUnstakeCooldown { .. }
+        ));
+        let resp = exec(
+            &mut deps,
+            110,
+            "alice",
+            ExecuteMsg::WithdrawNfts {
+                token_ids: token_ids(&["1"]),
+            },
+        )
+        .unwrap();
+        assert_eq!(
+            returned_nfts(&resp),
+            vec![("alice".to_string(), "1".to_string())]
+        );
+        // NFT has left this contract
+        assert!(matches!(
+            exec(
+                &mut deps,
+                110,
+                "alice",
+                ExecuteMsg::WithdrawNfts {
+                    token_ids: token_ids(&["1"]),
+                },
+            )
+            .unwrap_err(),
+            ContractError::NftNotStakedBySender { .. }
+        ));
+    }
+
+    #[test]
+    fn staked_nft_cannot_be_withdrawn() {
+        let mut deps = setup(100);
+        stake(&mut deps, 0, "alice", "1");
+        assert!(matches!(
+            exec(
+                &mut deps,
+                200,
+                "alice",
+                ExecuteMsg::WithdrawNfts {
+                    token_ids: token_ids(&["1"]),
+                },
+            )
+            .unwrap_err(),
+            ContractError::NftNotUnstaked { .. }
+        ));
+    }
+
+    #[test]
+    fn reward_rate_change_keeps_rewards_accrued_at_old_rate() {
+        let mut deps = setup(0);
+        stake(&mut deps, 0, "alice", "1");
+        let update_config = ExecuteMsg::UpdateConfig {
+            new_reward_per_nft_per_second: Some(Uint128::new(1)),
+            new_unstake_cooldown_seconds: None,
+        };
+        assert!(matches!(
+            exec(&mut deps, 100, "alice", update_config.clone()).unwrap_err(),
+            ContractError::OnlyAdminCanCallThisFunction { .. }
+        ));
+        exec(&mut deps, 100, "admin", update_config).unwrap();
+        assert_eq!(pending_rewards(&deps, 200, "alice"), 1_100);
+    }
+
+    #[test]
+    fn admin_changes_in_two_steps() {
+        let mut deps = setup(0);
+        exec(
+            &mut deps,
+            0,
+            "admin",
+            ExecuteMsg::ProposeNewAdmin {
+                new_admin_addr: "new_admin".to_string(),
+                expiry: None,
+            },
+        )
+        .unwrap();
+        assert!(matches!(
+            exec(&mut deps, 0, "alice", ExecuteMsg::AcceptAdmin {})
+                .unwrap_err(),
+            ContractError::OnlyPendingAdminCanAccept {}
+        ));
+        exec(&mut deps, 0, "new_admin", ExecuteMsg::AcceptAdmin {}).unwrap();
+        let ownership: OwnershipResponse = from_json(
+            query(deps.as_ref(), env_at(0), QueryMsg::Ownership {}).unwrap(),
+        )
+        .unwrap();
+        assert_eq!(ownership.admin_addr, Some(Addr::unchecked("new_admin")));
+        assert_eq!(ownership.pending_admin, None);
+
+        exec(&mut deps, 0, "new_admin", ExecuteMsg::RenounceAdmin {}).unwrap();
+        assert!(matches!(
+            exec(
+                &mut deps,
+                0,
+                "new_admin",
+                ExecuteMsg::UpdateConfig {
+                    new_reward_per_nft_per_second: None,
+                    new_unstake_cooldown_seconds: Some(1),
+                },
+            )
+            .unwrap_err(),
+            ContractError::OnlyAdminCanCallThisFunctionButContractHasNoAdmin { .. }
+        ));
+    }
+
+    #[test]
+    fn migrate_only_from_same_contract() {
+        let mut deps = setup(0);
+        migrate(deps.as_mut(), env_at(0), MigrateMsg {}).unwrap();
+        set_contract_version(
+            &mut deps.storage,
+            "crates.io:cw404-base",
+            "0.1.0",
+        )
+        .unwrap();
+        assert!(matches!(
+            migrate(deps.as_mut(), env_at(0), MigrateMsg {}).unwrap_err(),
+            ContractError::CannotMigrateFromDifferentContract { .. }
+        ));
+    }
+}
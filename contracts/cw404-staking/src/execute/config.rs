@@ -0,0 +1,21 @@
+use crate::{state::CONFIG, util::reward::update_reward_state};
+use cosmwasm_std::{BlockInfo, Response, Storage, Uint128};
+use shared_pkg::error::ContractError;
+
+pub fn update_config(
+    storage: &mut dyn Storage,
+    block: &BlockInfo,
+    new_reward_per_nft_per_second: Option<Uint128>,
+    new_unstake_cooldown_seconds: Option<u64>,
+) -> Result<Response, ContractError> {
+    let mut config = CONFIG.load(storage)?;
+    // accrue rewards at the old rate before the rate changes
+    update_reward_state(storage, &config, block)?;
+    config.reward_per_nft_per_second = new_reward_per_nft_per_second
+        .unwrap_or(config.reward_per_nft_per_second);
+    // only applies to NFTs unstaked from now on
+    config.unstake_cooldown_seconds =
+        new_unstake_cooldown_seconds.unwrap_or(config.unstake_cooldown_seconds);
+    CONFIG.save(storage, &config)?;
+    Ok(Response::new().add_attribute("action", "update_config"))
+}
@@ -0,0 +1,4 @@
+pub(crate) mod config;
+pub(crate) mod ownership;
+pub(crate) mod reward;
+pub(crate) mod stake;
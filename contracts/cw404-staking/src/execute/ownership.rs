@@ -0,0 +1,12 @@
+use crate::state::CONFIG;
+use cosmwasm_std::{Addr, StdResult, Storage};
+
+/// Passed to shared ownership handlers, admin lives in this contract's config
+pub fn save_admin_addr(
+    storage: &mut dyn Storage,
+    admin_addr: Option<Addr>,
+) -> StdResult<()> {
+    let mut config = CONFIG.load(storage)?;
+    config.admin_addr = admin_addr;
+    CONFIG.save(storage, &config)
+}
@@ -0,0 +1,38 @@
+use crate::{
+    state::STAKERS,
+    util::reward::{settle_staker_rewards, update_reward_state},
+};
+use cosmwasm_std::{
+    coins, Addr, BankMsg, BlockInfo, Response, Storage, Uint128,
+};
+use cw404_staking_pkg::config::Config;
+use shared_pkg::error::ContractError;
+
+pub fn claim(
+    storage: &mut dyn Storage,
+    block: &BlockInfo,
+    config: &Config,
+    staker_addr: &Addr,
+) -> Result<Response, ContractError> {
+    let reward_state = update_reward_state(storage, config, block)?;
+    let mut staker_info = settle_staker_rewards(
+        storage,
+        staker_addr,
+        reward_state.reward_per_nft,
+    )?;
+    let claim_amount = staker_info.unclaimed_rewards;
+    if claim_amount.is_zero() {
+        return Err(ContractError::NoRewardsToClaim {});
+    }
+    staker_info.unclaimed_rewards = Uint128::zero();
+    STAKERS.save(storage, staker_addr, &staker_info)?;
+    Ok(Response::new()
+        .add_message(BankMsg::Send {
+            to_address: staker_addr.to_string(),
+            amount: coins(claim_amount.u128(), &config.reward_denom),
+        })
+        .add_attribute("action", "claim")
+        .add_attribute("staker", staker_addr)
+        .add_attribute("amount", claim_amount)
+        .add_attribute("denom", &config.reward_denom))
+}
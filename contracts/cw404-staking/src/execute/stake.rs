@@ -0,0 +1,144 @@
+use crate::{
+    state::{REWARD_STATE, STAKED_NFTS, STAKERS},
+    util::{
+        reward::{settle_staker_rewards, update_reward_state},
+        stake::{
+            load_staked_nft_of_staker, parse_token_id_from_string_to_u128,
+            return_nft_msg,
+        },
+    },
+};
+use cosmwasm_std::{Addr, BlockInfo, Response, Storage};
+use cw404_staking_pkg::{config::Config, stake::StakedNft};
+use shared_pkg::error::ContractError;
+
+pub fn stake_nft(
+    storage: &mut dyn Storage,
+    block: &BlockInfo,
+    config: &Config,
+    collection_addr: &Addr,
+    staker_addr: &Addr,
+    token_id: String,
+) -> Result<Response, ContractError> {
+    // anyone can call ReceiveNft, only trust the configured collection
+    if collection_addr != config.collection_addr {
+        return Err(ContractError::NftFromUnexpectedCollection {
+            expected: config.collection_addr.to_string(),
+            actual: collection_addr.to_string(),
+        });
+    }
+    let token_id_in_u128 = parse_token_id_from_string_to_u128(&token_id)?;
+    let mut reward_state = update_reward_state(storage, config, block)?;
+    let mut staker_info = settle_staker_rewards(
+        storage,
+        staker_addr,
+        reward_state.reward_per_nft,
+    )?;
+    staker_info.staked_nft_count += 1;
+    STAKERS.save(storage, staker_addr, &staker_info)?;
+    reward_state.total_staked_nfts += 1;
+    REWARD_STATE.save(storage, &reward_state)?;
+    STAKED_NFTS().save(
+        storage,
+        token_id_in_u128,
+        &StakedNft {
+            token_id: token_id.clone(),
+            staker_addr: staker_addr.clone(),
+            staked_time: block.time,
+            unstake_release_time: None,
+        },
+    )?;
+    Ok(Response::new()
+        .add_attribute("action", "stake")
+        .add_attribute("staker", staker_addr)
+        .add_attribute("token_id", token_id))
+}
+
+pub fn unstake_nfts(
+    storage: &mut dyn Storage,
+    block: &BlockInfo,
+    config: &Config,
+    staker_addr: &Addr,
+    token_ids: Vec<String>,
+) -> Result<Response, ContractError> {
+    let mut reward_state = update_reward_state(storage, config, block)?;
+    let mut staker_info = settle_staker_rewards(
+        storage,
+        staker_addr,
+        reward_state.reward_per_nft,
+    )?;
+    let mut return_nft_msgs = vec![];
+    for token_id in token_ids.iter() {
+        let (token_id_in_u128, mut staked_nft) =
+            load_staked_nft_of_staker(storage, staker_addr, token_id)?;
+        if staked_nft.unstake_release_time.is_some() {
+            return Err(ContractError::NftAlreadyUnstaked {
+                token_id: token_id.clone(),
+            });
+        }
+        staker_info.staked_nft_count -= 1;
+        reward_state.total_staked_nfts -= 1;
+        if config.unstake_cooldown_seconds == 0 {
+            STAKED_NFTS().remove(storage, token_id_in_u128)?;
+            return_nft_msgs.push(return_nft_msg(
+                &config.collection_addr,
+                staker_addr,
+                token_id,
+            )?);
+        } else {
+            staked_nft.unstake_release_time =
+                Some(block.time.plus_seconds(config.unstake_cooldown_seconds));
+            STAKED_NFTS().save(storage, token_id_in_u128, &staked_nft)?;
+        }
+    }
+    STAKERS.save(storage, staker_addr, &staker_info)?;
+    REWARD_STATE.save(storage, &reward_state)?;
+    Ok(Response::new()
+        .add_messages(return_nft_msgs)
+        .add_attribute("action", "unstake")
+        .add_attribute("staker", staker_addr)
+        .add_attribute("token_ids", token_ids.join(","))
+        .add_attribute(
+            "unstake_cooldown_seconds",
+            config.unstake_cooldown_seconds.to_string(),
+        ))
+}
+
+pub fn withdraw_nfts(
+    storage: &mut dyn Storage,
+    block: &BlockInfo,
+    config: &Config,
+    staker_addr: &Addr,
+    token_ids: Vec<String>,
+) -> Result<Response, ContractError> {
+    let mut return_nft_msgs = vec![];
+    for token_id in token_ids.iter() {
+        let (token_id_in_u128, staked_nft) =
+            load_staked_nft_of_staker(storage, staker_addr, token_id)?;
+        match staked_nft.unstake_release_time {
+            None => {
+                return Err(ContractError::NftNotUnstaked {
+                    token_id: token_id.clone(),
+                });
+            }
+            Some(release_time) if block.time < release_time => {
+                return Err(ContractError::NftInUnstakeCooldown {
+                    token_id: token_id.clone(),
+                    release_time,
+                });
+            }
+            Some(_) => {}
+        }
+        STAKED_NFTS().remove(storage, token_id_in_u128)?;
+        return_nft_msgs.push(return_nft_msg(
+            &config.collection_addr,
+            staker_addr,
+            token_id,
+        )?);
+    }
+    Ok(Response::new()
+        .add_messages(return_nft_msgs)
+        .add_attribute("action", "withdraw_nfts")
+        .add_attribute("staker", staker_addr)
+        .add_attribute("token_ids", token_ids.join(",")))
+}
@@ -0,0 +1,5 @@
+pub mod contract;
+mod execute;
+mod query;
+mod state;
+mod util;
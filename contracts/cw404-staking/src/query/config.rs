@@ -0,0 +1,8 @@
+use cosmwasm_std::StdResult;
+use cw404_staking_pkg::{config::Config, msg::ConfigResponse};
+
+pub fn query_config(config: &Config) -> StdResult<ConfigResponse> {
+    Ok(ConfigResponse {
+        config: config.clone(),
+    })
+}
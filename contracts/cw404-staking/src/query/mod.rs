@@ -0,0 +1,3 @@
+pub(crate) mod config;
+pub(crate) mod reward;
+pub(crate) mod stake;
@@ -0,0 +1,44 @@
+use crate::{
+    state::REWARD_STATE,
+    util::reward::{accrue_reward_state, settle_staker_rewards},
+};
+use cosmwasm_std::{Addr, BlockInfo, StdResult, Storage};
+use cw404_staking_pkg::{
+    config::Config,
+    msg::{PendingRewardsResponse, RewardStateResponse},
+};
+
+pub fn query_reward_state(
+    storage: &dyn Storage,
+    block: &BlockInfo,
+    config: &Config,
+) -> StdResult<RewardStateResponse> {
+    Ok(RewardStateResponse {
+        reward_state: accrue_reward_state(
+            config,
+            &REWARD_STATE.load(storage)?,
+            block,
+        ),
+    })
+}
+
+pub fn query_pending_rewards(
+    storage: &dyn Storage,
+    block: &BlockInfo,
+    config: &Config,
+    staker_addr: &Addr,
+) -> StdResult<PendingRewardsResponse> {
+    let reward_state =
+        accrue_reward_state(config, &REWARD_STATE.load(storage)?, block);
+    let staker_info = settle_staker_rewards(
+        storage,
+        staker_addr,
+        reward_state.reward_per_nft,
+    )?;
+    Ok(PendingRewardsResponse {
+        staker: staker_addr.to_string(),
+        staked_nft_count: staker_info.staked_nft_count,
+        pending_rewards: staker_info.unclaimed_rewards,
+        reward_denom: config.reward_denom.clone(),
+    })
+}
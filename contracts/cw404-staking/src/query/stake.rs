@@ -0,0 +1,43 @@
+use crate::{
+    state::{DEFAULT_LIMIT, MAX_LIMIT, STAKED_NFTS},
+    util::stake::parse_token_id_from_string_to_u128,
+};
+use cosmwasm_std::{Addr, Order, StdResult, Storage};
+use cw404_staking_pkg::msg::{StakedNftResponse, StakedNftsResponse};
+use cw_storage_plus::Bound;
+
+pub fn query_staked_nft(
+    storage: &dyn Storage,
+    token_id: String,
+) -> StdResult<StakedNftResponse> {
+    let token_id_in_u128 = parse_token_id_from_string_to_u128(&token_id)?;
+    Ok(StakedNftResponse {
+        staked_nft: STAKED_NFTS().load(storage, token_id_in_u128)?,
+    })
+}
+
+pub fn query_staked_nfts(
+    storage: &dyn Storage,
+    staker_addr: &Addr,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<StakedNftsResponse> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let start = match start_after {
+        Some(token_id) => Some(Bound::exclusive(
+            parse_token_id_from_string_to_u128(&token_id)?,
+        )),
+        None => None,
+    };
+
+    let staked_nfts = STAKED_NFTS()
+        .idx
+        .staker
+        .prefix(staker_addr.clone())
+        .range(storage, start, None, Order::Ascending)
+        .take(limit)
+        .map(|item| item.map(|(_, staked_nft)| staked_nft))
+        .collect::<StdResult<Vec<_>>>()?;
+
+    Ok(StakedNftsResponse { staked_nfts })
+}
@@ -0,0 +1,40 @@
+use cosmwasm_std::Addr;
+use cw404_staking_pkg::{
+    config::{Config, RewardState},
+    stake::{StakedNft, StakerInfo},
+};
+use cw_storage_plus::{Index, IndexList, IndexedMap, Item, Map, MultiIndex};
+
+pub const DEFAULT_LIMIT: u32 = 10;
+pub const MAX_LIMIT: u32 = 100;
+
+pub const CONFIG: Item<Config> = Item::new("CONFIG");
+pub const REWARD_STATE: Item<RewardState> = Item::new("REWARD_STATE");
+
+/// Reward bookkeeping of each staker, key is staker address
+pub const STAKERS: Map<&Addr, StakerInfo> = Map::new("STAKERS");
+
+pub struct StakedNftIndexes<'a> {
+    pub staker: MultiIndex<'a, Addr, StakedNft, u128>,
+}
+impl<'a> IndexList<StakedNft> for StakedNftIndexes<'a> {
+    fn get_indexes(
+        &'_ self,
+    ) -> Box<dyn Iterator<Item = &'_ dyn Index<StakedNft>> + '_> {
+        let v: Vec<&dyn Index<StakedNft>> = vec![&self.staker];
+        Box::new(v.into_iter())
+    }
+}
+/// NFTs held by this contract, including NFTs in unstake cooldown, key is NFT token ID
+#[allow(non_snake_case)]
+pub fn STAKED_NFTS<'a>() -> IndexedMap<'a, u128, StakedNft, StakedNftIndexes<'a>>
+{
+    let indexes = StakedNftIndexes {
+        staker: MultiIndex::new(
+            |_token_id, staked_nft| staked_nft.staker_addr.clone(),
+            "STAKED_NFTS",
+            "STAKED_NFTS_STAKER",
+        ),
+    };
+    IndexedMap::new("STAKED_NFTS", indexes)
+}
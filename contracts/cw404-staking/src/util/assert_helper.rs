@@ -0,0 +1,24 @@
+use cosmwasm_std::Addr;
+use shared_pkg::error::ContractError;
+
+pub fn assert_only_admin_can_call_this_function(
+    sender: &Addr,
+    admin: &Option<Addr>,
+    function: &str,
+) -> Result<(), ContractError> {
+    match admin {
+        Some(admin) => {
+            if sender != admin {
+                return Err(ContractError::OnlyAdminCanCallThisFunction {
+                    function: function.to_string(),
+                });
+            }
+        }
+        None => {
+            return Err(ContractError::OnlyAdminCanCallThisFunctionButContractHasNoAdmin {
+                function: function.to_string(),
+            });
+        }
+    }
+    Ok(())
+}
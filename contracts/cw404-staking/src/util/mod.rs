@@ -0,0 +1,3 @@
+pub(crate) mod assert_helper;
+pub(crate) mod reward;
+pub(crate) mod stake;
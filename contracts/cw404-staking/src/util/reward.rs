@@ -0,0 +1,59 @@
+use crate::state::{REWARD_STATE, STAKERS};
+use cosmwasm_std::{Addr, BlockInfo, StdResult, Storage, Uint128};
+use cw404_staking_pkg::{
+    config::{Config, RewardState},
+    stake::StakerInfo,
+};
+
+/// Return reward state accrued up to the current block time without saving it
+pub fn accrue_reward_state(
+    config: &Config,
+    reward_state: &RewardState,
+    block: &BlockInfo,
+) -> RewardState {
+    let elapsed_seconds = block
+        .time
+        .seconds()
+        .saturating_sub(reward_state.last_updated_time.seconds());
+    RewardState {
+        reward_per_nft: reward_state.reward_per_nft
+            + config.reward_per_nft_per_second * Uint128::from(elapsed_seconds),
+        last_updated_time: block.time,
+        total_staked_nfts: reward_state.total_staked_nfts,
+    }
+}
+
+/// Accrue rewards up to the current block time, must be called before staked NFT count or reward rate changes
+pub fn update_reward_state(
+    storage: &mut dyn Storage,
+    config: &Config,
+    block: &BlockInfo,
+) -> StdResult<RewardState> {
+    let reward_state =
+        accrue_reward_state(config, &REWARD_STATE.load(storage)?, block);
+    REWARD_STATE.save(storage, &reward_state)?;
+    Ok(reward_state)
+}
+
+/// Return staker info with rewards settled up to reward_per_nft, caller must save it
+pub fn settle_staker_rewards(
+    storage: &dyn Storage,
+    staker_addr: &Addr,
+    reward_per_nft: Uint128,
+) -> StdResult<StakerInfo> {
+    let staker_info =
+        STAKERS
+            .may_load(storage, staker_addr)?
+            .unwrap_or(StakerInfo {
+                staked_nft_count: 0,
+                reward_per_nft_paid: reward_per_nft,
+                unclaimed_rewards: Uint128::zero(),
+            });
+    let earned_rewards = (reward_per_nft - staker_info.reward_per_nft_paid)
+        * Uint128::from(staker_info.staked_nft_count);
+    Ok(StakerInfo {
+        staked_nft_count: staker_info.staked_nft_count,
+        reward_per_nft_paid: reward_per_nft,
+        unclaimed_rewards: staker_info.unclaimed_rewards + earned_rewards,
+    })
+}
@@ -0,0 +1,48 @@
+use crate::state::STAKED_NFTS;
+use cosmwasm_std::{
+    to_json_binary, Addr, CosmosMsg, StdError, StdResult, Storage, WasmMsg,
+};
+use cw404_staking_pkg::stake::StakedNft;
+use cw721::Cw721ExecuteMsg;
+use shared_pkg::error::ContractError;
+
+pub fn parse_token_id_from_string_to_u128(token_id: &str) -> StdResult<u128> {
+    token_id
+        .parse::<u128>()
+        .map_err(|_| StdError::generic_err("token_id is not a valid u128"))
+}
+
+/// Load an NFT held by this contract and ensure it belongs to the staker
+pub fn load_staked_nft_of_staker(
+    storage: &dyn Storage,
+    staker_addr: &Addr,
+    token_id: &str,
+) -> Result<(u128, StakedNft), ContractError> {
+    let token_id_in_u128 = parse_token_id_from_string_to_u128(token_id)?;
+    match STAKED_NFTS().may_load(storage, token_id_in_u128)? {
+        Some(staked_nft) if staked_nft.staker_addr == staker_addr => {
+            Ok((token_id_in_u128, staked_nft))
+        }
+        _ => Err(ContractError::NftNotStakedBySender {
+            token_id: token_id.to_string(),
+            staker: staker_addr.to_string(),
+        }),
+    }
+}
+
+/// Transfer the NFT in CW404 collection from this contract back to the staker
+/// CW404 moves the FT backing the NFT along with it
+pub fn return_nft_msg(
+    collection_addr: &Addr,
+    staker_addr: &Addr,
+    token_id: &str,
+) -> StdResult<CosmosMsg> {
+    Ok(CosmosMsg::Wasm(WasmMsg::Execute {
+        contract_addr: collection_addr.to_string(),
+        msg: to_json_binary(&Cw721ExecuteMsg::TransferNft {
+            recipient: staker_addr.to_string(),
+            token_id: token_id.to_string(),
+        })?,
+        funds: vec![],
+    }))
+}
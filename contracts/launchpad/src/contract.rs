@@ -5,7 +5,24 @@ use crate::{
             update_coin_config, update_cw404_config, update_shared_config,
         },
         cw404::{create_cw404_collection, mint_ft_of_cw404},
+        fee::validate_fee_recipients,
+        launch_access::{
+            assert_can_launch, request_launch, review_launch_request,
+            update_launch_allowlist,
+        },
+        migration::migrate_token_contracts,
+        ownership::save_admin_addr,
+        pause::{assert_not_paused, pause, set_guardian, unpause},
+        referral::{
+            assert_valid_referral_share, claim_referral_rewards, collect_fee,
+        },
+        registry::set_verified,
+        template::{
+            add_template, apply_collection_template, apply_template,
+            update_template,
+        },
     },
+    migrate::v0_2_0::migrate_to_v0_2_0,
     query::{
         coin::{
             query_coin_by_contract_addr, query_coins,
@@ -16,24 +33,39 @@ use crate::{
             query_cw404_collection_by_contract_addr, query_cw404_collections,
             query_cw404_collections_by_creator_addr,
         },
+        launch_access::{query_launch_allowlist, query_launch_requests},
+        pause::query_pause_status,
+        referral::query_referral_earnings,
+        template::query_templates,
     },
     reply::{
         coin::reply_instantiate_coin_contract,
         cw404::reply_instantiate_cw404_contract,
     },
-    state::{CONFIG, FEE_DENOM},
+    state::{CONFIG, FEE_DENOM, GUARDIAN_ADDR},
 };
 use cosmwasm_std::{
     entry_point, to_json_binary, Binary, Deps, DepsMut, Env, MessageInfo,
-    Reply, Response, StdResult, Uint128,
+    Reply, Response, StdResult,
 };
 use cw2::set_contract_version;
-use cw_utils::{may_pay, must_pay, nonpayable};
+use cw_utils::{may_pay, maybe_addr, must_pay, nonpayable};
 use launchpad_pkg::{
     config::{CoinConfig, Config, Cw404Config},
+    launch_access::LaunchAccess,
     msg::{ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg},
+    pause::PauseAction,
+    token::TokenType,
+};
+use semver::Version;
+use shared_pkg::{
+    error::ContractError,
+    migration::assert_can_migrate,
+    ownership::{
+        accept_admin, cancel_proposal, propose_new_admin, query_ownership,
+        renounce_admin,
+    },
 };
-use shared_pkg::error::ContractError;
 
 pub const CONTRACT_NAME: &str = env!("CARGO_PKG_NAME");
 pub const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
@@ -49,6 +81,8 @@ pub fn instantiate(
     msg: InstantiateMsg,
 ) -> Result<Response, ContractError> {
     nonpayable(&info)?;
+    let referral_share_bps = msg.referral_share_bps.unwrap_or(0);
+    assert_valid_referral_share(referral_share_bps)?;
     set_contract_version(
         deps.storage,
         format!("crates.io:{CONTRACT_NAME}"),
@@ -57,25 +91,29 @@ pub fn instantiate(
     CONFIG.save(
         deps.storage,
         &Config {
-            admin_addr: deps.api.addr_validate(&msg.admin_addr)?,
+            admin_addr: Some(deps.api.addr_validate(&msg.admin_addr)?),
             astroport_factory_addr: deps
                 .api
                 .addr_validate(&msg.astroport_factory_addr)?,
             cw404_config: Cw404Config {
-                fee_collector: deps
-                    .api
-                    .addr_validate(&msg.cw404_fee_collector)?,
+                fee_recipients: validate_fee_recipients(
+                    deps.api,
+                    msg.cw404_fee_recipients,
+                )?,
                 cw404_code_id: msg.cw404_code_id,
                 collection_creation_fee: msg.cw404_collection_creation_fee,
                 mint_fee: msg.cw404_mint_fee,
             },
             coin_config: CoinConfig {
-                fee_collector: deps
-                    .api
-                    .addr_validate(&msg.coin_fee_collector)?,
+                fee_recipients: validate_fee_recipients(
+                    deps.api,
+                    msg.coin_fee_recipients,
+                )?,
                 coin_code_id: msg.coin_code_id,
                 coin_creation_fee: msg.coin_creation_fee,
             },
+            launch_access: msg.launch_access.unwrap_or(LaunchAccess::Open {}),
+            referral_share_bps,
         },
     )?;
     Ok(Response::new()
@@ -83,14 +121,12 @@ pub fn instantiate(
         .add_attribute("contract_addr", env.contract.address)
         .add_attribute("admin_addr", msg.admin_addr)
         .add_attribute("astroport_factory_addr", msg.astroport_factory_addr)
-        .add_attribute("cw404_fee_collector_addr", msg.cw404_fee_collector)
         .add_attribute("cw404_code_id", msg.cw404_code_id.to_string())
         .add_attribute(
             "cw404_collection_creation_fee",
             msg.cw404_collection_creation_fee.to_string(),
         )
         .add_attribute("cw404_mint_fee", msg.cw404_mint_fee.to_string())
-        .add_attribute("coin_fee_collector_addr", msg.coin_fee_collector)
         .add_attribute("coin_code_id", msg.coin_code_id.to_string())
         .add_attribute("coin_creation_fee", msg.coin_creation_fee.to_string()))
 }
@@ -107,11 +143,12 @@ pub fn execute(
     let config_ref = &CONFIG.load(deps.storage)?;
     match msg {
         ExecuteMsg::UpdateSharedConfig {
-            new_admin_addr,
             new_astroport_factory_addr,
+            new_launch_access,
+            new_referral_share_bps,
         } => {
             nonpayable(info_ref)?;
-            if sender_addr_ref != config_ref.admin_addr {
+            if config_ref.admin_addr.as_ref() != Some(sender_addr_ref) {
                 return Err(ContractError::OnlyAdminCanCallThisFunction {
                     function: "update_shared_config".to_string(),
                 });
@@ -119,18 +156,154 @@ pub fn execute(
             update_shared_config(
                 deps.api,
                 deps.storage,
-                new_admin_addr,
                 new_astroport_factory_addr,
+                new_launch_access,
+                new_referral_share_bps,
+            )
+        }
+        ExecuteMsg::ClaimReferralRewards {} => {
+            nonpayable(info_ref)?;
+            claim_referral_rewards(deps.storage, sender_addr_ref)
+        }
+        ExecuteMsg::UpdateLaunchAllowlist {
+            add_addrs,
+            remove_addrs,
+        } => {
+            nonpayable(info_ref)?;
+            if config_ref.admin_addr.as_ref() != Some(sender_addr_ref) {
+                return Err(ContractError::OnlyAdminCanCallThisFunction {
+                    function: "update_launch_allowlist".to_string(),
+                });
+            }
+            update_launch_allowlist(
+                deps.api,
+                deps.storage,
+                add_addrs,
+                remove_addrs,
+            )
+        }
+        ExecuteMsg::RequestLaunch { description } => {
+            nonpayable(info_ref)?;
+            request_launch(
+                deps.storage,
+                &env.block,
+                sender_addr_ref,
+                description,
+            )
+        }
+        ExecuteMsg::ReviewLaunchRequest {
+            creator_addr,
+            approve,
+        } => {
+            nonpayable(info_ref)?;
+            if config_ref.admin_addr.as_ref() != Some(sender_addr_ref) {
+                return Err(ContractError::OnlyAdminCanCallThisFunction {
+                    function: "review_launch_request".to_string(),
+                });
+            }
+            review_launch_request(
+                deps.storage,
+                &deps.api.addr_validate(&creator_addr)?,
+                approve,
+            )
+        }
+        ExecuteMsg::SetVerified {
+            contract_addr,
+            verified,
+        } => {
+            nonpayable(info_ref)?;
+            if config_ref.admin_addr.as_ref() != Some(sender_addr_ref) {
+                return Err(ContractError::OnlyAdminCanCallThisFunction {
+                    function: "set_verified".to_string(),
+                });
+            }
+            set_verified(
+                deps.storage,
+                deps.api.addr_validate(&contract_addr)?,
+                verified,
+            )
+        }
+        ExecuteMsg::ProposeNewAdmin {
+            new_admin_addr,
+            expiry,
+        } => {
+            nonpayable(info_ref)?;
+            if config_ref.admin_addr.as_ref() != Some(sender_addr_ref) {
+                return Err(ContractError::OnlyAdminCanCallThisFunction {
+                    function: "propose_new_admin".to_string(),
+                });
+            }
+            propose_new_admin(
+                deps.api,
+                deps.storage,
+                &env.block,
+                new_admin_addr,
+                expiry,
+            )
+        }
+        ExecuteMsg::AcceptAdmin {} => {
+            nonpayable(info_ref)?;
+            accept_admin(
+                deps.storage,
+                &env.block,
+                sender_addr_ref,
+                save_admin_addr,
             )
         }
+        ExecuteMsg::CancelProposal {} => {
+            nonpayable(info_ref)?;
+            if config_ref.admin_addr.as_ref() != Some(sender_addr_ref) {
+                return Err(ContractError::OnlyAdminCanCallThisFunction {
+                    function: "cancel_proposal".to_string(),
+                });
+            }
+            cancel_proposal(deps.storage)
+        }
+        ExecuteMsg::RenounceAdmin {} => {
+            nonpayable(info_ref)?;
+            if config_ref.admin_addr.as_ref() != Some(sender_addr_ref) {
+                return Err(ContractError::OnlyAdminCanCallThisFunction {
+                    function: "renounce_admin".to_string(),
+                });
+            }
+            renounce_admin(deps.storage, save_admin_addr)
+        }
+        ExecuteMsg::SetGuardian { guardian_addr } => {
+            nonpayable(info_ref)?;
+            if config_ref.admin_addr.as_ref() != Some(sender_addr_ref) {
+                return Err(ContractError::OnlyAdminCanCallThisFunction {
+                    function: "set_guardian".to_string(),
+                });
+            }
+            set_guardian(deps.api, deps.storage, guardian_addr)
+        }
+        ExecuteMsg::Pause { action } => {
+            nonpayable(info_ref)?;
+            if config_ref.admin_addr.as_ref() != Some(sender_addr_ref)
+                && GUARDIAN_ADDR.may_load(deps.storage)?.as_ref()
+                    != Some(sender_addr_ref)
+            {
+                return Err(ContractError::OnlyAdminOrGuardianCanPause {});
+            }
+            pause(deps.storage, action)
+        }
+        ExecuteMsg::Unpause { action } => {
+            nonpayable(info_ref)?;
+            if config_ref.admin_addr.as_ref() != Some(sender_addr_ref) {
+                return Err(ContractError::OnlyAdminCanCallThisFunction {
+                    function: "unpause".to_string(),
+                });
+            }
+            unpause(deps.storage, action)
+        }
         ExecuteMsg::UpdateCw404Config {
-            new_fee_collector_addr,
+            new_fee_recipients,
             new_cw404_code_id,
             new_collection_creation_fee,
             new_mint_fee,
         } => {
             nonpayable(info_ref)?;
-            if sender_addr_ref != config_ref.admin_addr {
+            if config_ref.admin_addr.as_ref() != Some(sender_addr_ref) {
                 return Err(ContractError::OnlyAdminCanCallThisFunction {
                     function: "update_cw404_config".to_string(),
                 });
@@ -138,19 +311,19 @@ pub fn execute(
             update_cw404_config(
                 deps.api,
                 deps.storage,
-                new_fee_collector_addr,
+                new_fee_recipients,
                 new_cw404_code_id,
                 new_collection_creation_fee,
                 new_mint_fee,
             )
         }
         ExecuteMsg::UpdateCoinConfig {
-            new_fee_collector_addr,
+            new_fee_recipients,
             new_coin_code_id,
             new_coin_creation_fee,
         } => {
             nonpayable(info_ref)?;
-            if sender_addr_ref != config_ref.admin_addr {
+            if config_ref.admin_addr.as_ref() != Some(sender_addr_ref) {
                 return Err(ContractError::OnlyAdminCanCallThisFunction {
                     function: "update_coin_config".to_string(),
                 });
@@ -158,46 +331,121 @@ pub fn execute(
             update_coin_config(
                 deps.api,
                 deps.storage,
-                new_fee_collector_addr,
+                new_fee_recipients,
                 new_coin_code_id,
                 new_coin_creation_fee,
             )
         }
-        ExecuteMsg::CreateCw404Collection {
-            royalty_payment_address,
-            royalty_percentage,
-            max_nft_supply,
-            subdenom,
-            denom_description,
-            denom_name,
-            denom_symbol,
-            denom_uri,
-            denom_uri_hash,
-            mint_groups,
+        ExecuteMsg::AddTemplate {
+            name,
+            version,
+            token_type,
+            code_id,
+            creation_fee,
+            mint_fee,
+        } => {
+            nonpayable(info_ref)?;
+            if config_ref.admin_addr.as_ref() != Some(sender_addr_ref) {
+                return Err(ContractError::OnlyAdminCanCallThisFunction {
+                    function: "add_template".to_string(),
+                });
+            }
+            add_template(
+                deps.storage,
+                deps.querier,
+                name,
+                version,
+                token_type,
+                code_id,
+                creation_fee,
+                mint_fee,
+            )
+        }
+        ExecuteMsg::UpdateTemplate {
+            name,
+            version,
+            new_creation_fee,
+            new_mint_fee,
+            enabled,
+        } => {
+            nonpayable(info_ref)?;
+            if config_ref.admin_addr.as_ref() != Some(sender_addr_ref) {
+                return Err(ContractError::OnlyAdminCanCallThisFunction {
+                    function: "update_template".to_string(),
+                });
+            }
+            update_template(
+                deps.storage,
+                name,
+                version,
+                new_creation_fee,
+                new_mint_fee,
+                enabled,
+            )
+        }
+        ExecuteMsg::MigrateTokenContracts {
+            code_id,
+            targets,
+            msg,
         } => {
+            nonpayable(info_ref)?;
+            if config_ref.admin_addr.as_ref() != Some(sender_addr_ref) {
+                return Err(ContractError::OnlyAdminCanCallThisFunction {
+                    function: "migrate_token_contracts".to_string(),
+                });
+            }
+            migrate_token_contracts(
+                deps.api,
+                deps.storage,
+                deps.querier,
+                &env.contract.address,
+                code_id,
+                targets,
+                msg,
+            )
+        }
+        ExecuteMsg::CreateCw404Collection(create_cw404_collection_msg) => {
+            let create_cw404_collection_msg = *create_cw404_collection_msg;
+            assert_not_paused(deps.storage, &PauseAction::Creation {})?;
+            assert_can_launch(
+                deps.storage,
+                &config_ref.launch_access,
+                sender_addr_ref,
+                create_cw404_collection_msg.launch_merkle_proof.clone(),
+            )?;
+            let config = apply_template(
+                deps.storage,
+                config_ref,
+                TokenType::Cw404Collection {},
+                create_cw404_collection_msg.template.clone(),
+            )?;
             let creator_paid_amount =
-                if config_ref.cw404_config.collection_creation_fee.is_zero() {
-                    nonpayable(info_ref)?;
-                    Uint128::zero()
+                if config.cw404_config.collection_creation_fee.is_zero() {
+                    // may still pay for seed liquidity
+                    may_pay(info_ref, FEE_DENOM)?
                 } else {
                     must_pay(info_ref, FEE_DENOM)?
                 };
-            create_cw404_collection(
-                config_ref,
+            let (fee_msgs, fee_attrs) = collect_fee(
+                deps.storage,
+                config.referral_share_bps,
+                &config.cw404_config.fee_recipients,
+                config.cw404_config.collection_creation_fee,
+                sender_addr_ref,
+                maybe_addr(
+                    deps.api,
+                    create_cw404_collection_msg.referrer.clone(),
+                )?,
+            )?;
+            Ok(create_cw404_collection(
+                &config,
                 env.contract.address,
                 sender_addr_ref.clone(),
                 creator_paid_amount,
-                royalty_payment_address,
-                royalty_percentage,
-                max_nft_supply,
-                subdenom,
-                denom_description,
-                denom_name,
-                denom_symbol,
-                denom_uri,
-                denom_uri_hash,
-                mint_groups,
-            )
+                create_cw404_collection_msg,
+            )?
+            .add_messages(fee_msgs)
+            .add_attributes(fee_attrs))
         }
         ExecuteMsg::MintFtOfCw404 {
             collection_addr,
@@ -205,57 +453,80 @@ pub fn execute(
             recipient,
             mint_group_name,
             merkle_proof,
+            referrer,
         } => {
-            let user_paid_amount = if config_ref.cw404_config.mint_fee.is_zero()
-            {
+            assert_not_paused(deps.storage, &PauseAction::Minting {})?;
+            let collection_addr = deps.api.addr_validate(&collection_addr)?;
+            let config = apply_collection_template(
+                deps.storage,
+                config_ref,
+                &collection_addr,
+            )?;
+            let user_paid_amount = if config.cw404_config.mint_fee.is_zero() {
                 // may still pay to creator
                 may_pay(info_ref, FEE_DENOM)?
             } else {
                 must_pay(info_ref, FEE_DENOM)?
             };
-            mint_ft_of_cw404(
-                config_ref,
-                deps.api.addr_validate(&collection_addr)?,
+            let (fee_msgs, fee_attrs) = collect_fee(
+                deps.storage,
+                config.referral_share_bps,
+                &config.cw404_config.fee_recipients,
+                config.cw404_config.mint_fee,
+                sender_addr_ref,
+                maybe_addr(deps.api, referrer)?,
+            )?;
+            Ok(mint_ft_of_cw404(
+                &config,
+                collection_addr,
                 deps.api.addr_validate(&recipient)?,
                 user_paid_amount,
                 amount,
                 mint_group_name,
                 merkle_proof,
-            )
+            )?
+            .add_messages(fee_msgs)
+            .add_attributes(fee_attrs))
         }
-        ExecuteMsg::CreateCoin {
-            subdenom,
-            denom_description,
-            denom_name,
-            denom_symbol,
-            denom_uri,
-            denom_uri_hash,
-            initial_supply_in_denom,
-            max_supply_in_denom,
-            immutable,
-        } => {
+        ExecuteMsg::CreateCoin(create_coin_msg) => {
+            let create_coin_msg = *create_coin_msg;
+            assert_not_paused(deps.storage, &PauseAction::Creation {})?;
+            assert_can_launch(
+                deps.storage,
+                &config_ref.launch_access,
+                sender_addr_ref,
+                create_coin_msg.launch_merkle_proof.clone(),
+            )?;
+            let config = apply_template(
+                deps.storage,
+                config_ref,
+                TokenType::Coin {},
+                create_coin_msg.template.clone(),
+            )?;
             let creator_paid_amount =
-                if config_ref.coin_config.coin_creation_fee.is_zero() {
+                if config.coin_config.coin_creation_fee.is_zero() {
                     // may still pay for seed liquidity
                     may_pay(info_ref, FEE_DENOM)?
                 } else {
                     must_pay(info_ref, FEE_DENOM)?
                 };
-            create_coin(
-                config_ref,
+            let (fee_msgs, fee_attrs) = collect_fee(
+                deps.storage,
+                config.referral_share_bps,
+                &config.coin_config.fee_recipients,
+                config.coin_config.coin_creation_fee,
+                sender_addr_ref,
+                maybe_addr(deps.api, create_coin_msg.referrer.clone())?,
+            )?;
+            Ok(create_coin(
+                &config,
                 env.contract.address,
                 sender_addr_ref.clone(),
                 creator_paid_amount,
-                immutable,
-                initial_supply_in_denom,
-                max_supply_in_denom,
-                subdenom,
-                denom_description,
-                denom_name,
-                denom_symbol,
-                denom_uri,
-                denom_uri_hash,
-            )
+                create_coin_msg,
+            )?
+            .add_messages(fee_msgs)
+            .add_attributes(fee_attrs))
         }
     }
 }
@@ -264,7 +535,34 @@ pub fn execute(
 pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
     match msg {
         QueryMsg::Config {} => to_json_binary(&query_config(deps.storage)?),
+        QueryMsg::Ownership {} => to_json_binary(&query_ownership(
+            deps.storage,
+            CONFIG.load(deps.storage)?.admin_addr,
+        )?),
         QueryMsg::Stats {} => to_json_binary(&query_stats(deps.storage)?),
+        QueryMsg::PauseStatus {} => {
+            to_json_binary(&query_pause_status(deps.storage)?)
+        }
+        QueryMsg::LaunchAllowlist { start_after, limit } => {
+            to_json_binary(&query_launch_allowlist(
+                deps.api,
+                deps.storage,
+                start_after,
+                limit,
+            )?)
+        }
+        QueryMsg::LaunchRequests { start_after, limit } => to_json_binary(
+            &query_launch_requests(deps.api, deps.storage, start_after, limit)?,
+        ),
+        QueryMsg::ReferralEarnings { referrer } => {
+            to_json_binary(&query_referral_earnings(
+                deps.storage,
+                &deps.api.addr_validate(&referrer)?,
+            )?)
+        }
+        QueryMsg::Templates { start_after, limit } => {
+            to_json_binary(&query_templates(deps.storage, start_after, limit)?)
+        }
         QueryMsg::Cw404CollectionByContract { contract_addr } => {
             to_json_binary(&query_cw404_collection_by_contract_addr(
                 deps.storage,
@@ -325,11 +623,251 @@ pub fn reply(
 
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn migrate(
-    _deps: DepsMut,
+    deps: DepsMut,
     _env: Env,
     msg: MigrateMsg,
 ) -> Result<Response, ContractError> {
     match msg {
-        MigrateMsg::FromCompatible {} => Ok(Response::default()),
+        MigrateMsg::FromCompatible {} => {
+            let contract_name = format!("crates.io:{CONTRACT_NAME}");
+            let stored_version = assert_can_migrate(
+                deps.storage,
+                &contract_name,
+                CONTRACT_VERSION,
+            )?;
+            if stored_version < Version::new(0, 2, 0) {
+                migrate_to_v0_2_0(deps.storage)?;
+            }
+            set_contract_version(
+                deps.storage,
+                contract_name,
+                CONTRACT_VERSION,
+            )?;
+            Ok(Response::new()
+                .add_attribute("action", "migrate")
+                .add_attribute("from_version", stored_version.to_string())
+                .add_attribute("to_version", CONTRACT_VERSION))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::{COINS, CW404_COLLECTIONS};
+    use cosmwasm_std::{
+        coins, from_json,
+        testing::{
+            mock_dependencies, mock_env, mock_info, MockApi, MockQuerier,
+            MockStorage,
+        },
+        Addr, Coin, Event, OwnedDeps, SubMsgResponse, SubMsgResult, Uint128,
+        Uint64,
+    };
+    use launchpad_pkg::{
+        fee::FeeRecipientConfig,
+        msg::{CreateCoinMsg, PauseStatusResponse},
+    };
+
+    fn setup() -> OwnedDeps<MockStorage, MockApi, MockQuerier> {
+        let mut deps = mock_dependencies();
+        let fee_recipients = vec![FeeRecipientConfig {
+            addr: "fee_recipient".to_string(),
+            weight_bps: 10_000,
+        }];
+        instantiate(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("admin", &[]),
+            InstantiateMsg {
+                admin_addr: "admin".to_string(),
+                astroport_factory_addr: "astroport_factory".to_string(),
+                cw404_fee_recipients: fee_recipients.clone(),
+                cw404_code_id: Uint64::new(1),
+                cw404_collection_creation_fee: Uint128::new(100),
+                cw404_mint_fee: Uint128::new(10),
+                coin_fee_recipients: fee_recipients,
+                coin_code_id: Uint64::new(2),
+                coin_creation_fee: Uint128::new(100),
+                launch_access: None,
+                referral_share_bps: None,
+            },
+        )
+        .unwrap();
+        deps
+    }
+
+    fn exec(
+        deps: &mut OwnedDeps<MockStorage, MockApi, MockQuerier>,
+        sender: &str,
+        funds: &[Coin],
+        msg: ExecuteMsg,
+    ) -> Result<Response, ContractError> {
+        execute(deps.as_mut(), mock_env(), mock_info(sender, funds), msg)
+    }
+
+    fn create_coin_msg() -> ExecuteMsg {
+        ExecuteMsg::CreateCoin(Box::new(CreateCoinMsg {
+            initial_supply_in_denom: Uint128::new(1_000),
+            max_supply_in_denom: Uint128::new(1_000),
+            immutable: false,
+            subdenom: "coin".to_string(),
+            denom_description: "coin".to_string(),
+            denom_name: "coin".to_string(),
+            denom_symbol: "COIN".to_string(),
+            denom_uri: "".to_string(),
+            denom_uri_hash: "".to_string(),
+            bonding_curve: None,
+            seed_liquidity_options: None,
+            vesting_allocations: None,
+            airdrop: None,
+            launch_protection_config: None,
+            template: None,
+            launch_merkle_proof: None,
+            referrer: None,
+        }))
+    }
+
+    fn create_coin(
+        deps: &mut OwnedDeps<MockStorage, MockApi, MockQuerier>,
+        sender: &str,
+    ) -> Result<Response, ContractError> {
+        exec(deps, sender, &coins(100, FEE_DENOM), create_coin_msg())
+    }
+
+    #[test]
+    fn created_coin_is_registered_as_coin() {
+        let mut deps = setup();
+        let resp = create_coin(&mut deps, "creator").unwrap();
+        let reply_id = resp.messages[0].id;
+        assert_eq!(reply_id, REPLY_ID_INSTANTIATE_COIN_CONTRACT);
+
+        reply(
+            deps.as_mut(),
+            mock_env(),
+            Reply {
+                id: reply_id,
+                result: SubMsgResult::Ok(SubMsgResponse {
+                    events: vec![Event::new("wasm")
+                        .add_attribute("action", "instantiate")
+                        .add_attribute("contract_addr", "coin_contract")
+                        .add_attribute("creator_addr", "creator")],
+                    data: None,
+                }),
+            },
+        )
+        .unwrap();
+        let coin_addr = Addr::unchecked("coin_contract");
+        assert_eq!(
+            COINS()
+                .load(deps.as_ref().storage, coin_addr.clone())
+                .unwrap()
+                .creator_addr,
+            Addr::unchecked("creator")
+        );
+        assert!(!CW404_COLLECTIONS().has(deps.as_ref().storage, coin_addr));
+    }
+
+    #[test]
+    fn guardian_pauses_and_only_admin_unpauses() {
+        let mut deps = setup();
+        let pause_msg = |action| ExecuteMsg::Pause { action };
+        assert!(matches!(
+            exec(
+                &mut deps,
+                "guardian",
+                &[],
+                pause_msg(PauseAction::Creation {})
+            )
+            .unwrap_err(),
+            ContractError::OnlyAdminOrGuardianCanPause {}
+        ));
+        assert!(matches!(
+            exec(
+                &mut deps,
+                "guardian",
+                &[],
+                ExecuteMsg::SetGuardian {
+                    guardian_addr: Some("guardian".to_string()),
+                },
+            )
+            .unwrap_err(),
+            ContractError::OnlyAdminCanCallThisFunction { .. }
+        ));
+        exec(
+            &mut deps,
+            "admin",
+            &[],
+            ExecuteMsg::SetGuardian {
+                guardian_addr: Some("guardian".to_string()),
+            },
+        )
+        .unwrap();
+        exec(
+            &mut deps,
+            "guardian",
+            &[],
+            pause_msg(PauseAction::Creation {}),
+        )
+        .unwrap();
+        exec(&mut deps, "admin", &[], pause_msg(PauseAction::Minting {}))
+            .unwrap();
+        let status: PauseStatusResponse = from_json(
+            query(deps.as_ref(), mock_env(), QueryMsg::PauseStatus {}).unwrap(),
+        )
+        .unwrap();
+        assert_eq!(
+            status,
+            PauseStatusResponse {
+                guardian_addr: Some(Addr::unchecked("guardian")),
+                creation_paused: true,
+                minting_paused: true,
+            }
+        );
+
+        assert!(matches!(
+            create_coin(&mut deps, "creator").unwrap_err(),
+            ContractError::Paused { action } if action == "creation"
+        ));
+        assert!(matches!(
+            exec(
+                &mut deps,
+                "alice",
+                &coins(10, FEE_DENOM),
+                ExecuteMsg::MintFtOfCw404 {
+                    collection_addr: "collection".to_string(),
+                    amount: Uint128::new(1),
+                    recipient: "alice".to_string(),
+                    mint_group_name: "public".to_string(),
+                    merkle_proof: None,
+                    referrer: None,
+                },
+            )
+            .unwrap_err(),
+            ContractError::Paused { action } if action == "minting"
+        ));
+
+        assert!(matches!(
+            exec(
+                &mut deps,
+                "guardian",
+                &[],
+                ExecuteMsg::Unpause {
+                    action: PauseAction::Creation {},
+                },
+            )
+            .unwrap_err(),
+            ContractError::OnlyAdminCanCallThisFunction { .. }
+        ));
+        exec(
+            &mut deps,
+            "admin",
+            &[],
+            ExecuteMsg::Unpause {
+                action: PauseAction::Creation {},
+            },
+        )
+        .unwrap();
+        create_coin(&mut deps, "creator").unwrap();
     }
 }
@@ -1,10 +1,18 @@
-use crate::{contract::REPLY_ID_INSTANTIATE_CW404_CONTRACT, state::FEE_DENOM};
-use coin::msg::{InstantiateMsg as CoinInstantiateMsg, SeedLiquidityConfig};
+use crate::{contract::REPLY_ID_INSTANTIATE_COIN_CONTRACT, state::FEE_DENOM};
+use coin::{
+    bonding_curve::BondingCurveConfig,
+    dex::{SeedLiquidityDex, SeedLiquidityPairType},
+    liquidity_lock::LiquidityLockMode,
+    msg::{InstantiateMsg as CoinInstantiateMsg, SeedLiquidityConfig},
+};
 use cosmwasm_std::{
-    coins, to_json_binary, Addr, BankMsg, CosmosMsg, ReplyOn, Response, SubMsg,
-    Uint128, WasmMsg,
+    coins, to_json_binary, Addr, CosmosMsg, ReplyOn, Response, SubMsg, Uint128,
+    WasmMsg,
+};
+use launchpad_pkg::{
+    config::Config,
+    msg::{CreateCoinMsg, SeedLiquidityDexOption, SeedLiquidityOptions},
 };
-use launchpad_pkg::config::Config;
 use shared_pkg::error::ContractError;
 
 pub fn create_coin(
@@ -12,16 +20,26 @@ pub fn create_coin(
     launchpad_addr: Addr,
     creator_addr: Addr,
     creator_paid_amount: Uint128,
-    immutable: bool,
-    initial_supply_in_denom: Uint128,
-    max_supply_in_denom: Uint128,
-    subdenom: String,
-    denom_description: String,
-    denom_name: String,
-    denom_symbol: String,
-    denom_uri: String,
-    denom_uri_hash: String,
+    msg: CreateCoinMsg,
 ) -> Result<Response, ContractError> {
+    // fields left out are handled by the caller
+    let CreateCoinMsg {
+        initial_supply_in_denom,
+        max_supply_in_denom,
+        immutable,
+        subdenom,
+        denom_description,
+        denom_name,
+        denom_symbol,
+        denom_uri,
+        denom_uri_hash,
+        bonding_curve,
+        seed_liquidity_options,
+        vesting_allocations,
+        airdrop,
+        launch_protection_config,
+        ..
+    } = msg;
     if creator_paid_amount < config.coin_config.coin_creation_fee {
         return Err(ContractError::InsufficientFundsToCreateCoin {
             paid: creator_paid_amount,
@@ -30,12 +48,34 @@ pub fn create_coin(
     }
     let seed_liquidity =
         creator_paid_amount - config.coin_config.coin_creation_fee;
-    let send_creation_fee_to_fee_collector_msg: BankMsg = BankMsg::Send {
-        to_address: config.coin_config.fee_collector.to_string(),
-        amount: coins(config.coin_config.coin_creation_fee.u128(), FEE_DENOM),
+    let seed_liquidity_options =
+        seed_liquidity_options.unwrap_or(SeedLiquidityOptions {
+            lock_mode: LiquidityLockMode::Creator {},
+            dex: SeedLiquidityDexOption::Astroport {
+                pair_type: SeedLiquidityPairType::Xyk {},
+            },
+            pool_supply_in_denom: None,
+        });
+    let seed_liquidity_dex = match seed_liquidity_options.dex {
+        SeedLiquidityDexOption::Astroport { pair_type } => {
+            SeedLiquidityDex::Astroport {
+                factory_addr: config.astroport_factory_addr.to_string(),
+                pair_type,
+            }
+        }
+        SeedLiquidityDexOption::OsmosisBalancer { swap_fee } => {
+            SeedLiquidityDex::OsmosisBalancer { swap_fee }
+        }
+        SeedLiquidityDexOption::OsmosisConcentrated {
+            tick_spacing,
+            spread_factor,
+        } => SeedLiquidityDex::OsmosisConcentrated {
+            tick_spacing,
+            spread_factor,
+        },
     };
     let instantiate_coin_submsg = SubMsg {
-        id: REPLY_ID_INSTANTIATE_CW404_CONTRACT,
+        id: REPLY_ID_INSTANTIATE_COIN_CONTRACT,
         msg: CosmosMsg::Wasm(WasmMsg::Instantiate {
             admin: if immutable {
                 None
@@ -56,13 +96,29 @@ pub fn create_coin(
                     None
                 } else {
                     Some(SeedLiquidityConfig {
-                        astroport_factory_addr: config
-                            .astroport_factory_addr
-                            .to_string(),
+                        dex: seed_liquidity_dex,
                         paired_base_denom: FEE_DENOM.to_string(),
                         paired_base_denom_amount: seed_liquidity,
+                        lock_mode: seed_liquidity_options.lock_mode,
+                        pool_supply_in_denom: seed_liquidity_options
+                            .pool_supply_in_denom,
                     })
                 },
+                // coin contract rejects bonding curve with seed liquidity
+                bonding_curve_config: bonding_curve.map(|params| {
+                    BondingCurveConfig {
+                        astroport_factory_addr: config
+                            .astroport_factory_addr
+                            .to_string(),
+                        paired_base_denom: FEE_DENOM.to_string(),
+                        params,
+                    }
+                }),
+                vesting_allocations,
+                airdrop,
+                // creator sets it after launch with SetTransferTax, only mutable coins have an admin
+                transfer_tax_config: None,
+                launch_protection_config,
                 subdenom,
                 denom_description,
                 denom_name,
@@ -83,7 +139,6 @@ pub fn create_coin(
     };
     Ok(Response::new()
         .add_submessage(instantiate_coin_submsg)
-        .add_message(send_creation_fee_to_fee_collector_msg)
         .add_attribute("action", "create_coin")
         .add_attribute("amount", creator_paid_amount))
 }
@@ -1,24 +1,35 @@
-use crate::state::CONFIG;
+use crate::{
+    execute::{
+        fee::validate_fee_recipients, referral::assert_valid_referral_share,
+    },
+    state::CONFIG,
+};
 use cosmwasm_std::{Api, Response, Storage, Uint128, Uint64};
+use launchpad_pkg::{fee::FeeRecipientConfig, launch_access::LaunchAccess};
 use shared_pkg::error::ContractError;
 
 pub fn update_shared_config(
     api: &dyn Api,
     storage: &mut dyn Storage,
-    new_admin: Option<String>,
     new_astroport_factory_addr: Option<String>,
+    new_launch_access: Option<LaunchAccess>,
+    new_referral_share_bps: Option<u64>,
 ) -> Result<Response, ContractError> {
     let mut config = CONFIG.load(storage)?;
-    config.admin_addr = match new_admin {
-        Some(admin) => api.addr_validate(&admin)?,
-        None => config.admin_addr,
-    };
     config.astroport_factory_addr = match new_astroport_factory_addr {
         Some(astroport_factory_addr) => {
             api.addr_validate(&astroport_factory_addr)?
         }
         None => config.astroport_factory_addr,
     };
+    config.launch_access = match new_launch_access {
+        Some(launch_access) => launch_access,
+        None => config.launch_access,
+    };
+    if let Some(referral_share_bps) = new_referral_share_bps {
+        assert_valid_referral_share(referral_share_bps)?;
+        config.referral_share_bps = referral_share_bps;
+    }
     CONFIG.save(storage, &config)?;
     Ok(Response::new().add_attribute("action", "update_shared_config"))
 }
@@ -26,16 +37,16 @@ pub fn update_shared_config(
 pub fn update_cw404_config(
     api: &dyn Api,
     storage: &mut dyn Storage,
-    new_fee_collector: Option<String>,
+    new_fee_recipients: Option<Vec<FeeRecipientConfig>>,
     new_cw404_code_id: Option<Uint64>,
     new_collection_creation_fee: Option<Uint128>,
     new_mint_fee: Option<Uint128>,
 ) -> Result<Response, ContractError> {
     let mut config = CONFIG.load(storage)?;
-    config.cw404_config.fee_collector = match new_fee_collector {
-        Some(fee_collector) => api.addr_validate(&fee_collector)?,
-        None => config.cw404_config.fee_collector,
-    };
+    if let Some(fee_recipients) = new_fee_recipients {
+        config.cw404_config.fee_recipients =
+            validate_fee_recipients(api, fee_recipients)?;
+    }
     config.cw404_config.cw404_code_id = match new_cw404_code_id {
         Some(cw404_code_id) => cw404_code_id,
         None => config.cw404_config.cw404_code_id,
@@ -56,15 +67,15 @@ pub fn update_cw404_config(
 pub fn update_coin_config(
     api: &dyn Api,
     storage: &mut dyn Storage,
-    new_fee_collector: Option<String>,
+    new_fee_recipients: Option<Vec<FeeRecipientConfig>>,
     new_coin_code_id: Option<Uint64>,
     new_coin_creation_fee: Option<Uint128>,
 ) -> Result<Response, ContractError> {
     let mut config = CONFIG.load(storage)?;
-    config.coin_config.fee_collector = match new_fee_collector {
-        Some(fee_collector) => api.addr_validate(&fee_collector)?,
-        None => config.coin_config.fee_collector,
-    };
+    if let Some(fee_recipients) = new_fee_recipients {
+        config.coin_config.fee_recipients =
+            validate_fee_recipients(api, fee_recipients)?;
+    }
     config.coin_config.coin_code_id = match new_coin_code_id {
         Some(coin_code_id) => coin_code_id,
         None => config.coin_config.coin_code_id,
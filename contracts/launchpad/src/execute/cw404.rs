@@ -1,15 +1,13 @@
 use crate::{contract::REPLY_ID_INSTANTIATE_CW404_CONTRACT, state::FEE_DENOM};
 use cosmwasm_std::{
-    coins, to_json_binary, Addr, BankMsg, CosmosMsg, ReplyOn, Response, SubMsg,
-    Uint128, Uint64, WasmMsg,
+    coins, to_json_binary, Addr, CosmosMsg, ReplyOn, Response, SubMsg, Uint128,
+    WasmMsg,
 };
-use cw404::{
-    mint_group::MintGroup,
-    msg::{
-        ExecuteMsg as Cw404ExecuteMsg, InstantiateMsg as Cw404InstantiateMsg,
-    },
+use cw404::msg::{
+    ExecuteMsg as Cw404ExecuteMsg, InstantiateMsg as Cw404InstantiateMsg,
+    SeedLiquidityConfig,
 };
-use launchpad_pkg::config::Config;
+use launchpad_pkg::{config::Config, msg::CreateCw404CollectionMsg};
 use shared_pkg::error::ContractError;
 
 pub fn create_cw404_collection(
@@ -17,29 +15,59 @@ pub fn create_cw404_collection(
     launchpad_addr: Addr,
     creator_addr: Addr,
     creator_paid_amount: Uint128,
-    royalty_payment_addr: String,
-    royalty_percentage: Uint64,
-    max_nft_supply: Uint128,
-    subdenom: String,
-    denom_description: String,
-    denom_name: String,
-    denom_symbol: String,
-    denom_uri: String,
-    denom_uri_hash: String,
-    mint_groups: Vec<MintGroup>,
+    msg: CreateCw404CollectionMsg,
 ) -> Result<Response, ContractError> {
-    if creator_paid_amount != config.cw404_config.collection_creation_fee {
-        return Err(ContractError::FundsMisMatchToCreateCw404Collection {
-            paid: creator_paid_amount,
-            required: config.cw404_config.collection_creation_fee,
-        });
-    }
-    let send_creation_fee_to_fee_collector_msg = BankMsg::Send {
-        to_address: config.cw404_config.fee_collector.to_string(),
-        amount: coins(
-            config.cw404_config.collection_creation_fee.u128(),
-            FEE_DENOM,
-        ),
+    // fields left out are handled by the caller
+    let CreateCw404CollectionMsg {
+        royalty_payment_address: royalty_payment_addr,
+        royalty_percentage,
+        max_nft_supply,
+        subdenom,
+        denom_description,
+        denom_name,
+        denom_symbol,
+        denom_uri,
+        denom_uri_hash,
+        mint_groups,
+        trait_metadata,
+        seed_liquidity_nft_supply,
+        airdrop,
+        ..
+    } = msg;
+    let creation_fee = config.cw404_config.collection_creation_fee;
+    // anything paid on top of creation fee is seed liquidity
+    let seed_liquidity_config = match seed_liquidity_nft_supply {
+        Some(nft_supply) => {
+            if creator_paid_amount <= creation_fee {
+                return Err(ContractError::InsufficientFundsToSeedLiquidity {
+                    paid: creator_paid_amount,
+                    creation_fee,
+                });
+            }
+            Some(SeedLiquidityConfig {
+                astroport_factory_addr: config
+                    .astroport_factory_addr
+                    .to_string(),
+                paired_base_denom: FEE_DENOM.to_string(),
+                paired_base_denom_amount: creator_paid_amount - creation_fee,
+                nft_supply,
+            })
+        }
+        None => {
+            if creator_paid_amount != creation_fee {
+                return Err(
+                    ContractError::FundsMisMatchToCreateCw404Collection {
+                        paid: creator_paid_amount,
+                        required: creation_fee,
+                    },
+                );
+            }
+            None
+        }
+    };
+    let instantiate_funds = match &seed_liquidity_config {
+        Some(cfg) => coins(cfg.paired_base_denom_amount.u128(), FEE_DENOM),
+        None => vec![],
     };
     let instantiate_cw404_collection_submsg = SubMsg {
         id: REPLY_ID_INSTANTIATE_CW404_CONTRACT,
@@ -64,9 +92,12 @@ pub fn create_cw404_collection(
                 royalty_payment_addr,
                 royalty_percentage,
                 mint_groups,
+                trait_metadata,
+                seed_liquidity_config,
+                airdrop,
             })
             .unwrap(),
-            funds: vec![],
+            funds: instantiate_funds,
             label: "CW404".to_string(),
         }),
         gas_limit: None,
@@ -74,7 +105,6 @@ pub fn create_cw404_collection(
     };
     Ok(Response::new()
         .add_submessage(instantiate_cw404_collection_submsg)
-        .add_message(send_creation_fee_to_fee_collector_msg)
         .add_attribute("action", "create_cw404_collection")
         .add_attribute("amount", creator_paid_amount))
 }
@@ -95,10 +125,6 @@ pub fn mint_ft_of_cw404(
             required: mint_fee,
         });
     }
-    let send_mint_fee_to_fee_collector_msg = BankMsg::Send {
-        to_address: config.cw404_config.fee_collector.to_string(),
-        amount: coins(mint_fee.u128(), FEE_DENOM),
-    };
     let mint_msg = WasmMsg::Execute {
         contract_addr: collection_addr.to_string(),
         msg: to_json_binary(&Cw404ExecuteMsg::MintFt {
@@ -111,7 +137,6 @@ pub fn mint_ft_of_cw404(
         funds: coins((user_paid_amount - mint_fee).u128(), FEE_DENOM),
     };
     Ok(Response::new()
-        .add_message(send_mint_fee_to_fee_collector_msg)
         .add_message(mint_msg)
         .add_attribute("action", "mint_ft_of_cw_404"))
 }
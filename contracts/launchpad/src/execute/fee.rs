@@ -0,0 +1,172 @@
+use crate::state::FEE_DENOM;
+use cosmwasm_std::{coins, Api, Attribute, BankMsg, Uint128};
+use launchpad_pkg::fee::{
+    FeeRecipient, FeeRecipientConfig, TOTAL_FEE_WEIGHT_BPS,
+};
+use shared_pkg::error::ContractError;
+
+pub fn validate_fee_recipients(
+    api: &dyn Api,
+    fee_recipients: Vec<FeeRecipientConfig>,
+) -> Result<Vec<FeeRecipient>, ContractError> {
+    if fee_recipients.is_empty() {
+        return Err(ContractError::InvalidFeeRecipients {
+            reason: "at least one fee recipient is required".to_string(),
+        });
+    }
+    let mut validated: Vec<FeeRecipient> = vec![];
+    let mut total_weight_bps: u64 = 0;
+    for fee_recipient in fee_recipients {
+        let addr = api.addr_validate(&fee_recipient.addr)?;
+        if fee_recipient.weight_bps == 0 {
+            return Err(ContractError::InvalidFeeRecipients {
+                reason: format!("weight of {addr} cannot be 0"),
+            });
+        }
+        if validated.iter().any(|existing| existing.addr == addr) {
+            return Err(ContractError::InvalidFeeRecipients {
+                reason: format!("duplicate fee recipient {addr}"),
+            });
+        }
+        total_weight_bps =
+            total_weight_bps.saturating_add(fee_recipient.weight_bps);
+        validated.push(FeeRecipient {
+            addr,
+            weight_bps: fee_recipient.weight_bps,
+        });
+    }
+    if total_weight_bps != TOTAL_FEE_WEIGHT_BPS {
+        return Err(ContractError::InvalidFeeRecipients {
+            reason: format!(
+                "weights must add up to {TOTAL_FEE_WEIGHT_BPS} bps, got {total_weight_bps}"
+            ),
+        });
+    }
+    Ok(validated)
+}
+
+/// Split fee by weight, rounding dust goes to the first recipient
+/// Returns fee messages and per recipient payment attributes
+pub fn split_fee(
+    fee_recipients: &[FeeRecipient],
+    fee: Uint128,
+) -> (Vec<BankMsg>, Vec<Attribute>) {
+    let mut shares: Vec<Uint128> = fee_recipients
+        .iter()
+        .map(|fee_recipient| {
+            fee.multiply_ratio(fee_recipient.weight_bps, TOTAL_FEE_WEIGHT_BPS)
+        })
+        .collect();
+    let dust = fee - shares.iter().sum::<Uint128>();
+    if let Some(first_share) = shares.first_mut() {
+        *first_share += dust;
+    }
+    let mut msgs = vec![];
+    let mut attrs = vec![];
+    for (fee_recipient, share) in fee_recipients.iter().zip(shares) {
+        // bank module rejects sending 0
+        if share.is_zero() {
+            continue;
+        }
+        msgs.push(BankMsg::Send {
+            to_address: fee_recipient.addr.to_string(),
+            amount: coins(share.u128(), FEE_DENOM),
+        });
+        attrs.push(Attribute::new(
+            format!("fee_paid_to_{}", fee_recipient.addr),
+            share,
+        ));
+    }
+    (msgs, attrs)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cosmwasm_std::{testing::MockApi, Addr};
+
+    fn recipient(addr: &str, weight_bps: u64) -> FeeRecipient {
+        FeeRecipient {
+            addr: Addr::unchecked(addr),
+            weight_bps,
+        }
+    }
+
+    fn config(addr: &str, weight_bps: u64) -> FeeRecipientConfig {
+        FeeRecipientConfig {
+            addr: addr.to_string(),
+            weight_bps,
+        }
+    }
+
+    fn send(addr: &str, amount: u128) -> BankMsg {
+        BankMsg::Send {
+            to_address: addr.to_string(),
+            amount: coins(amount, FEE_DENOM),
+        }
+    }
+
+    #[test]
+    fn split_fee_gives_dust_to_first_recipient() {
+        let fee_recipients = vec![
+            recipient("dev", 1_000),
+            recipient("treasury", 7_000),
+            recipient("buyback", 2_000),
+        ];
+        let (msgs, attrs) = split_fee(&fee_recipients, Uint128::new(1_009));
+        // 100.9, 706.3 and 201.8 round down, 2 dust goes to dev
+        assert_eq!(
+            msgs,
+            vec![
+                send("dev", 102),
+                send("treasury", 706),
+                send("buyback", 201)
+            ]
+        );
+        assert_eq!(
+            attrs,
+            vec![
+                Attribute::new("fee_paid_to_dev", "102"),
+                Attribute::new("fee_paid_to_treasury", "706"),
+                Attribute::new("fee_paid_to_buyback", "201"),
+            ]
+        );
+
+        // zero shares are not sent
+        let (msgs, _) = split_fee(&fee_recipients, Uint128::new(5));
+        assert_eq!(
+            msgs,
+            vec![send("dev", 1), send("treasury", 3), send("buyback", 1)]
+        );
+        let (msgs, _) = split_fee(&fee_recipients, Uint128::new(1));
+        assert_eq!(msgs, vec![send("dev", 1)]);
+        let (msgs, attrs) = split_fee(&fee_recipients, Uint128::zero());
+        assert!(msgs.is_empty() && attrs.is_empty());
+    }
+
+    #[test]
+    fn fee_recipients_must_be_unique_nonzero_and_add_up() {
+        let api = MockApi::default();
+        assert_eq!(
+            validate_fee_recipients(
+                &api,
+                vec![config("treasury", 7_000), config("dev", 3_000)]
+            )
+            .unwrap(),
+            vec![recipient("treasury", 7_000), recipient("dev", 3_000)]
+        );
+        for fee_recipients in [
+            vec![],
+            vec![config("treasury", 10_000), config("dev", 0)],
+            vec![config("treasury", 5_000), config("treasury", 5_000)],
+            vec![config("treasury", 7_000), config("dev", 2_000)],
+            vec![config("treasury", 7_000), config("dev", 4_000)],
+            vec![config("treasury", u64::MAX), config("dev", 1)],
+        ] {
+            assert!(matches!(
+                validate_fee_recipients(&api, fee_recipients).unwrap_err(),
+                ContractError::InvalidFeeRecipients { .. }
+            ));
+        }
+    }
+}
@@ -0,0 +1,252 @@
+use crate::state::{LAUNCH_ALLOWLIST, LAUNCH_REQUESTS};
+use cosmwasm_std::{Addr, Api, BlockInfo, Empty, Response, Storage};
+use launchpad_pkg::launch_access::{
+    LaunchAccess, LaunchRequest, LaunchRequestStatus,
+};
+use shared_pkg::{
+    error::ContractError,
+    merkle::{keccak256, verify_merkle_proof},
+};
+
+pub fn update_launch_allowlist(
+    api: &dyn Api,
+    storage: &mut dyn Storage,
+    add_addrs: Vec<String>,
+    remove_addrs: Vec<String>,
+) -> Result<Response, ContractError> {
+    for addr in &add_addrs {
+        LAUNCH_ALLOWLIST.save(storage, &api.addr_validate(addr)?, &Empty {})?;
+    }
+    for addr in &remove_addrs {
+        LAUNCH_ALLOWLIST.remove(storage, &api.addr_validate(addr)?);
+    }
+    Ok(Response::new()
+        .add_attribute("action", "update_launch_allowlist")
+        .add_attribute("added_count", add_addrs.len().to_string())
+        .add_attribute("removed_count", remove_addrs.len().to_string()))
+}
+
+pub fn request_launch(
+    storage: &mut dyn Storage,
+    block: &BlockInfo,
+    creator_addr: &Addr,
+    description: String,
+) -> Result<Response, ContractError> {
+    if let Some(launch_request) =
+        LAUNCH_REQUESTS.may_load(storage, creator_addr)?
+    {
+        if launch_request.status == (LaunchRequestStatus::Approved {}) {
+            return Err(ContractError::LaunchRequestAlreadyApproved {});
+        }
+    }
+    LAUNCH_REQUESTS.save(
+        storage,
+        creator_addr,
+        &LaunchRequest {
+            creator_addr: creator_addr.clone(),
+            description,
+            status: LaunchRequestStatus::Pending {},
+            requested_at: block.time,
+        },
+    )?;
+    Ok(Response::new()
+        .add_attribute("action", "request_launch")
+        .add_attribute("creator_addr", creator_addr))
+}
+
+pub fn review_launch_request(
+    storage: &mut dyn Storage,
+    creator_addr: &Addr,
+    approve: bool,
+) -> Result<Response, ContractError> {
+    let mut launch_request = match LAUNCH_REQUESTS
+        .may_load(storage, creator_addr)?
+    {
+        Some(launch_request)
+            if launch_request.status == (LaunchRequestStatus::Pending {}) =>
+        {
+            launch_request
+        }
+        _ => {
+            return Err(ContractError::NoPendingLaunchRequest {
+                creator_addr: creator_addr.to_string(),
+            })
+        }
+    };
+    if approve {
+        launch_request.status = LaunchRequestStatus::Approved {};
+        LAUNCH_REQUESTS.save(storage, creator_addr, &launch_request)?;
+    } else {
+        LAUNCH_REQUESTS.remove(storage, creator_addr);
+    }
+    Ok(Response::new()
+        .add_attribute("action", "review_launch_request")
+        .add_attribute("creator_addr", creator_addr)
+        .add_attribute("approved", approve.to_string()))
+}
+
+/// Error if creator cannot create under launch access, approved launch request is used up
+pub fn assert_can_launch(
+    storage: &mut dyn Storage,
+    launch_access: &LaunchAccess,
+    creator_addr: &Addr,
+    launch_merkle_proof: Option<Vec<Vec<u8>>>,
+) -> Result<(), ContractError> {
+    match launch_access {
+        LaunchAccess::Open {} => {}
+        LaunchAccess::Allowlist {} => {
+            if !LAUNCH_ALLOWLIST.has(storage, creator_addr) {
+                return Err(ContractError::CreatorNotInLaunchAllowlist {});
+            }
+        }
+        LaunchAccess::MerkleGated { merkle_root } => {
+            let merkle_proof = launch_merkle_proof
+                .ok_or(ContractError::LaunchMerkleProofRequired {})?;
+            if !verify_merkle_proof(
+                keccak256(creator_addr.as_bytes()),
+                merkle_proof,
+                merkle_root,
+            ) {
+                return Err(ContractError::InvalidLaunchMerkleProof {});
+            }
+        }
+        LaunchAccess::Application {} => {
+            match LAUNCH_REQUESTS.may_load(storage, creator_addr)? {
+                Some(launch_request)
+                    if launch_request.status
+                        == (LaunchRequestStatus::Approved {}) =>
+                {
+                    LAUNCH_REQUESTS.remove(storage, creator_addr);
+                }
+                _ => return Err(ContractError::LaunchRequestNotApproved {}),
+            }
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cosmwasm_std::testing::{mock_env, MockApi, MockStorage};
+
+    fn addr(name: &str) -> Addr {
+        Addr::unchecked(name)
+    }
+
+    fn can_launch(
+        storage: &mut dyn Storage,
+        launch_access: &LaunchAccess,
+        name: &str,
+        launch_merkle_proof: Option<Vec<Vec<u8>>>,
+    ) -> Result<(), ContractError> {
+        assert_can_launch(
+            storage,
+            launch_access,
+            &addr(name),
+            launch_merkle_proof,
+        )
+    }
+
+    #[test]
+    fn open_and_allowlist_access() {
+        let mut storage = MockStorage::new();
+        can_launch(&mut storage, &LaunchAccess::Open {}, "alice", None)
+            .unwrap();
+
+        let allowlist = LaunchAccess::Allowlist {};
+        assert!(matches!(
+            can_launch(&mut storage, &allowlist, "alice", None).unwrap_err(),
+            ContractError::CreatorNotInLaunchAllowlist {}
+        ));
+        update_launch_allowlist(
+            &MockApi::default(),
+            &mut storage,
+            vec!["alice".to_string(), "bob".to_string()],
+            vec![],
+        )
+        .unwrap();
+        can_launch(&mut storage, &allowlist, "alice", None).unwrap();
+        // allowlisted creator can launch again
+        can_launch(&mut storage, &allowlist, "alice", None).unwrap();
+        update_launch_allowlist(
+            &MockApi::default(),
+            &mut storage,
+            vec![],
+            vec!["alice".to_string()],
+        )
+        .unwrap();
+        assert!(can_launch(&mut storage, &allowlist, "alice", None).is_err());
+        can_launch(&mut storage, &allowlist, "bob", None).unwrap();
+    }
+
+    #[test]
+    fn merkle_gated_access() {
+        let mut storage = MockStorage::new();
+        let (alice, bob) = (keccak256(b"alice"), keccak256(b"bob"));
+        let merkle_root = if alice < bob {
+            keccak256(&[alice.clone(), bob.clone()].concat())
+        } else {
+            keccak256(&[bob.clone(), alice.clone()].concat())
+        };
+        let access = LaunchAccess::MerkleGated { merkle_root };
+        assert!(matches!(
+            can_launch(&mut storage, &access, "alice", None).unwrap_err(),
+            ContractError::LaunchMerkleProofRequired {}
+        ));
+        can_launch(&mut storage, &access, "alice", Some(vec![bob.clone()]))
+            .unwrap();
+        can_launch(&mut storage, &access, "bob", Some(vec![alice])).unwrap();
+        assert!(matches!(
+            can_launch(&mut storage, &access, "carol", Some(vec![bob]))
+                .unwrap_err(),
+            ContractError::InvalidLaunchMerkleProof {}
+        ));
+    }
+
+    #[test]
+    fn approved_application_is_used_up_by_launch() {
+        let mut storage = MockStorage::new();
+        let block = mock_env().block;
+        let access = LaunchAccess::Application {};
+        assert!(matches!(
+            can_launch(&mut storage, &access, "alice", None).unwrap_err(),
+            ContractError::LaunchRequestNotApproved {}
+        ));
+        assert!(matches!(
+            review_launch_request(&mut storage, &addr("alice"), true)
+                .unwrap_err(),
+            ContractError::NoPendingLaunchRequest { .. }
+        ));
+
+        request_launch(
+            &mut storage,
+            &block,
+            &addr("alice"),
+            "coin".to_string(),
+        )
+        .unwrap();
+        assert!(can_launch(&mut storage, &access, "alice", None).is_err());
+        review_launch_request(&mut storage, &addr("alice"), true).unwrap();
+        assert!(matches!(
+            request_launch(
+                &mut storage,
+                &block,
+                &addr("alice"),
+                "coin".to_string()
+            )
+            .unwrap_err(),
+            ContractError::LaunchRequestAlreadyApproved {}
+        ));
+        can_launch(&mut storage, &access, "alice", None).unwrap();
+        assert!(can_launch(&mut storage, &access, "alice", None).is_err());
+
+        // rejected request is removed, creator can apply again
+        request_launch(&mut storage, &block, &addr("bob"), "coin".to_string())
+            .unwrap();
+        review_launch_request(&mut storage, &addr("bob"), false).unwrap();
+        assert!(!LAUNCH_REQUESTS.has(&storage, &addr("bob")));
+        request_launch(&mut storage, &block, &addr("bob"), "coin".to_string())
+            .unwrap();
+    }
+}
@@ -0,0 +1,81 @@
+use crate::state::{COINS, CW404_COLLECTIONS, DEFAULT_LIMIT, MAX_LIMIT};
+use cosmwasm_std::{
+    Addr, Api, Binary, Order, QuerierWrapper, Response, StdResult, Storage,
+    Uint64, WasmMsg,
+};
+use cw_storage_plus::Bound;
+use launchpad_pkg::{msg::MigrationTargets, token::TokenType};
+use shared_pkg::error::ContractError;
+
+pub fn migrate_token_contracts(
+    api: &dyn Api,
+    storage: &dyn Storage,
+    querier: QuerierWrapper,
+    launchpad_addr: &Addr,
+    code_id: Uint64,
+    targets: MigrationTargets,
+    msg: Binary,
+) -> Result<Response, ContractError> {
+    let contract_addrs = match targets {
+        MigrationTargets::Contracts { contract_addrs } => contract_addrs
+            .iter()
+            .map(|contract_addr| {
+                let contract_addr = api.addr_validate(contract_addr)?;
+                if CW404_COLLECTIONS()
+                    .may_load(storage, contract_addr.clone())?
+                    .is_none()
+                    && COINS()
+                        .may_load(storage, contract_addr.clone())?
+                        .is_none()
+                {
+                    return Err(ContractError::TokenContractNotRegistered {
+                        contract_addr: contract_addr.to_string(),
+                    });
+                }
+                Ok(contract_addr)
+            })
+            .collect::<Result<Vec<Addr>, ContractError>>()?,
+        MigrationTargets::All {
+            token_type,
+            start_after,
+            limit,
+        } => {
+            let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+            let start = start_after.map(|s| Bound::ExclusiveRaw(s.into()));
+            let registry = match token_type {
+                TokenType::Cw404Collection {} => CW404_COLLECTIONS(),
+                TokenType::Coin {} => COINS(),
+            };
+            registry
+                .keys(storage, start, None, Order::Ascending)
+                .take(limit)
+                .collect::<StdResult<Vec<Addr>>>()?
+        }
+    };
+
+    let mut migrate_msgs = vec![];
+    let mut skipped_contract_addrs = vec![];
+    for contract_addr in contract_addrs {
+        // immutable tokens have no wasm admin, launchpad cannot migrate them
+        let contract_info =
+            querier.query_wasm_contract_info(contract_addr.as_str())?;
+        if contract_info.admin.as_deref() != Some(launchpad_addr.as_str()) {
+            skipped_contract_addrs.push(contract_addr.to_string());
+            continue;
+        }
+        migrate_msgs.push(WasmMsg::Migrate {
+            contract_addr: contract_addr.to_string(),
+            new_code_id: code_id.u64(),
+            msg: msg.clone(),
+        });
+    }
+    Ok(Response::new()
+        .add_attribute("action", "migrate_token_contracts")
+        .add_attribute("new_code_id", code_id.to_string())
+        .add_attribute("migrated_count", migrate_msgs.len().to_string())
+        .add_attribute(
+            "skipped_contract_addrs",
+            skipped_contract_addrs.join(","),
+        )
+        .add_messages(migrate_msgs))
+}
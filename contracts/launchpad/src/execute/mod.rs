@@ -1,3 +1,11 @@
 pub(crate) mod coin;
 pub(crate) mod config;
 pub(crate) mod cw404;
+pub(crate) mod fee;
+pub(crate) mod launch_access;
+pub(crate) mod migration;
+pub(crate) mod ownership;
+pub(crate) mod pause;
+pub(crate) mod referral;
+pub(crate) mod registry;
+pub(crate) mod template;
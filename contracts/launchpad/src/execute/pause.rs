@@ -0,0 +1,57 @@
+use crate::state::{GUARDIAN_ADDR, PAUSED_ACTIONS};
+use cosmwasm_std::{Api, Empty, Response, Storage};
+use launchpad_pkg::pause::PauseAction;
+use shared_pkg::error::ContractError;
+
+pub fn set_guardian(
+    api: &dyn Api,
+    storage: &mut dyn Storage,
+    guardian_addr: Option<String>,
+) -> Result<Response, ContractError> {
+    let guardian_addr = match guardian_addr {
+        Some(addr) => {
+            let addr = api.addr_validate(&addr)?;
+            GUARDIAN_ADDR.save(storage, &addr)?;
+            addr.to_string()
+        }
+        None => {
+            GUARDIAN_ADDR.remove(storage);
+            "None".to_string()
+        }
+    };
+    Ok(Response::new()
+        .add_attribute("action", "set_guardian")
+        .add_attribute("guardian_addr", guardian_addr))
+}
+
+pub fn pause(
+    storage: &mut dyn Storage,
+    action: PauseAction,
+) -> Result<Response, ContractError> {
+    PAUSED_ACTIONS.save(storage, action.as_str(), &Empty {})?;
+    Ok(Response::new()
+        .add_attribute("action", "pause")
+        .add_attribute("paused_action", action.as_str()))
+}
+
+pub fn unpause(
+    storage: &mut dyn Storage,
+    action: PauseAction,
+) -> Result<Response, ContractError> {
+    PAUSED_ACTIONS.remove(storage, action.as_str());
+    Ok(Response::new()
+        .add_attribute("action", "unpause")
+        .add_attribute("unpaused_action", action.as_str()))
+}
+
+pub fn assert_not_paused(
+    storage: &dyn Storage,
+    action: &PauseAction,
+) -> Result<(), ContractError> {
+    if PAUSED_ACTIONS.has(storage, action.as_str()) {
+        return Err(ContractError::Paused {
+            action: action.as_str().to_string(),
+        });
+    }
+    Ok(())
+}
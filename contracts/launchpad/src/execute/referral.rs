@@ -0,0 +1,203 @@
+use crate::{
+    execute::fee::split_fee,
+    state::{FEE_DENOM, REFERRAL_EARNINGS},
+};
+use cosmwasm_std::{
+    coins, Addr, Attribute, BankMsg, Response, Storage, Uint128,
+};
+use launchpad_pkg::{fee::FeeRecipient, referral::MAX_REFERRAL_SHARE_BPS};
+use shared_pkg::error::ContractError;
+
+pub const REFERRAL_BPS_DENOMINATOR: u64 = 10_000;
+
+pub fn assert_valid_referral_share(
+    referral_share_bps: u64,
+) -> Result<(), ContractError> {
+    if referral_share_bps > MAX_REFERRAL_SHARE_BPS {
+        return Err(ContractError::ReferralShareTooHigh {
+            max_bps: MAX_REFERRAL_SHARE_BPS,
+        });
+    }
+    Ok(())
+}
+
+/// Split fee across fee recipients, referral share of it stays in launchpad for referrer to claim
+/// Returns fee messages and payment attributes including referral amount
+pub fn collect_fee(
+    storage: &mut dyn Storage,
+    referral_share_bps: u64,
+    fee_recipients: &[FeeRecipient],
+    fee: Uint128,
+    payer_addr: &Addr,
+    referrer_addr: Option<Addr>,
+) -> Result<(Vec<BankMsg>, Vec<Attribute>), ContractError> {
+    let referral_amount = match referrer_addr {
+        Some(referrer_addr) => {
+            if referrer_addr == payer_addr {
+                return Err(ContractError::SelfReferral {});
+            }
+            let referral_amount = fee
+                .multiply_ratio(referral_share_bps, REFERRAL_BPS_DENOMINATOR);
+            if !referral_amount.is_zero() {
+                let mut earnings = REFERRAL_EARNINGS
+                    .may_load(storage, &referrer_addr)?
+                    .unwrap_or_default();
+                earnings.total_earned += referral_amount;
+                earnings.unclaimed += referral_amount;
+                REFERRAL_EARNINGS.save(storage, &referrer_addr, &earnings)?;
+            }
+            referral_amount
+        }
+        None => Uint128::zero(),
+    };
+    let (msgs, mut attrs) = split_fee(fee_recipients, fee - referral_amount);
+    attrs.push(Attribute::new("referral_amount", referral_amount));
+    Ok((msgs, attrs))
+}
+
+pub fn claim_referral_rewards(
+    storage: &mut dyn Storage,
+    referrer_addr: &Addr,
+) -> Result<Response, ContractError> {
+    let mut earnings = REFERRAL_EARNINGS
+        .may_load(storage, referrer_addr)?
+        .unwrap_or_default();
+    if earnings.unclaimed.is_zero() {
+        return Err(ContractError::NoReferralRewardsToClaim {});
+    }
+    let claim_amount = earnings.unclaimed;
+    earnings.unclaimed = Uint128::zero();
+    REFERRAL_EARNINGS.save(storage, referrer_addr, &earnings)?;
+    Ok(Response::new()
+        .add_message(BankMsg::Send {
+            to_address: referrer_addr.to_string(),
+            amount: coins(claim_amount.u128(), FEE_DENOM),
+        })
+        .add_attribute("action", "claim_referral_rewards")
+        .add_attribute("referrer_addr", referrer_addr)
+        .add_attribute("amount", claim_amount))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cosmwasm_std::testing::MockStorage;
+
+    fn addr(name: &str) -> Addr {
+        Addr::unchecked(name)
+    }
+
+    fn treasury() -> Vec<FeeRecipient> {
+        vec![FeeRecipient {
+            addr: addr("treasury"),
+            weight_bps: 10_000,
+        }]
+    }
+
+    fn earnings(storage: &dyn Storage, name: &str) -> (u128, u128) {
+        let earnings = REFERRAL_EARNINGS
+            .may_load(storage, &addr(name))
+            .unwrap()
+            .unwrap_or_default();
+        (earnings.total_earned.u128(), earnings.unclaimed.u128())
+    }
+
+    #[test]
+    fn referral_share_is_taken_from_fee_and_accrued() {
+        let mut storage = MockStorage::new();
+        let (msgs, attrs) = collect_fee(
+            &mut storage,
+            1_000,
+            &treasury(),
+            Uint128::new(1_005),
+            &addr("alice"),
+            Some(addr("kol")),
+        )
+        .unwrap();
+        // 10% rounds down, the rest goes to fee recipients
+        assert_eq!(
+            msgs,
+            vec![BankMsg::Send {
+                to_address: "treasury".to_string(),
+                amount: coins(905, FEE_DENOM),
+            }]
+        );
+        assert!(attrs.contains(&Attribute::new("referral_amount", "100")));
+        assert_eq!(earnings(&storage, "kol"), (100, 100));
+
+        // without referrer the whole fee goes to fee recipients
+        let (msgs, attrs) = collect_fee(
+            &mut storage,
+            1_000,
+            &treasury(),
+            Uint128::new(1_000),
+            &addr("alice"),
+            None,
+        )
+        .unwrap();
+        assert_eq!(
+            msgs,
+            vec![BankMsg::Send {
+                to_address: "treasury".to_string(),
+                amount: coins(1_000, FEE_DENOM),
+            }]
+        );
+        assert!(attrs.contains(&Attribute::new("referral_amount", "0")));
+
+        assert!(matches!(
+            collect_fee(
+                &mut storage,
+                1_000,
+                &treasury(),
+                Uint128::new(1_000),
+                &addr("alice"),
+                Some(addr("alice")),
+            )
+            .unwrap_err(),
+            ContractError::SelfReferral {}
+        ));
+    }
+
+    #[test]
+    fn claim_pays_unclaimed_and_keeps_total_earned() {
+        let mut storage = MockStorage::new();
+        assert!(matches!(
+            claim_referral_rewards(&mut storage, &addr("kol")).unwrap_err(),
+            ContractError::NoReferralRewardsToClaim {}
+        ));
+        for _ in 0..2 {
+            collect_fee(
+                &mut storage,
+                500,
+                &treasury(),
+                Uint128::new(1_000),
+                &addr("alice"),
+                Some(addr("kol")),
+            )
+            .unwrap();
+        }
+        assert_eq!(earnings(&storage, "kol"), (100, 100));
+        let res = claim_referral_rewards(&mut storage, &addr("kol")).unwrap();
+        assert_eq!(
+            res.messages[0].msg,
+            BankMsg::Send {
+                to_address: "kol".to_string(),
+                amount: coins(100, FEE_DENOM),
+            }
+            .into()
+        );
+        assert_eq!(earnings(&storage, "kol"), (100, 0));
+        assert!(claim_referral_rewards(&mut storage, &addr("kol")).is_err());
+    }
+
+    #[test]
+    fn referral_share_cannot_exceed_fee() {
+        assert_valid_referral_share(0).unwrap();
+        assert_valid_referral_share(MAX_REFERRAL_SHARE_BPS).unwrap();
+        assert!(matches!(
+            assert_valid_referral_share(MAX_REFERRAL_SHARE_BPS + 1)
+                .unwrap_err(),
+            ContractError::ReferralShareTooHigh { .. }
+        ));
+    }
+}
@@ -0,0 +1,67 @@
+use crate::state::{COINS, CW404_COLLECTIONS};
+use cosmwasm_std::{Addr, Response, Storage};
+use shared_pkg::error::ContractError;
+
+pub fn set_verified(
+    storage: &mut dyn Storage,
+    contract_addr: Addr,
+    verified: bool,
+) -> Result<Response, ContractError> {
+    let registry = if CW404_COLLECTIONS()
+        .may_load(storage, contract_addr.clone())?
+        .is_some()
+    {
+        CW404_COLLECTIONS()
+    } else if COINS().may_load(storage, contract_addr.clone())?.is_some() {
+        COINS()
+    } else {
+        return Err(ContractError::TokenContractNotRegistered {
+            contract_addr: contract_addr.to_string(),
+        });
+    };
+    let mut token_contract = registry.load(storage, contract_addr.clone())?;
+    token_contract.verified = verified;
+    registry.save(storage, contract_addr.clone(), &token_contract)?;
+    Ok(Response::new()
+        .add_attribute("action", "set_verified")
+        .add_attribute("contract_addr", contract_addr)
+        .add_attribute("verified", verified.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cosmwasm_std::testing::MockStorage;
+    use launchpad_pkg::token::TokenContract;
+
+    #[test]
+    fn verified_badge_toggles_on_registered_token() {
+        let mut storage = MockStorage::new();
+        let coin_addr = Addr::unchecked("coin");
+        COINS()
+            .save(
+                &mut storage,
+                coin_addr.clone(),
+                &TokenContract {
+                    creator_addr: Addr::unchecked("creator"),
+                    contract_addr: coin_addr.clone(),
+                    pair_addr: None,
+                    template: None,
+                    verified: false,
+                },
+            )
+            .unwrap();
+        let verified = |storage: &MockStorage| {
+            COINS().load(storage, coin_addr.clone()).unwrap().verified
+        };
+        set_verified(&mut storage, coin_addr.clone(), true).unwrap();
+        assert!(verified(&storage));
+        set_verified(&mut storage, coin_addr.clone(), false).unwrap();
+        assert!(!verified(&storage));
+        assert!(matches!(
+            set_verified(&mut storage, Addr::unchecked("unknown"), true)
+                .unwrap_err(),
+            ContractError::TokenContractNotRegistered { .. }
+        ));
+    }
+}
@@ -0,0 +1,140 @@
+use crate::state::{CW404_COLLECTIONS, PENDING_TEMPLATE, TEMPLATES};
+use cosmwasm_std::{Addr, QuerierWrapper, Response, Storage, Uint128, Uint64};
+use launchpad_pkg::{
+    config::Config,
+    template::{Template, TemplateId},
+    token::TokenType,
+};
+use shared_pkg::error::ContractError;
+
+pub fn add_template(
+    storage: &mut dyn Storage,
+    querier: QuerierWrapper,
+    name: String,
+    version: String,
+    token_type: TokenType,
+    code_id: Uint64,
+    creation_fee: Uint128,
+    mint_fee: Uint128,
+) -> Result<Response, ContractError> {
+    if TEMPLATES.has(storage, (name.as_str(), version.as_str())) {
+        return Err(ContractError::TemplateAlreadyExists { name, version });
+    }
+    let checksum = querier.query_wasm_code_info(code_id.u64())?.checksum;
+    TEMPLATES.save(
+        storage,
+        (name.as_str(), version.as_str()),
+        &Template {
+            name: name.clone(),
+            version: version.clone(),
+            token_type,
+            code_id,
+            checksum: checksum.clone(),
+            creation_fee,
+            mint_fee,
+            enabled: true,
+        },
+    )?;
+    Ok(Response::new()
+        .add_attribute("action", "add_template")
+        .add_attribute("name", name)
+        .add_attribute("version", version)
+        .add_attribute("code_id", code_id.to_string())
+        .add_attribute("checksum", checksum.to_hex()))
+}
+
+pub fn update_template(
+    storage: &mut dyn Storage,
+    name: String,
+    version: String,
+    new_creation_fee: Option<Uint128>,
+    new_mint_fee: Option<Uint128>,
+    enabled: Option<bool>,
+) -> Result<Response, ContractError> {
+    let mut template = TEMPLATES
+        .may_load(storage, (name.as_str(), version.as_str()))?
+        .ok_or(ContractError::TemplateNotFound {
+            name: name.clone(),
+            version: version.clone(),
+        })?;
+    template.creation_fee = new_creation_fee.unwrap_or(template.creation_fee);
+    template.mint_fee = new_mint_fee.unwrap_or(template.mint_fee);
+    template.enabled = enabled.unwrap_or(template.enabled);
+    TEMPLATES.save(storage, (name.as_str(), version.as_str()), &template)?;
+    Ok(Response::new()
+        .add_attribute("action", "update_template")
+        .add_attribute("name", name)
+        .add_attribute("version", version))
+}
+
+/// Config with code ID and creation fee of the selected template, config is returned as is without template
+/// Selected template is kept until the token is recorded in registry
+pub fn apply_template(
+    storage: &mut dyn Storage,
+    config: &Config,
+    token_type: TokenType,
+    template_id: Option<TemplateId>,
+) -> Result<Config, ContractError> {
+    let mut config = config.clone();
+    let template_id = match template_id {
+        Some(template_id) => template_id,
+        None => {
+            PENDING_TEMPLATE.remove(storage);
+            return Ok(config);
+        }
+    };
+    let template = TEMPLATES
+        .may_load(
+            storage,
+            (template_id.name.as_str(), template_id.version.as_str()),
+        )?
+        .ok_or(ContractError::TemplateNotFound {
+            name: template_id.name.clone(),
+            version: template_id.version.clone(),
+        })?;
+    if !template.enabled {
+        return Err(ContractError::TemplateDisabled {
+            name: template.name,
+            version: template.version,
+        });
+    }
+    if template.token_type != token_type {
+        return Err(ContractError::TemplateTokenTypeMismatch {
+            name: template.name,
+            version: template.version,
+        });
+    }
+    match token_type {
+        TokenType::Cw404Collection {} => {
+            config.cw404_config.cw404_code_id = template.code_id;
+            config.cw404_config.collection_creation_fee = template.creation_fee;
+        }
+        TokenType::Coin {} => {
+            config.coin_config.coin_code_id = template.code_id;
+            config.coin_config.coin_creation_fee = template.creation_fee;
+        }
+    }
+    PENDING_TEMPLATE.save(storage, &template_id)?;
+    Ok(config)
+}
+
+/// Config with mint fee of the template the collection is created with, even if the template is disabled later
+pub fn apply_collection_template(
+    storage: &dyn Storage,
+    config: &Config,
+    collection_addr: &Addr,
+) -> Result<Config, ContractError> {
+    let mut config = config.clone();
+    let template_id = CW404_COLLECTIONS()
+        .may_load(storage, collection_addr.clone())?
+        .and_then(|collection| collection.template);
+    if let Some(template_id) = template_id {
+        if let Some(template) = TEMPLATES.may_load(
+            storage,
+            (template_id.name.as_str(), template_id.version.as_str()),
+        )? {
+            config.cw404_config.mint_fee = template.mint_fee;
+        }
+    }
+    Ok(config)
+}
@@ -1,5 +1,6 @@
 pub mod contract;
 mod execute;
+mod migrate;
 mod query;
 mod reply;
 mod state;
@@ -0,0 +1,110 @@
+use crate::state::CONFIG;
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, Order, StdResult, Storage, Uint128, Uint64};
+use cw_storage_plus::{Item, Map};
+use launchpad_pkg::{
+    config::{CoinConfig, Config, Cw404Config},
+    fee::{FeeRecipient, TOTAL_FEE_WEIGHT_BPS},
+    launch_access::LaunchAccess,
+    token::TokenContract,
+};
+use shared_pkg::error::ContractError;
+
+/// CW404 config before fees could be split across recipients
+#[cw_serde]
+struct Cw404ConfigV0_1 {
+    fee_collector: Addr,
+    cw404_code_id: Uint64,
+    collection_creation_fee: Uint128,
+    mint_fee: Uint128,
+}
+
+/// Coin config before fees could be split across recipients
+#[cw_serde]
+struct CoinConfigV0_1 {
+    fee_collector: Addr,
+    coin_code_id: Uint64,
+    coin_creation_fee: Uint128,
+}
+
+/// Config before admin could be renounced, launch access and referral share
+#[cw_serde]
+struct ConfigV0_1 {
+    admin_addr: Addr,
+    astroport_factory_addr: Addr,
+    cw404_config: Cw404ConfigV0_1,
+    coin_config: CoinConfigV0_1,
+}
+
+/// Fee collector keeps the whole fee
+fn sole_fee_recipient(fee_collector: Addr) -> Vec<FeeRecipient> {
+    vec![FeeRecipient {
+        addr: fee_collector,
+        weight_bps: TOTAL_FEE_WEIGHT_BPS,
+    }]
+}
+
+/// Registry entry before pair, template and verified badge were recorded
+#[cw_serde]
+struct TokenContractV0_1 {
+    creator_addr: Addr,
+    contract_addr: Addr,
+}
+
+const CONFIG_V0_1: Item<ConfigV0_1> = Item::new("CONFIG");
+
+pub fn migrate_to_v0_2_0(
+    storage: &mut dyn Storage,
+) -> Result<(), ContractError> {
+    // launchpad stays open to anyone and keeps all fees as before
+    let config = CONFIG_V0_1.load(storage)?;
+    CONFIG.save(
+        storage,
+        &Config {
+            admin_addr: Some(config.admin_addr),
+            astroport_factory_addr: config.astroport_factory_addr,
+            cw404_config: Cw404Config {
+                fee_recipients: sole_fee_recipient(
+                    config.cw404_config.fee_collector,
+                ),
+                cw404_code_id: config.cw404_config.cw404_code_id,
+                collection_creation_fee: config
+                    .cw404_config
+                    .collection_creation_fee,
+                mint_fee: config.cw404_config.mint_fee,
+            },
+            coin_config: CoinConfig {
+                fee_recipients: sole_fee_recipient(
+                    config.coin_config.fee_collector,
+                ),
+                coin_code_id: config.coin_config.coin_code_id,
+                coin_creation_fee: config.coin_config.coin_creation_fee,
+            },
+            launch_access: LaunchAccess::Open {},
+            referral_share_bps: 0,
+        },
+    )?;
+
+    // primary key map of registry, creator index does not change
+    for namespace in ["CW404_COLLECTIONS", "COINS"] {
+        let legacy_registry: Map<Addr, TokenContractV0_1> = Map::new(namespace);
+        let registry: Map<Addr, TokenContract> = Map::new(namespace);
+        let token_contracts = legacy_registry
+            .range(storage, None, None, Order::Ascending)
+            .collect::<StdResult<Vec<_>>>()?;
+        for (contract_addr, token_contract) in token_contracts {
+            registry.save(
+                storage,
+                contract_addr,
+                &TokenContract {
+                    creator_addr: token_contract.creator_addr,
+                    contract_addr: token_contract.contract_addr,
+                    pair_addr: None,
+                    template: None,
+                    verified: false,
+                },
+            )?;
+        }
+    }
+    Ok(())
+}
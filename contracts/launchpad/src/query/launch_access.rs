@@ -0,0 +1,40 @@
+use crate::state::{
+    DEFAULT_LIMIT, LAUNCH_ALLOWLIST, LAUNCH_REQUESTS, MAX_LIMIT,
+};
+use cosmwasm_std::{Addr, Api, Order, StdResult, Storage};
+use cw_storage_plus::Bound;
+use cw_utils::maybe_addr;
+use launchpad_pkg::msg::{LaunchAllowlistResponse, LaunchRequestsResponse};
+
+pub fn query_launch_allowlist(
+    api: &dyn Api,
+    storage: &dyn Storage,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<LaunchAllowlistResponse> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let start_addr = maybe_addr(api, start_after)?;
+    let start = start_addr.as_ref().map(Bound::exclusive);
+    let addrs = LAUNCH_ALLOWLIST
+        .keys(storage, start, None, Order::Ascending)
+        .take(limit)
+        .collect::<StdResult<Vec<Addr>>>()?;
+    Ok(LaunchAllowlistResponse { addrs })
+}
+
+pub fn query_launch_requests(
+    api: &dyn Api,
+    storage: &dyn Storage,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<LaunchRequestsResponse> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let start_addr = maybe_addr(api, start_after)?;
+    let start = start_addr.as_ref().map(Bound::exclusive);
+    let launch_requests = LAUNCH_REQUESTS
+        .range(storage, start, None, Order::Ascending)
+        .take(limit)
+        .map(|item| item.map(|(_, launch_request)| launch_request))
+        .collect::<StdResult<Vec<_>>>()?;
+    Ok(LaunchRequestsResponse { launch_requests })
+}
@@ -1,3 +1,7 @@
 pub(crate) mod coin;
 pub(crate) mod config;
 pub(crate) mod cw404;
+pub(crate) mod launch_access;
+pub(crate) mod pause;
+pub(crate) mod referral;
+pub(crate) mod template;
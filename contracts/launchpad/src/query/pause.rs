@@ -0,0 +1,15 @@
+use crate::state::{GUARDIAN_ADDR, PAUSED_ACTIONS};
+use cosmwasm_std::{StdResult, Storage};
+use launchpad_pkg::{msg::PauseStatusResponse, pause::PauseAction};
+
+pub fn query_pause_status(
+    storage: &dyn Storage,
+) -> StdResult<PauseStatusResponse> {
+    Ok(PauseStatusResponse {
+        guardian_addr: GUARDIAN_ADDR.may_load(storage)?,
+        creation_paused: PAUSED_ACTIONS
+            .has(storage, PauseAction::Creation {}.as_str()),
+        minting_paused: PAUSED_ACTIONS
+            .has(storage, PauseAction::Minting {}.as_str()),
+    })
+}
@@ -0,0 +1,14 @@
+use crate::state::REFERRAL_EARNINGS;
+use cosmwasm_std::{Addr, StdResult, Storage};
+use launchpad_pkg::msg::ReferralEarningsResponse;
+
+pub fn query_referral_earnings(
+    storage: &dyn Storage,
+    referrer_addr: &Addr,
+) -> StdResult<ReferralEarningsResponse> {
+    Ok(ReferralEarningsResponse {
+        referral_earnings: REFERRAL_EARNINGS
+            .may_load(storage, referrer_addr)?
+            .unwrap_or_default(),
+    })
+}
@@ -0,0 +1,24 @@
+use crate::state::{DEFAULT_LIMIT, MAX_LIMIT, TEMPLATES};
+use cosmwasm_std::{Order, StdResult, Storage};
+use cw_storage_plus::Bound;
+use launchpad_pkg::{msg::TemplatesResponse, template::TemplateId};
+
+pub fn query_templates(
+    storage: &dyn Storage,
+    start_after: Option<TemplateId>,
+    limit: Option<u32>,
+) -> StdResult<TemplatesResponse> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let start = start_after.as_ref().map(|template_id| {
+        Bound::exclusive((
+            template_id.name.as_str(),
+            template_id.version.as_str(),
+        ))
+    });
+    let templates = TEMPLATES
+        .range(storage, start, None, Order::Ascending)
+        .take(limit)
+        .map(|item| item.map(|(_, template)| template))
+        .collect::<StdResult<Vec<_>>>()?;
+    Ok(TemplatesResponse { templates })
+}
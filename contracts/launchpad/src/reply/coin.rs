@@ -1,4 +1,4 @@
-use crate::state::COINS;
+use crate::state::{COINS, PENDING_TEMPLATE};
 use cosmwasm_std::{DepsMut, Reply, Response};
 use launchpad_pkg::token::TokenContract;
 use shared_pkg::error::ContractError;
@@ -36,11 +36,16 @@ pub fn reply_instantiate_coin_contract(
                     .ok_or(ContractError::ErrorGettingCreatorAddrFromInstantiateReplyOfCw404Contract{})?
                     .value,
             )?;
+    let template = PENDING_TEMPLATE.may_load(deps.storage)?;
+    PENDING_TEMPLATE.remove(deps.storage);
     COINS().update(deps.storage, contract_addr.clone(), |existing| {
         match existing {
             None => Ok(TokenContract {
                 creator_addr,
                 contract_addr: contract_addr.clone(),
+                pair_addr: None,
+                template,
+                verified: false,
             }),
             Some(_) => Err(ContractError::CoinAlreadyExists {
                 coin_addr: contract_addr.to_string(),
@@ -1,4 +1,4 @@
-use crate::state::CW404_COLLECTIONS;
+use crate::state::{CW404_COLLECTIONS, PENDING_TEMPLATE};
 use cosmwasm_std::{DepsMut, Reply, Response};
 use launchpad_pkg::token::TokenContract;
 use shared_pkg::error::ContractError;
@@ -36,6 +36,23 @@ pub fn reply_instantiate_cw404_contract(
                     .ok_or(ContractError::ErrorGettingCreatorAddrFromInstantiateReplyOfCw404Contract{})?
                     .value,
             )?;
+    // only exists if collection is created with seed liquidity
+    let pair_addr = reply
+        .events
+        .iter()
+        .find(|event| {
+            event.attributes.iter().any(|attr| {
+                attr.key == "action"
+                    && attr.value == "create_seed_liquidity_pair"
+            })
+        })
+        .and_then(|event| {
+            event.attributes.iter().find(|attr| attr.key == "pair_addr")
+        })
+        .map(|attr| deps.api.addr_validate(&attr.value))
+        .transpose()?;
+    let template = PENDING_TEMPLATE.may_load(deps.storage)?;
+    PENDING_TEMPLATE.remove(deps.storage);
     CW404_COLLECTIONS().update(
         deps.storage,
         contract_addr.clone(),
@@ -43,6 +60,9 @@ pub fn reply_instantiate_cw404_contract(
             None => Ok(TokenContract {
                 creator_addr,
                 contract_addr: contract_addr.clone(),
+                pair_addr,
+                template,
+                verified: false,
             }),
             Some(_) => Err(ContractError::CollectionAlreadyExists {
                 collection_addr: contract_addr.to_string(),
@@ -1,6 +1,9 @@
-use cosmwasm_std::Addr;
-use cw_storage_plus::{Index, IndexList, IndexedMap, Item, MultiIndex};
+use cosmwasm_std::{Addr, Empty};
+use cw_storage_plus::{Index, IndexList, IndexedMap, Item, Map, MultiIndex};
 use launchpad_pkg::config::{Config, Stats};
+use launchpad_pkg::launch_access::LaunchRequest;
+use launchpad_pkg::referral::ReferralEarnings;
+use launchpad_pkg::template::{Template, TemplateId};
 use launchpad_pkg::token::TokenContract;
 
 pub const DEFAULT_LIMIT: u32 = 10;
@@ -9,6 +12,26 @@ pub const MAX_LIMIT: u32 = 100;
 pub const CONFIG: Item<Config> = Item::new("CONFIG");
 pub const STATS: Item<Stats> = Item::new("STATS");
 
+/// Guardian can pause actions, only admin can unpause
+pub const GUARDIAN_ADDR: Item<Addr> = Item::new("GUARDIAN_ADDR");
+/// Key is paused action
+pub const PAUSED_ACTIONS: Map<&str, Empty> = Map::new("PAUSED_ACTIONS");
+
+/// Creators that can create when launch access is allowlist
+pub const LAUNCH_ALLOWLIST: Map<&Addr, Empty> = Map::new("LAUNCH_ALLOWLIST");
+/// Key is creator, approved request is removed once creator creates a token
+pub const LAUNCH_REQUESTS: Map<&Addr, LaunchRequest> =
+    Map::new("LAUNCH_REQUESTS");
+
+/// Key is referrer
+pub const REFERRAL_EARNINGS: Map<&Addr, ReferralEarnings> =
+    Map::new("REFERRAL_EARNINGS");
+
+/// Key is (name, version)
+pub const TEMPLATES: Map<(&str, &str), Template> = Map::new("TEMPLATES");
+/// Template selected by the token being created, recorded in registry in reply
+pub const PENDING_TEMPLATE: Item<TemplateId> = Item::new("PENDING_TEMPLATE");
+
 pub const FEE_DENOM: &str = "untrn";
 
 pub struct TokenContractIndexes<'a> {
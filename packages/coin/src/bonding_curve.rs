@@ -0,0 +1,68 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, Decimal, Uint128};
+
+#[cw_serde]
+pub enum BondingCurve {
+    /// price = initial_price + slope * supply_sold
+    /// price is in paired base denom per denom, supply_sold is in denom
+    Linear {
+        initial_price: Decimal,
+        slope: Decimal,
+    },
+    /// price = initial_price * (1 + growth_rate) ^ floor(supply_sold / step_size_in_denom)
+    /// i.e. price is flat within a step and grows by growth_rate every step
+    Exponential {
+        initial_price: Decimal,
+        growth_rate: Decimal,
+        step_size_in_denom: Uint128,
+    },
+}
+
+#[cw_serde]
+pub enum BondingCurveLpMode {
+    /// LP token stays in the coin contract forever, no one can withdraw it
+    Lock {},
+    /// LP token is burned right after liquidity is provided
+    Burn {},
+}
+
+/// Bonding curve settings chosen by the creator
+#[cw_serde]
+pub struct BondingCurveParams {
+    pub curve: BondingCurve,
+    /// Max supply sold on the curve in denom, must be less than max supply
+    pub curve_supply_in_denom: Uint128,
+    /// Migrate to Astroport once fully diluted market cap (spot price * max supply) reaches this, in paired base denom
+    pub migration_market_cap: Uint128,
+    pub lp_mode: BondingCurveLpMode,
+}
+
+#[cw_serde]
+pub struct BondingCurveConfig {
+    /// Astroport factory used to create the pair at migration
+    pub astroport_factory_addr: String,
+    /// Denom users pay to buy on the curve, e.g. untrn
+    pub paired_base_denom: String,
+    pub params: BondingCurveParams,
+}
+
+#[cw_serde]
+pub enum BondingCurvePhase {
+    /// Users buy and sell against the curve
+    Trading {},
+    /// Threshold reached, waiting for Astroport pair to be created and funded
+    Migrating {},
+    /// Reserve and remaining supply are in the Astroport pair, curve is closed
+    Migrated {},
+}
+
+#[cw_serde]
+pub struct BondingCurveState {
+    pub phase: BondingCurvePhase,
+    /// Supply sold on the curve and not sold back, in base denom
+    pub supply_sold_in_base_denom: Uint128,
+    /// Paired base denom held by the curve
+    pub reserve_in_paired_base_denom: Uint128,
+    /// Astroport pair created at migration
+    pub pair_addr: Option<Addr>,
+}
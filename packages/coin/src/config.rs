@@ -2,7 +2,7 @@ use cosmwasm_schema::cw_serde;
 use cosmwasm_std::{Addr, Uint128};
 use osmosis_std::types::cosmos::bank::v1beta1::Metadata as DenomMetadata;
 
-use crate::msg::SeedLiquidityConfig;
+use crate::{bonding_curve::BondingCurveConfig, msg::SeedLiquidityConfig};
 
 #[cw_serde]
 pub struct Config {
@@ -15,4 +15,6 @@ pub struct Config {
     pub max_supply_in_base_denom: Uint128,
     /// Seed liquidity config
     pub seed_liquidity_config: Option<SeedLiquidityConfig>,
+    /// If exists, coin is sold on a bonding curve before migrating to Astroport
+    pub bonding_curve_config: Option<BondingCurveConfig>,
 }
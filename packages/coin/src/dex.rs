@@ -0,0 +1,49 @@
+use astroport::pair_concentrated::ConcentratedPoolParams;
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, Decimal};
+
+#[cw_serde]
+pub enum SeedLiquidityPairType {
+    /// Constant product pool, initial price is paired amount / pool supply
+    Xyk {},
+    /// Stableswap pool, for coins pegged to the paired denom
+    Stable { amp: u64 },
+    /// Astroport PCL (concentrated liquidity) pool
+    /// Assets are ordered as [paired denom, coin], price_scale is the initial price of the coin in paired denom
+    Concentrated { params: ConcentratedPoolParams },
+}
+
+/// DEX seed liquidity pool is created on
+#[cw_serde]
+pub enum SeedLiquidityDex {
+    /// Pair created through Astroport factory, LP is a CW20 token
+    Astroport {
+        factory_addr: String,
+        pair_type: SeedLiquidityPairType,
+    },
+    /// Osmosis GAMM balancer pool with equal weights created through poolmanager, LP is a native gamm/pool/{pool_id} share
+    /// Poolmanager pool creation fee must be in paired denom, it is paid from the paired amount
+    OsmosisBalancer { swap_fee: Decimal },
+    /// Osmosis concentrated liquidity pool, seed liquidity is a full range position
+    /// Paired denom must be an authorized quote denom of poolmanager
+    /// Position cannot be transferred, so only Burn lock mode is supported and coin contract holds it forever
+    OsmosisConcentrated {
+        tick_spacing: u64,
+        spread_factor: Decimal,
+    },
+}
+
+/// LP received for seed liquidity
+#[cw_serde]
+pub enum SeedLiquidityLpToken {
+    Cw20 {
+        addr: Addr,
+    },
+    Native {
+        denom: String,
+    },
+    /// Osmosis concentrated liquidity position owned by coin contract
+    Position {
+        position_id: u64,
+    },
+}
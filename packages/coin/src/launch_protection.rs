@@ -0,0 +1,39 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Timestamp, Uint128};
+
+/// Limits enforced by before send hook right after launch
+/// Transfers from or to coin contract are never limited, e.g. seed liquidity, bonding curve and claims
+#[cw_serde]
+pub struct LaunchProtectionConfig {
+    /// in base denom, recipient balance after transfer cannot exceed it
+    pub max_wallet_balance: Option<Uint128>,
+    /// in base denom
+    pub max_tx_amount: Option<Uint128>,
+    /// blocks an address has to wait between transfers
+    pub cooldown_blocks: Option<u64>,
+    /// only transfers from or to coin contract can happen before it, None starts trading at instantiation
+    pub trading_start_time: Option<Timestamp>,
+    /// all limits lift this long after trading starts
+    pub duration_seconds: u64,
+    /// transfers from or to these are never limited, e.g. team wallets
+    pub exempt_addrs: Vec<String>,
+    /// pools are only limited by max transaction amount, their counterparties by all limits
+    /// Astroport pair of seed liquidity or bonding curve migration is added automatically
+    pub pool_addrs: Vec<String>,
+}
+
+#[cw_serde]
+pub struct LaunchProtection {
+    pub max_wallet_balance: Option<Uint128>,
+    pub max_tx_amount: Option<Uint128>,
+    pub cooldown_blocks: Option<u64>,
+    pub trading_start_time: Timestamp,
+    /// limits lift at this time
+    pub end_time: Timestamp,
+}
+
+#[cw_serde]
+pub enum LaunchProtectionAddrKind {
+    Exempt {},
+    Pool {},
+}
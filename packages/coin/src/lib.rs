@@ -1,2 +1,8 @@
+pub mod bonding_curve;
 pub mod config;
+pub mod dex;
+pub mod launch_protection;
+pub mod liquidity_lock;
 pub mod msg;
+pub mod transfer_tax;
+pub mod vesting;
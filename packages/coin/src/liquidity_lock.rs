@@ -0,0 +1,33 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Timestamp, Uint128};
+
+use crate::dex::SeedLiquidityLpToken;
+
+/// What happens to the LP token received for seed liquidity
+#[cw_serde]
+pub enum LiquidityLockMode {
+    /// LP token is sent to creator, creator can withdraw liquidity anytime
+    Creator {},
+    /// LP token is burned, liquidity can never be withdrawn
+    Burn {},
+    /// LP token is held by coin contract, creator can withdraw all of it after unlock_time
+    TimeLocked { unlock_time: Timestamp },
+    /// LP token is held by coin contract and vests linearly to creator from start_time to end_time
+    LinearVesting {
+        start_time: Timestamp,
+        end_time: Timestamp,
+    },
+}
+
+/// LP token received for seed liquidity, not tracked in Creator mode
+#[cw_serde]
+pub struct SeedLiquidityLp {
+    /// Pair address on Astroport, pool id on Osmosis
+    pub pool: String,
+    pub lp_token: SeedLiquidityLpToken,
+    /// LP received when seed liquidity was provided, burned amount in Burn mode
+    /// Always 0 for concentrated liquidity position
+    pub total_amount: Uint128,
+    /// LP withdrawn by creator so far
+    pub withdrawn_amount: Uint128,
+}
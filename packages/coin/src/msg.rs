@@ -1,17 +1,38 @@
-use crate::config::Config;
+use crate::{
+    bonding_curve::{BondingCurveConfig, BondingCurveState},
+    config::Config,
+    dex::SeedLiquidityDex,
+    launch_protection::{
+        LaunchProtection, LaunchProtectionAddrKind, LaunchProtectionConfig,
+    },
+    liquidity_lock::{LiquidityLockMode, SeedLiquidityLp},
+    transfer_tax::{TaxDestination, TransferTax, TransferTaxConfig},
+    vesting::{VestingAllocation, VestingSchedule},
+};
 use cosmwasm_schema::{cw_serde, QueryResponses};
-use cosmwasm_std::Uint128;
+use cosmwasm_std::{Addr, Coin, Decimal256, Uint128};
+use cw_utils::Expiration;
+use shared_pkg::{
+    airdrop::{AirdropClaimedResponse, AirdropConfig, AirdropResponse},
+    burn::{BurnLeaderboardResponse, BurnedAmountResponse, Burner},
+    ownership::OwnershipResponse,
+};
 
 // ========== instantiate ==========
 
 #[cw_serde]
 pub struct SeedLiquidityConfig {
-    // astroport factory address
-    pub astroport_factory_addr: String,
+    // DEX to create the pool on
+    pub dex: SeedLiquidityDex,
     // paired base denom, e.g. uatom
     pub paired_base_denom: String,
     // paired base denom amount, e.g. 1_000_000
     pub paired_base_denom_amount: Uint128,
+    // what happens to the LP token, lets users verify creator cannot pull the liquidity
+    pub lock_mode: LiquidityLockMode,
+    // supply deposited to the pool in denom, rest of initial supply goes to creator
+    // None deposits all initial supply
+    pub pool_supply_in_denom: Option<Uint128>,
 }
 
 #[cw_serde]
@@ -23,6 +44,19 @@ pub struct InstantiateMsg {
     // max_supply is in denom, e.g. atom, not base denom
     pub max_supply_in_denom: Uint128,
     pub seed_liquidity_config: Option<SeedLiquidityConfig>,
+    // if exists, initial supply must be 0 and seed liquidity must be None
+    // supply is minted on the bonding curve and migrated to Astroport later
+    pub bonding_curve_config: Option<BondingCurveConfig>,
+    // minted on top of initial supply and held by this contract until claimed
+    // initial supply plus allocations must not exceed max supply
+    pub vesting_allocations: Option<Vec<VestingAllocation>>,
+    // minted on top of initial supply and held by this contract until claimed with merkle proofs
+    // not supported on a bonding curve launch, admin can attach one after migration
+    pub airdrop: Option<AirdropConfig>,
+    // if exists, before send hook is registered and buys and sells through taxed addresses are taxed
+    pub transfer_tax_config: Option<TransferTaxConfig>,
+    // if exists, before send hook is registered and enforces anti-whale and anti-bot limits until they expire
+    pub launch_protection_config: Option<LaunchProtectionConfig>,
     // e.g. subdenom = atom, then base subdenom is uatom,
     // denom is factory/contract_addr/atom, base denom is factory/contract_addr/uatom
     // 1 atom = 1_000_000 uatom
@@ -38,9 +72,21 @@ pub struct InstantiateMsg {
 
 #[cw_serde]
 pub enum ExecuteMsg {
-    UpdateConfig {
-        new_admin_addr: Option<String>,
+    UpdateConfig {},
+    /// Propose new admin, admin changes only once new admin accepts
+    /// Only admin can execute this
+    ProposeNewAdmin {
+        new_admin_addr: String,
+        /// None never expires
+        expiry: Option<Expiration>,
     },
+    /// Only proposed admin can execute this before proposal expires
+    AcceptAdmin {},
+    /// Only admin can execute this
+    CancelProposal {},
+    /// Remove admin forever, account freezes and global pause stop applying
+    /// Only admin can execute this
+    RenounceAdmin {},
     /// Only admin can execute this
     Mint {
         /// amount is in base denom, e.g. uatom
@@ -48,12 +94,23 @@ pub enum ExecuteMsg {
         /// recipient address
         recipient: String,
     },
+    /// Burn coin held by this contract
     /// Only admin can execute this
     Burn {
         /// amount is in base denom
         amount: Uint128,
     },
-    /// Force transfer from one account to another
+    /// Burn coin straight from a holder
+    /// Only admin can execute this
+    BurnFrom {
+        from: String,
+        /// amount is in base denom
+        amount: Uint128,
+    },
+    /// Burn coin sent in funds, sender climbs the burn leaderboard
+    /// Anyone can execute this
+    SelfBurn {},
+    /// Force transfer from one account to another, goes through frozen accounts and global pause
     /// Only admin can execute this
     ForceTransfer {
         /// amount is in base denom
@@ -61,6 +118,86 @@ pub enum ExecuteMsg {
         from: String,
         to: String,
     },
+    /// Withdraw LP token of seed liquidity that is unlocked or vested
+    /// Only creator can execute this
+    WithdrawLockedLp {},
+    /// Buy coin on the bonding curve with paired denom in funds
+    /// Unused funds are refunded
+    BuyOnBondingCurve {
+        /// in base denom, error if less coin would be bought
+        min_amount_out: Option<Uint128>,
+    },
+    /// Sell coin in funds back to the bonding curve
+    SellOnBondingCurve {
+        /// in paired base denom, error if less would be received
+        min_paired_amount_out: Option<Uint128>,
+    },
+    /// Claim coin vested so far from sender's vesting allocation
+    ClaimVested {},
+    /// Mint airdrop supply to this contract, previous airdrop must be closed
+    /// Only admin can execute this
+    CreateAirdrop {
+        airdrop: AirdropConfig,
+    },
+    /// Claim sender's airdrop leaf
+    ClaimAirdrop {
+        /// leaf index in the merkle tree
+        index: u32,
+        /// in base denom
+        amount: Uint128,
+        merkle_proof: Vec<Vec<u8>>,
+    },
+    /// Return unclaimed airdrop to unclaimed recipient or burn it after expiration
+    /// Anyone can execute this
+    CloseAirdrop {},
+    /// Set tax rates and destination, registers before send hook the first time
+    /// Only admin can execute this before transfer tax is renounced
+    SetTransferTax {
+        /// in basis points, capped at MAX_TRANSFER_TAX_BPS
+        buy_tax_bps: u64,
+        /// in basis points, capped at MAX_TRANSFER_TAX_BPS
+        sell_tax_bps: u64,
+        destination: TaxDestination,
+    },
+    /// Only admin can execute this before transfer tax is renounced
+    SetTaxedAddr {
+        addr: String,
+        taxed: bool,
+    },
+    /// Only admin can execute this before transfer tax is renounced
+    SetTaxExemptAddr {
+        addr: String,
+        exempt: bool,
+    },
+    /// Lock transfer tax as it is forever
+    /// Only admin can execute this
+    RenounceTransferTax {},
+    /// Force transfer tax owed by addrs to tax destination, capped at their balance
+    /// Tax the balance cannot cover is written off as uncollectable
+    /// Anyone can execute this
+    SweepTax {
+        addrs: Vec<String>,
+    },
+    /// Exempt an address from launch protection or mark it as a pool, None removes it
+    /// Only admin can execute this while launch protection is active
+    SetLaunchProtectionAddr {
+        addr: String,
+        kind: Option<LaunchProtectionAddrKind>,
+    },
+    /// Block all sends from or to addr, except force transfer by admin
+    /// Only admin can execute this, immutable coins have no admin
+    FreezeAccount {
+        addr: String,
+    },
+    /// Only admin can execute this
+    UnfreezeAccount {
+        addr: String,
+    },
+    /// Block all sends except into this contract, e.g. force transfer by admin
+    /// Only admin can execute this
+    SetGlobalPause {
+        paused: bool,
+    },
 }
 
 // ========== query ==========
@@ -74,6 +211,8 @@ pub struct ConfigResponse {
 pub struct SupplyResponse {
     pub current_supply_in_base_denom: Uint128,
     pub max_supply_in_base_denom: Uint128,
+    /// burned by this contract so far, coin sold back to the bonding curve is not counted
+    pub total_burned_in_base_denom: Uint128,
 }
 
 #[cw_serde]
@@ -82,18 +221,171 @@ pub struct BalanceResponse {
     pub balance_in_base_denom: Uint128,
 }
 
+#[cw_serde]
+pub struct LiquidityLockResponse {
+    pub lock_mode: LiquidityLockMode,
+    /// None before seed liquidity is provided or in Creator mode
+    pub seed_liquidity_lp: Option<SeedLiquidityLp>,
+    /// LP creator can withdraw now
+    pub withdrawable_amount: Uint128,
+}
+
+#[cw_serde]
+pub struct BondingCurveResponse {
+    pub bonding_curve_config: BondingCurveConfig,
+    pub bonding_curve_state: BondingCurveState,
+    /// Paired base denom per denom
+    pub spot_price: Decimal256,
+    /// Fully diluted market cap in paired base denom
+    pub market_cap: Uint128,
+}
+
+#[cw_serde]
+pub struct SimulateBuyOnBondingCurveResponse {
+    /// in base denom
+    pub amount_out: Uint128,
+    /// paired base denom actually spent, the rest is refunded
+    pub paired_amount_spent: Uint128,
+}
+
+#[cw_serde]
+pub struct SimulateSellOnBondingCurveResponse {
+    pub paired_amount_out: Uint128,
+}
+
+#[cw_serde]
+pub struct VestingScheduleResponse {
+    pub vesting_schedule: VestingSchedule,
+    /// in base denom, recipient can claim now
+    pub claimable_amount: Uint128,
+}
+
+#[cw_serde]
+pub struct TransferTaxResponse {
+    pub transfer_tax: TransferTax,
+    pub taxed_addrs: Vec<Addr>,
+    pub exempt_addrs: Vec<Addr>,
+    /// tax written off by SweepTax because the payer's balance could not cover it, in base denom
+    pub uncollectable_tax: Uint128,
+}
+
+#[cw_serde]
+pub struct TaxOwedResponse {
+    /// in base denom
+    pub amount: Uint128,
+}
+
+#[cw_serde]
+pub struct LaunchProtectionAddr {
+    pub addr: Addr,
+    pub kind: LaunchProtectionAddrKind,
+}
+
+#[cw_serde]
+pub struct LaunchProtectionResponse {
+    pub launch_protection: LaunchProtection,
+    /// false before trading start time
+    pub trading_started: bool,
+    /// false once limits expire
+    pub active: bool,
+    pub addrs: Vec<LaunchProtectionAddr>,
+}
+
+#[cw_serde]
+pub struct IsFrozenResponse {
+    pub is_frozen: bool,
+    /// a globally paused coin cannot be sent by anyone
+    pub globally_paused: bool,
+}
+
+#[cw_serde]
+pub struct FrozenAccountsResponse {
+    pub addrs: Vec<Addr>,
+    pub globally_paused: bool,
+}
+
 #[derive(QueryResponses)]
 #[cw_serde]
 pub enum QueryMsg {
     // ========== general functions ==========
     #[returns(ConfigResponse)]
     Config {},
+    #[returns(OwnershipResponse)]
+    Ownership {},
     #[returns(SupplyResponse)]
     Supply {},
     #[returns(BalanceResponse)]
     Balance { owner: String },
+    /// Cumulative self burn of addr
+    #[returns(BurnedAmountResponse)]
+    BurnedAmount { addr: String },
+    #[returns(BurnLeaderboardResponse)]
+    BurnLeaderboard {
+        start_after: Option<Burner>,
+        limit: Option<u32>,
+    },
+    /// Error if coin is launched without seed liquidity
+    #[returns(LiquidityLockResponse)]
+    LiquidityLock {},
+    // ========== bonding curve functions ==========
+    /// Error if coin is not launched on a bonding curve
+    #[returns(BondingCurveResponse)]
+    BondingCurve {},
+    #[returns(SimulateBuyOnBondingCurveResponse)]
+    SimulateBuyOnBondingCurve { paired_amount: Uint128 },
+    #[returns(SimulateSellOnBondingCurveResponse)]
+    SimulateSellOnBondingCurve { amount: Uint128 },
+    // ========== vesting functions ==========
+    /// Error if addr has no vesting allocation
+    #[returns(VestingScheduleResponse)]
+    VestingSchedule { addr: String },
+    // ========== airdrop functions ==========
+    #[returns(AirdropResponse)]
+    Airdrop {},
+    /// Whether leaf index of the latest airdrop is claimed
+    #[returns(AirdropClaimedResponse)]
+    AirdropClaimed { index: u32 },
+    // ========== transfer tax functions ==========
+    /// Error if transfer tax is not set
+    #[returns(TransferTaxResponse)]
+    TransferTax {},
+    #[returns(TaxOwedResponse)]
+    TaxOwed { addr: String },
+    // ========== launch protection functions ==========
+    /// Error if coin is launched without launch protection
+    #[returns(LaunchProtectionResponse)]
+    LaunchProtection {},
+    // ========== freeze functions ==========
+    /// Always false for immutable coins and once admin is renounced, freezes and pause lift with the admin
+    #[returns(IsFrozenResponse)]
+    IsFrozen { addr: String },
+    #[returns(FrozenAccountsResponse)]
+    FrozenAccounts {
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
 }
 
 // ========== migrate ==========
 #[cw_serde]
-pub enum MigrateMsg {}
+pub struct MigrateMsg {}
+
+// ========== sudo ==========
+#[cw_serde]
+pub enum SudoMsg {
+    /// Token factory calls this on every send after BlockBeforeSend once the hook is registered
+    /// Records tax owed, errors are ignored by token factory
+    TrackBeforeSend {
+        from: String,
+        to: String,
+        amount: Coin,
+    },
+    /// Token factory calls this on every send too once the hook is registered
+    /// Erroring blocks the send
+    /// e.g. frozen account, launch protection limit or balance left above 0 but below tax owed
+    BlockBeforeSend {
+        from: String,
+        to: String,
+        amount: Coin,
+    },
+}
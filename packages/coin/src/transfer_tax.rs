@@ -0,0 +1,34 @@
+use cosmwasm_schema::cw_serde;
+
+/// 10%, buy and sell tax can never be set higher
+pub const MAX_TRANSFER_TAX_BPS: u64 = 1_000;
+
+/// Where swept tax goes
+#[cw_serde]
+pub enum TaxDestination {
+    Treasury { addr: String },
+    Burn {},
+}
+
+/// Tax is only charged on transfers touching a taxed address, e.g. a DEX pair
+#[cw_serde]
+pub struct TransferTaxConfig {
+    /// charged to recipient when coin moves out of a taxed address, in basis points
+    pub buy_tax_bps: u64,
+    /// charged to sender when coin moves into a taxed address, in basis points
+    pub sell_tax_bps: u64,
+    pub destination: TaxDestination,
+    /// Astroport pair of seed liquidity or bonding curve migration is added automatically
+    pub taxed_addrs: Vec<String>,
+    /// transfers from or to these are never taxed, coin contract and treasury are always exempt
+    pub exempt_addrs: Vec<String>,
+}
+
+#[cw_serde]
+pub struct TransferTax {
+    pub buy_tax_bps: u64,
+    pub sell_tax_bps: u64,
+    pub destination: TaxDestination,
+    /// once renounced, rates, destination and address lists can never change
+    pub renounced: bool,
+}
@@ -0,0 +1,27 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, Timestamp, Uint128};
+
+/// Coin reserved at instantiate for a recipient, e.g. team allocation
+/// Held by coin contract and vests linearly from instantiate time
+#[cw_serde]
+pub struct VestingAllocation {
+    pub recipient: String,
+    /// amount is in denom, e.g. atom, not base denom
+    pub amount_in_denom: Uint128,
+    /// nothing can be claimed before cliff, vested amount at cliff becomes claimable at once
+    pub cliff_seconds: u64,
+    /// must be greater than 0 and not less than cliff
+    pub duration_seconds: u64,
+}
+
+#[cw_serde]
+pub struct VestingSchedule {
+    pub recipient: Addr,
+    /// in base denom
+    pub total_amount: Uint128,
+    /// in base denom
+    pub claimed_amount: Uint128,
+    pub start_time: Timestamp,
+    pub cliff_time: Timestamp,
+    pub end_time: Timestamp,
+}
@@ -0,0 +1,27 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, Timestamp, Uint128};
+
+#[cw_serde]
+pub struct Config {
+    /// If exists, admin can update reward rate and unstake cooldown
+    pub admin_addr: Option<Addr>,
+    /// CW404 collection whose NFTs can be staked
+    pub collection_addr: Addr,
+    /// Rewards are paid from this contract's balance in this denom, e.g. untrn
+    pub reward_denom: String,
+    /// Reward amount in reward denom paid per staked NFT per second
+    pub reward_per_nft_per_second: Uint128,
+    /// Seconds an unstaked NFT stays in this contract before it can be withdrawn
+    /// 0 means the NFT is returned right away
+    pub unstake_cooldown_seconds: u64,
+}
+
+#[cw_serde]
+pub struct RewardState {
+    /// Total reward earned by 1 NFT staked since instantiation
+    pub reward_per_nft: Uint128,
+    /// Last time reward_per_nft was updated
+    pub last_updated_time: Timestamp,
+    /// Number of NFTs currently staked, excluding NFTs in unstake cooldown
+    pub total_staked_nfts: u64,
+}
@@ -0,0 +1,117 @@
+use crate::{
+    config::{Config, RewardState},
+    stake::StakedNft,
+};
+use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::Uint128;
+use cw721::Cw721ReceiveMsg;
+use cw_utils::Expiration;
+use shared_pkg::ownership::OwnershipResponse;
+
+// ========== instantiate ==========
+
+#[cw_serde]
+pub struct InstantiateMsg {
+    pub admin_addr: Option<String>,
+    /// CW404 collection whose NFTs can be staked
+    pub collection_addr: String,
+    /// e.g. untrn, rewards are paid from this contract's balance so fund it after instantiation
+    pub reward_denom: String,
+    /// Reward amount in reward denom paid per staked NFT per second
+    pub reward_per_nft_per_second: Uint128,
+    /// Seconds before an unstaked NFT can be withdrawn, 0 returns the NFT right away
+    pub unstake_cooldown_seconds: u64,
+}
+
+// ========== execute ==========
+
+#[cw_serde]
+pub enum ExecuteMsg {
+    /// Only admin can execute this
+    UpdateConfig {
+        new_reward_per_nft_per_second: Option<Uint128>,
+        new_unstake_cooldown_seconds: Option<u64>,
+    },
+    /// Propose new admin, admin changes only once new admin accepts
+    /// Only admin can execute this
+    ProposeNewAdmin {
+        new_admin_addr: String,
+        /// None never expires
+        expiry: Option<Expiration>,
+    },
+    /// Only proposed admin can execute this before proposal expires
+    AcceptAdmin {},
+    /// Only admin can execute this
+    CancelProposal {},
+    /// Remove admin forever, reward rate and unstake cooldown can never change after this
+    /// Only admin can execute this
+    RenounceAdmin {},
+    /// Stake an NFT, called by the CW404 collection when user sends NFT to this contract via SendNft
+    ReceiveNft(Cw721ReceiveMsg),
+    /// Claim all unclaimed rewards
+    Claim {},
+    /// Stop earning rewards on these NFTs
+    /// NFTs are returned right away if there is no unstake cooldown, otherwise use WithdrawNfts after cooldown
+    Unstake { token_ids: Vec<String> },
+    /// Withdraw unstaked NFTs whose cooldown has passed
+    WithdrawNfts { token_ids: Vec<String> },
+}
+
+// ========== query ==========
+
+#[cw_serde]
+pub struct ConfigResponse {
+    pub config: Config,
+}
+
+#[cw_serde]
+pub struct RewardStateResponse {
+    pub reward_state: RewardState,
+}
+
+#[cw_serde]
+pub struct PendingRewardsResponse {
+    pub staker: String,
+    pub staked_nft_count: u64,
+    /// Rewards claimable now in reward denom
+    pub pending_rewards: Uint128,
+    pub reward_denom: String,
+}
+
+#[cw_serde]
+pub struct StakedNftResponse {
+    pub staked_nft: StakedNft,
+}
+
+#[cw_serde]
+pub struct StakedNftsResponse {
+    pub staked_nfts: Vec<StakedNft>,
+}
+
+#[derive(QueryResponses)]
+#[cw_serde]
+pub enum QueryMsg {
+    #[returns(ConfigResponse)]
+    Config {},
+    #[returns(OwnershipResponse)]
+    Ownership {},
+    #[returns(RewardStateResponse)]
+    RewardState {},
+    #[returns(PendingRewardsResponse)]
+    PendingRewards { staker: String },
+    /// Error if the NFT is not in this contract
+    #[returns(StakedNftResponse)]
+    StakedNft { token_id: String },
+    /// Return NFTs of the staker, including NFTs in unstake cooldown
+    #[returns(StakedNftsResponse)]
+    StakedNfts {
+        staker: String,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+}
+
+// ========== migrate ==========
+
+#[cw_serde]
+pub struct MigrateMsg {}
@@ -0,0 +1,21 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, Timestamp, Uint128};
+
+#[cw_serde]
+pub struct StakedNft {
+    pub token_id: String,
+    pub staker_addr: Addr,
+    pub staked_time: Timestamp,
+    /// If exists, NFT is unstaked and can be withdrawn after this time
+    pub unstake_release_time: Option<Timestamp>,
+}
+
+#[cw_serde]
+pub struct StakerInfo {
+    /// Number of NFTs earning rewards, excluding NFTs in unstake cooldown
+    pub staked_nft_count: u64,
+    /// reward_per_nft in RewardState when rewards were last settled for this staker
+    pub reward_per_nft_paid: Uint128,
+    /// Settled rewards not yet claimed
+    pub unclaimed_rewards: Uint128,
+}
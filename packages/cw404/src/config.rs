@@ -4,10 +4,8 @@ use osmosis_std::types::cosmos::bank::v1beta1::Metadata as DenomMetadata;
 
 #[cw_serde]
 pub struct Config {
-    /// If exists, admin can mint, burn and force transfer FT
+    /// If exists, admin can burn and force transfer FT, grant roles and act as any role
     pub admin_addr: Option<Addr>,
-    /// If exists, minter can mint FT
-    pub minter_addr: Addr,
     /// Creator of the collection
     pub creator_addr: Addr,
     pub denom_metadata: DenomMetadata,
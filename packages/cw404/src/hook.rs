@@ -0,0 +1,34 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, Uint128};
+
+#[cw_serde]
+pub enum NftHookWatch {
+    /// Notify on every NFT ownership change
+    All {},
+    /// Notify when any of these NFTs changes owner
+    TokenIds { token_ids: Vec<Uint128> },
+    /// Notify when NFTs move from or to any of these addresses
+    Owners { owners: Vec<String> },
+}
+
+#[cw_serde]
+pub struct NftHook {
+    /// Contract that receives NftHookExecuteMsg
+    pub hook_addr: Addr,
+    pub watch: NftHookWatch,
+}
+
+#[cw_serde]
+pub struct NftOwnershipChange {
+    pub token_id: String,
+    /// None if the NFT is minted
+    pub old_owner: Option<String>,
+    /// None if the NFT is burned
+    pub new_owner: Option<String>,
+}
+
+/// Message sent to hook contracts, hook contracts must handle this in their ExecuteMsg
+#[cw_serde]
+pub enum NftHookExecuteMsg {
+    NftOwnershipChanged { changes: Vec<NftOwnershipChange> },
+}
@@ -1,3 +1,7 @@
 pub mod config;
+pub mod hook;
 pub mod mint_group;
 pub mod msg;
+pub mod nft_metadata;
+pub mod pause;
+pub mod role;
@@ -1,19 +1,47 @@
-use crate::{config::Config, mint_group::MintGroup};
+use crate::{
+    config::Config,
+    hook::{NftHook, NftHookWatch},
+    mint_group::MintGroup,
+    nft_metadata::{
+        NftMetadataEntry, TraitLayer, TraitMetadata, TraitMetadataConfig,
+    },
+    pause::PauseAction,
+    role::Role,
+};
 use cosmwasm_schema::{cw_serde, QueryResponses};
-use cosmwasm_std::{Binary, Coin, Uint128, Uint64};
+use cosmwasm_std::{Addr, Binary, Coin, Uint128, Uint64};
 use cw721::{
     AllNftInfoResponse, ApprovalResponse, ApprovalsResponse,
     ContractInfoResponse, NftInfoResponse, NumTokensResponse, OperatorResponse,
     OperatorsResponse, OwnerOfResponse, TokensResponse,
 };
+use cw721_base::state::TokenInfo as NftTokenInfo;
 use cw721_metadata_onchain::Extension as NftExtension;
 use cw_utils::Expiration;
+use shared_pkg::{
+    airdrop::{AirdropClaimedResponse, AirdropConfig, AirdropResponse},
+    burn::{BurnLeaderboardResponse, BurnedAmountResponse, Burner},
+    ownership::OwnershipResponse,
+};
 
 // ========== instantiate ==========
 
+#[cw_serde]
+pub struct SeedLiquidityConfig {
+    // astroport factory address
+    pub astroport_factory_addr: String,
+    // paired base denom, e.g. uatom
+    pub paired_base_denom: String,
+    // paired base denom amount, e.g. 1_000_000
+    pub paired_base_denom_amount: Uint128,
+    // NFT supply reserved for the pool, part of max_nft_supply, 1 NFT = 1 denom
+    pub nft_supply: Uint128,
+}
+
 #[cw_serde]
 pub struct InstantiateMsg {
     pub admin_addr: Option<String>,
+    /// granted minter role
     pub minter_addr: String,
     pub creator_addr: String,
     pub royalty_payment_addr: String,
@@ -29,6 +57,14 @@ pub struct InstantiateMsg {
     pub denom_uri: String,
     pub denom_uri_hash: String,
     pub mint_groups: Vec<MintGroup>,
+    /// If set, newly minted NFTs get name, image and attributes generated from trait layers
+    pub trait_metadata: Option<TraitMetadata>,
+    /// If set, reserved NFT supply is minted as FT and paired with paid funds in a new Astroport pair
+    /// LP goes to creator, pair holds FT without NFTs
+    pub seed_liquidity_config: Option<SeedLiquidityConfig>,
+    /// If set, airdrop FT is minted to and held by this contract until claimed with merkle proofs
+    /// Seed liquidity NFT supply plus airdrop must not exceed max NFT supply
+    pub airdrop: Option<AirdropConfig>,
 }
 
 // ========== execute ==========
@@ -36,13 +72,61 @@ pub struct InstantiateMsg {
 #[cw_serde]
 pub enum ExecuteMsg {
     // ========== FT functions ==========
+    /// Only admin or royalty manager can execute this
     UpdateConfig {
-        new_admin_addr: Option<String>,
-        new_minter_addr: Option<String>,
         new_royalty_payment_addr: Option<String>,
         new_royalty_percentage: Option<Uint64>,
     },
+    /// Propose new admin, admin changes only once new admin accepts
+    /// Only admin can execute this
+    ProposeNewAdmin {
+        new_admin_addr: String,
+        /// None never expires
+        expiry: Option<Expiration>,
+    },
+    /// Only proposed admin can execute this before proposal expires
+    AcceptAdmin {},
+    /// Only admin can execute this
+    CancelProposal {},
+    /// Remove admin forever
+    /// Only admin can execute this
+    RenounceAdmin {},
+    /// Only admin can execute this
+    GrantRole { role: Role, addr: String },
+    /// Only admin can execute this
+    RevokeRole { role: Role, addr: String },
+    /// Add a mint group or replace the one with the same name
+    /// Only admin or mint group manager can execute this
+    SetMintGroup { mint_group: MintGroup },
+    /// Only admin or mint group manager can execute this
+    RemoveMintGroup { name: String },
+    /// Only admin or pauser can execute this
+    Pause { action: PauseAction },
+    /// Only admin can execute this
+    Unpause { action: PauseAction },
     // TODO: add reveal_metadata msg
+    /// Upload trait layers in chunks, values of an existing trait type are appended to it
+    /// Locked once the first NFT gets its traits
+    /// Only admin or metadata manager can execute this
+    UploadTraitLayers {
+        trait_metadata_config: Option<TraitMetadataConfig>,
+        trait_layers: Vec<TraitLayer>,
+    },
+    /// Set token URI and extension of NFTs, works for live, recycled and not yet minted NFTs
+    /// Only admin or metadata manager can execute this
+    SetTokenMetadata { entries: Vec<NftMetadataEntry> },
+    /// Make all NFT metadata permanent, this cannot be undone
+    /// Only admin or metadata manager can execute this
+    FreezeMetadata {},
+    /// Subscribe a contract to NFT ownership changes, including implicit mint and burn from FT minted or burned by this contract
+    /// NFT changes from bank sends of FT only emit events, token factory caps gas of before send hook
+    /// Only admin can execute this
+    AddNftHook {
+        hook_addr: String,
+        watch: NftHookWatch,
+    },
+    /// Only admin can execute this
+    RemoveNftHook { hook_addr: String },
     /// Mint FT
     /// Only admin or minter can execute this
     MintFt {
@@ -61,6 +145,9 @@ pub enum ExecuteMsg {
         /// amount is in base denom
         amount: Uint128,
     },
+    /// Burn FT sent in funds, NFTs of sender are recycled and sender climbs the burn leaderboard
+    /// Anyone can execute this
+    SelfBurnFt {},
     /// Force transfer FT from one account to another
     /// Only admin can execute this
     ForceTransferFt {
@@ -69,6 +156,21 @@ pub enum ExecuteMsg {
         from: String,
         to: String,
     },
+    /// Mint airdrop FT to this contract, previous airdrop must be closed
+    /// This contract holds FT without NFTs from then on
+    /// Only admin can execute this
+    CreateAirdrop { airdrop: AirdropConfig },
+    /// Claim sender's airdrop leaf, NFTs are minted for whole denoms received
+    ClaimAirdrop {
+        /// leaf index in the merkle tree
+        index: u32,
+        /// in base denom
+        amount: Uint128,
+        merkle_proof: Vec<Vec<u8>>,
+    },
+    /// Return unclaimed airdrop to unclaimed recipient or burn it after expiration
+    /// Anyone can execute this
+    CloseAirdrop {},
     // ========== NFT functions ==========
     /// Allows operator to transfer / send the token from the owner's account.
     /// If expiration is set, then this allowance has a time/height limit
@@ -123,9 +225,81 @@ pub struct BalanceResponse {
     pub ft_balance_in_base_denom: Uint128,
 }
 
+#[cw_serde]
+pub struct RecycledNft {
+    /// position in the recycle queue, 0 is the next one to be minted
+    pub queue_position: u32,
+    pub token_id: Uint128,
+    /// NFT info as it was when the NFT got burned
+    pub nft: NftTokenInfo<NftExtension>,
+}
+
 #[cw_serde]
 pub struct RecycledNftTokenIdsResponse {
-    pub recycled_nft_token_ids: Vec<Uint128>,
+    /// total number of NFT IDs in the recycle queue
+    pub queue_length: u32,
+    pub recycled_nfts: Vec<RecycledNft>,
+    /// pass as start_after_idx to fetch the next page, None if no more
+    pub next: Option<u32>,
+}
+
+#[cw_serde]
+pub enum NftStatus {
+    /// NFT is minted and owned by owner
+    Live { owner: String },
+    /// NFT is burned and waiting in the recycle queue to be minted again
+    Recycled { queue_position: u32 },
+    /// NFT ID has never been minted
+    NeverMinted {},
+}
+
+#[cw_serde]
+pub struct NftStatusResponse {
+    pub token_id: Uint128,
+    pub status: NftStatus,
+}
+
+#[cw_serde]
+pub struct TraitLayersResponse {
+    pub trait_metadata_config: Option<TraitMetadataConfig>,
+    pub trait_layers: Vec<TraitLayer>,
+    /// true if trait layers can no longer be changed
+    pub locked: bool,
+}
+
+#[cw_serde]
+pub struct MetadataFrozenResponse {
+    pub frozen: bool,
+}
+
+#[cw_serde]
+pub struct NftHooksResponse {
+    pub nft_hooks: Vec<NftHook>,
+}
+
+#[cw_serde]
+pub struct SeedLiquidityResponse {
+    pub seed_liquidity_config: Option<SeedLiquidityConfig>,
+    /// Astroport pair created for seed liquidity, exempt from NFT bookkeeping
+    pub pair_addr: Option<Addr>,
+}
+
+#[cw_serde]
+pub struct HasRoleResponse {
+    /// admin has every role
+    pub has_role: bool,
+}
+
+#[cw_serde]
+pub struct RoleMembersResponse {
+    /// granted members only, admin is not listed
+    pub members: Vec<Addr>,
+}
+
+#[cw_serde]
+pub struct PauseStatusResponse {
+    pub minting_paused: bool,
+    pub nft_transfers_paused: bool,
 }
 
 #[derive(QueryResponses)]
@@ -134,17 +308,55 @@ pub enum QueryMsg {
     // ========== general functions ==========
     #[returns(ConfigResponse)]
     Config {},
+    #[returns(OwnershipResponse)]
+    Ownership {},
+    #[returns(HasRoleResponse)]
+    HasRole { role: Role, addr: String },
+    #[returns(RoleMembersResponse)]
+    RoleMembers {
+        role: Role,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    #[returns(PauseStatusResponse)]
+    PauseStatus {},
+    /// List NFTs in the recycle queue from front to back
     #[returns(RecycledNftTokenIdsResponse)]
     RecycledNftTokenIds {
+        /// queue position to start after, unset starts from the front
         start_after_idx: Option<u32>,
         limit: Option<u32>,
     },
     #[returns(NftInfoResponse<NftExtension>)]
     RecycledNftInfo { token_id: Uint128 },
+    /// Return whether the NFT is live, recycled or never minted
+    #[returns(NftStatusResponse)]
+    NftStatus { token_id: Uint128 },
+    #[returns(TraitLayersResponse)]
+    TraitLayers {},
+    #[returns(MetadataFrozenResponse)]
+    MetadataFrozen {},
+    #[returns(NftHooksResponse)]
+    NftHooks {},
+    #[returns(SeedLiquidityResponse)]
+    SeedLiquidity {},
+    #[returns(AirdropResponse)]
+    Airdrop {},
+    /// Whether leaf index of the latest airdrop is claimed
+    #[returns(AirdropClaimedResponse)]
+    AirdropClaimed { index: u32 },
     #[returns(SupplyResponse)]
     Supply {},
     #[returns(BalanceResponse)]
     Balance { owner: String },
+    /// Cumulative self burn of addr
+    #[returns(BurnedAmountResponse)]
+    BurnedAmount { addr: String },
+    #[returns(BurnLeaderboardResponse)]
+    BurnLeaderboard {
+        start_after: Option<Burner>,
+        limit: Option<u32>,
+    },
     // ========== NFT functions ==========
     /// Return the owner of the given token, error if token does not exist
     #[returns(OwnerOfResponse)]
@@ -222,7 +434,7 @@ pub enum QueryMsg {
 
 // ========== migrate ==========
 #[cw_serde]
-pub enum MigrateMsg {}
+pub struct MigrateMsg {}
 
 // ========== sudo ==========
 #[cw_serde]
@@ -0,0 +1,41 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::Uint128;
+use cw721_metadata_onchain::Extension as NftExtension;
+
+#[cw_serde]
+pub struct TraitValue {
+    /// e.g. red
+    pub value: String,
+    /// rarity weight, chance of being picked is weight / sum of all weights in the layer
+    pub weight: u32,
+}
+
+#[cw_serde]
+pub struct TraitLayer {
+    /// e.g. background
+    pub trait_type: String,
+    pub values: Vec<TraitValue>,
+}
+
+#[cw_serde]
+pub struct TraitMetadataConfig {
+    /// NFT name is {name_prefix}{token_id}, e.g. "Bad NFT #"
+    pub name_prefix: String,
+    /// NFT image is {image_base_uri}/{dna}, dna is the index of the picked value in each layer joined by "-"
+    /// e.g. ipfs://xxx/0-3-1
+    pub image_base_uri: String,
+}
+
+#[cw_serde]
+pub struct TraitMetadata {
+    pub config: TraitMetadataConfig,
+    /// more trait layers can be uploaded later with UploadTraitLayers
+    pub trait_layers: Vec<TraitLayer>,
+}
+
+#[cw_serde]
+pub struct NftMetadataEntry {
+    pub token_id: Uint128,
+    pub token_uri: Option<String>,
+    pub extension: NftExtension,
+}
@@ -0,0 +1,20 @@
+use cosmwasm_schema::cw_serde;
+
+/// Collection actions that can be paused independently
+#[cw_serde]
+pub enum PauseAction {
+    /// mint FT with MintFt
+    Minting {},
+    /// transfer and send NFT, FT transfers are not affected
+    NftTransfers {},
+}
+
+impl PauseAction {
+    /// storage key of the action
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PauseAction::Minting {} => "minting",
+            PauseAction::NftTransfers {} => "nft_transfers",
+        }
+    }
+}
@@ -0,0 +1,29 @@
+use cosmwasm_schema::cw_serde;
+
+/// Admin holds every role, each role can be granted to multiple addresses
+#[cw_serde]
+pub enum Role {
+    /// mint FT
+    Minter {},
+    /// upload trait layers, set token metadata and freeze metadata
+    MetadataManager {},
+    /// add, update and remove mint groups
+    MintGroupManager {},
+    /// update royalty payment address and percentage
+    RoyaltyManager {},
+    /// pause the collection
+    Pauser {},
+}
+
+impl Role {
+    /// storage key of the role
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Role::Minter {} => "minter",
+            Role::MetadataManager {} => "metadata_manager",
+            Role::MintGroupManager {} => "mint_group_manager",
+            Role::RoyaltyManager {} => "royalty_manager",
+            Role::Pauser {} => "pauser",
+        }
+    }
+}
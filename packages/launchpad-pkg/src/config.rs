@@ -1,10 +1,12 @@
 use cosmwasm_schema::cw_serde;
 use cosmwasm_std::{Addr, Uint128, Uint64};
 
+use crate::{fee::FeeRecipient, launch_access::LaunchAccess};
+
 #[cw_serde]
 pub struct Cw404Config {
-    /// Launchpad fee collector
-    pub fee_collector: Addr,
+    /// Launchpad fee recipients, weights add up to TOTAL_FEE_WEIGHT_BPS
+    pub fee_recipients: Vec<FeeRecipient>,
     /// CW404 contract code ID
     pub cw404_code_id: Uint64,
     /// Create collection fee
@@ -15,8 +17,8 @@ pub struct Cw404Config {
 
 #[cw_serde]
 pub struct CoinConfig {
-    /// Launchpad fee collector
-    pub fee_collector: Addr,
+    /// Launchpad fee recipients, weights add up to TOTAL_FEE_WEIGHT_BPS
+    pub fee_recipients: Vec<FeeRecipient>,
     /// Coin contract code ID
     pub coin_code_id: Uint64,
     /// Create coin fee
@@ -25,14 +27,18 @@ pub struct CoinConfig {
 
 #[cw_serde]
 pub struct Config {
-    /// Launchpad admin
-    pub admin_addr: Addr,
+    /// Launchpad admin, None once renounced
+    pub admin_addr: Option<Addr>,
     /// Astroport pool factory contract address
     pub astroport_factory_addr: Addr,
     /// CW404 related config
     pub cw404_config: Cw404Config,
     /// Coin related config
     pub coin_config: CoinConfig,
+    /// Who can create coins and CW404 collections
+    pub launch_access: LaunchAccess,
+    /// Share of creation and mint fees accrued for the referrer, in basis points
+    pub referral_share_bps: u64,
 }
 
 #[cw_serde]
@@ -0,0 +1,20 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::Addr;
+
+/// Weights of all fee recipients must add up to this
+pub const TOTAL_FEE_WEIGHT_BPS: u64 = 10_000;
+
+/// Fee recipient as passed in messages, address not yet validated
+#[cw_serde]
+pub struct FeeRecipientConfig {
+    pub addr: String,
+    /// Share of the fee in basis points
+    pub weight_bps: u64,
+}
+
+#[cw_serde]
+pub struct FeeRecipient {
+    pub addr: Addr,
+    /// Share of the fee in basis points
+    pub weight_bps: u64,
+}
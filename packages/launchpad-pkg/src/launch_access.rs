@@ -0,0 +1,30 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, Timestamp};
+
+/// Who can create coins and CW404 collections
+#[cw_serde]
+pub enum LaunchAccess {
+    /// anyone can create
+    Open {},
+    /// only creators in launch allowlist can create
+    Allowlist {},
+    /// only creators with a merkle proof of their address can create
+    MerkleGated { merkle_root: Vec<u8> },
+    /// creators request a launch, each approved request allows one creation
+    Application {},
+}
+
+#[cw_serde]
+pub enum LaunchRequestStatus {
+    Pending {},
+    Approved {},
+}
+
+#[cw_serde]
+pub struct LaunchRequest {
+    pub creator_addr: Addr,
+    /// e.g. project name and links for admin to review
+    pub description: String,
+    pub status: LaunchRequestStatus,
+    pub requested_at: Timestamp,
+}
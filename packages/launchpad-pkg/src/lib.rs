@@ -1,3 +1,8 @@
 pub mod config;
+pub mod fee;
+pub mod launch_access;
 pub mod msg;
+pub mod pause;
+pub mod referral;
+pub mod template;
 pub mod token;
@@ -1,10 +1,22 @@
 use crate::{
     config::{Config, Stats},
-    token::TokenContract,
+    fee::FeeRecipientConfig,
+    launch_access::{LaunchAccess, LaunchRequest},
+    pause::PauseAction,
+    referral::ReferralEarnings,
+    template::{Template, TemplateId},
+    token::{TokenContract, TokenType},
+};
+use coin::{
+    bonding_curve::BondingCurveParams, dex::SeedLiquidityPairType,
+    launch_protection::LaunchProtectionConfig,
+    liquidity_lock::LiquidityLockMode, vesting::VestingAllocation,
 };
 use cosmwasm_schema::{cw_serde, QueryResponses};
-use cosmwasm_std::{Uint128, Uint64};
-use cw404::mint_group::MintGroup;
+use cosmwasm_std::{Addr, Binary, Decimal, Uint128, Uint64};
+use cw404::{mint_group::MintGroup, nft_metadata::TraitMetadata};
+use cw_utils::Expiration;
+use shared_pkg::{airdrop::AirdropConfig, ownership::OwnershipResponse};
 
 // ========== instantiate ==========
 
@@ -12,53 +24,151 @@ use cw404::mint_group::MintGroup;
 pub struct InstantiateMsg {
     pub admin_addr: String,
     pub astroport_factory_addr: String,
-    pub cw404_fee_collector: String,
+    pub cw404_fee_recipients: Vec<FeeRecipientConfig>,
     pub cw404_code_id: Uint64,
     pub cw404_collection_creation_fee: Uint128,
     pub cw404_mint_fee: Uint128,
-    pub coin_fee_collector: String,
+    pub coin_fee_recipients: Vec<FeeRecipientConfig>,
     pub coin_code_id: Uint64,
     pub coin_creation_fee: Uint128,
+    /// None is open to anyone
+    pub launch_access: Option<LaunchAccess>,
+    /// None is 0, capped at MAX_REFERRAL_SHARE_BPS
+    pub referral_share_bps: Option<u64>,
 }
 
 // ========== execute ==========
 
+/// DEX to create seed liquidity pool on, Astroport uses the factory in launchpad config
+#[cw_serde]
+pub enum SeedLiquidityDexOption {
+    Astroport {
+        pair_type: SeedLiquidityPairType,
+    },
+    OsmosisBalancer {
+        swap_fee: Decimal,
+    },
+    OsmosisConcentrated {
+        tick_spacing: u64,
+        spread_factor: Decimal,
+    },
+}
+
+#[cw_serde]
+pub struct SeedLiquidityOptions {
+    // what happens to the LP token of seed liquidity
+    pub lock_mode: LiquidityLockMode,
+    pub dex: SeedLiquidityDexOption,
+    // supply deposited to the pool in denom, rest of initial supply goes to creator
+    // None deposits all initial supply
+    pub pool_supply_in_denom: Option<Uint128>,
+}
+
+#[cw_serde]
+pub enum MigrationTargets {
+    /// Registered token contracts, CW404 collections and coins can be mixed if they share the code
+    Contracts { contract_addrs: Vec<String> },
+    /// All registered token contracts of the type in address order, paginated to stay within gas limit
+    All {
+        token_type: TokenType,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+}
+
 #[cw_serde]
 pub enum ExecuteMsg {
     /// Update shared config
     UpdateSharedConfig {
-        new_admin_addr: Option<String>,
         new_astroport_factory_addr: Option<String>,
+        new_launch_access: Option<LaunchAccess>,
+        /// capped at MAX_REFERRAL_SHARE_BPS
+        new_referral_share_bps: Option<u64>,
+    },
+    /// Only admin can execute this
+    UpdateLaunchAllowlist {
+        add_addrs: Vec<String>,
+        remove_addrs: Vec<String>,
+    },
+    /// Request to create a token when launch access is application based
+    /// Replaces the pending request of sender, if any
+    RequestLaunch { description: String },
+    /// Approve or reject a pending launch request, rejected request is removed
+    /// Only admin can execute this
+    ReviewLaunchRequest { creator_addr: String, approve: bool },
+    /// Claim fees shared with sender as referrer
+    ClaimReferralRewards {},
+    /// Set verified badge of a registered coin or CW404 collection
+    /// Only admin can execute this
+    SetVerified {
+        contract_addr: String,
+        verified: bool,
     },
+    /// Propose new admin, admin changes only once new admin accepts
+    /// Only admin can execute this
+    ProposeNewAdmin {
+        new_admin_addr: String,
+        /// None never expires
+        expiry: Option<Expiration>,
+    },
+    /// Only proposed admin can execute this before proposal expires
+    AcceptAdmin {},
+    /// Only admin can execute this
+    CancelProposal {},
+    /// Remove admin forever
+    /// Only admin can execute this
+    RenounceAdmin {},
+    /// Set guardian that can pause but not unpause, None removes guardian
+    /// Only admin can execute this
+    SetGuardian { guardian_addr: Option<String> },
+    /// Only admin or guardian can execute this
+    Pause { action: PauseAction },
+    /// Only admin can execute this
+    Unpause { action: PauseAction },
     /// Update CW404 related config
     UpdateCw404Config {
-        new_fee_collector_addr: Option<String>,
+        new_fee_recipients: Option<Vec<FeeRecipientConfig>>,
         new_cw404_code_id: Option<Uint64>,
         new_collection_creation_fee: Option<Uint128>,
         new_mint_fee: Option<Uint128>,
     },
     /// Update coin related config
     UpdateCoinConfig {
-        new_fee_collector_addr: Option<String>,
+        new_fee_recipients: Option<Vec<FeeRecipientConfig>>,
         new_coin_code_id: Option<Uint64>,
         new_coin_creation_fee: Option<Uint128>,
     },
-    /// Create a new CW404 collection
-    CreateCw404Collection {
-        royalty_payment_address: String,
-        royalty_percentage: Uint64,
-        max_nft_supply: Uint128,
-        // e.g. subdenom = atom, then base subdenom is uatom,
-        // denom is factory/contract_addr/atom, base denom is factory/contract_addr/uatom
-        // 1 atom = 1_000_000 uatom, 1 atom = 1 atom NFT,
-        subdenom: String,
-        denom_description: String,
-        denom_name: String,
-        denom_symbol: String,
-        denom_uri: String,
-        denom_uri_hash: String,
-        mint_groups: Vec<MintGroup>,
+    /// Add a template, checksum is read from the stored code
+    /// Only admin can execute this
+    AddTemplate {
+        name: String,
+        version: String,
+        token_type: TokenType,
+        code_id: Uint64,
+        creation_fee: Uint128,
+        /// only charged for CW404 collections
+        mint_fee: Uint128,
     },
+    /// Code ID of a template never changes, add a new version instead
+    /// Only admin can execute this
+    UpdateTemplate {
+        name: String,
+        version: String,
+        new_creation_fee: Option<Uint128>,
+        new_mint_fee: Option<Uint128>,
+        enabled: Option<bool>,
+    },
+    /// Migrate registered token contracts to new code
+    /// Tokens the launchpad is not wasm admin of are skipped, e.g. immutable coins
+    /// Only admin can execute this
+    MigrateTokenContracts {
+        code_id: Uint64,
+        targets: MigrationTargets,
+        /// migrate msg passed to each token contract
+        msg: Binary,
+    },
+    /// Create a new CW404 collection
+    CreateCw404Collection(Box<CreateCw404CollectionMsg>),
     /// Call 404's mint_ft function and get NFT implicitly
     MintFtOfCw404 {
         /// collection address
@@ -71,6 +181,8 @@ pub enum ExecuteMsg {
         mint_group_name: String,
         /// merkle proof for recipient address
         merkle_proof: Option<Vec<Vec<u8>>>,
+        /// If set, referral share of mint fee is accrued for the referrer
+        referrer: Option<String>,
     },
     // TODO: implement this
     /// Call 404's mint_nft (to be added) function and get FT implicitly
@@ -79,21 +191,75 @@ pub enum ExecuteMsg {
     //     recipient_addr: String,
     // },
     /// Create a new Cosmos SDK native coin managed by token factory module
-    CreateCoin {
-        // initial_supply is in denom, e.g. atom, not base denom
-        initial_supply_in_denom: Uint128,
-        // max_supply is in denom, e.g. atom, not base denom
-        max_supply_in_denom: Uint128,
-        // immutable means no one can mint or burn or force transfer after creation
-        immutable: bool,
-        // same as subdenom in CreateCw404Collection
-        subdenom: String,
-        denom_description: String,
-        denom_name: String,
-        denom_symbol: String,
-        denom_uri: String,
-        denom_uri_hash: String,
-    },
+    CreateCoin(Box<CreateCoinMsg>),
+}
+
+/// Params of ExecuteMsg::CreateCw404Collection, boxed to keep ExecuteMsg small
+#[cw_serde]
+pub struct CreateCw404CollectionMsg {
+    pub royalty_payment_address: String,
+    pub royalty_percentage: Uint64,
+    pub max_nft_supply: Uint128,
+    // e.g. subdenom = atom, then base subdenom is uatom,
+    // denom is factory/contract_addr/atom, base denom is factory/contract_addr/uatom
+    // 1 atom = 1_000_000 uatom, 1 atom = 1 atom NFT,
+    pub subdenom: String,
+    pub denom_description: String,
+    pub denom_name: String,
+    pub denom_symbol: String,
+    pub denom_uri: String,
+    pub denom_uri_hash: String,
+    pub mint_groups: Vec<MintGroup>,
+    /// If set, NFTs get name, image and attributes generated from trait layers
+    pub trait_metadata: Option<TraitMetadata>,
+    /// If set, this many NFTs of max_nft_supply are reserved for an Astroport pair
+    /// paired with funds paid on top of collection creation fee, LP goes to creator
+    pub seed_liquidity_nft_supply: Option<Uint128>,
+    /// If set, airdrop FT is held by the collection until claimed with merkle proofs
+    pub airdrop: Option<AirdropConfig>,
+    /// If set, code ID and fees come from the template instead of CW404 config
+    pub template: Option<TemplateId>,
+    /// Proof of creator address when launch access is merkle gated
+    pub launch_merkle_proof: Option<Vec<Vec<u8>>>,
+    /// If set, referral share of creation fee is accrued for the referrer
+    pub referrer: Option<String>,
+}
+
+/// Params of ExecuteMsg::CreateCoin, boxed to keep ExecuteMsg small
+#[cw_serde]
+pub struct CreateCoinMsg {
+    // initial_supply is in denom, e.g. atom, not base denom
+    pub initial_supply_in_denom: Uint128,
+    // max_supply is in denom, e.g. atom, not base denom
+    pub max_supply_in_denom: Uint128,
+    // immutable means no one can mint or burn or force transfer after creation
+    pub immutable: bool,
+    // same as subdenom in CreateCw404Collection
+    pub subdenom: String,
+    pub denom_description: String,
+    pub denom_name: String,
+    pub denom_symbol: String,
+    pub denom_uri: String,
+    pub denom_uri_hash: String,
+    // if exists, coin is sold on a bonding curve and migrates to Astroport at the market cap threshold
+    // initial supply must be 0 and no seed liquidity can be paid
+    pub bonding_curve: Option<BondingCurveParams>,
+    // lock mode, pair type and pool supply of seed liquidity
+    // default sends LP to creator and deposits all initial supply to an Astroport XYK pool
+    pub seed_liquidity_options: Option<SeedLiquidityOptions>,
+    // team allocations minted on top of initial supply and vested inside the coin contract
+    pub vesting_allocations: Option<Vec<VestingAllocation>>,
+    // airdrop minted on top of initial supply and held by the coin contract until claimed
+    pub airdrop: Option<AirdropConfig>,
+    // anti-whale and anti-bot limits enforced by the coin contract until they expire
+    // Astroport pair is treated as a pool automatically
+    pub launch_protection_config: Option<LaunchProtectionConfig>,
+    // if exists, code ID and creation fee come from the template instead of coin config
+    pub template: Option<TemplateId>,
+    // proof of creator address when launch access is merkle gated
+    pub launch_merkle_proof: Option<Vec<Vec<u8>>>,
+    // if exists, referral share of creation fee is accrued for the referrer
+    pub referrer: Option<String>,
 }
 
 // ========== query ==========
@@ -108,6 +274,33 @@ pub struct StatsResponse {
     pub stats: Stats,
 }
 
+#[cw_serde]
+pub struct PauseStatusResponse {
+    pub guardian_addr: Option<Addr>,
+    pub creation_paused: bool,
+    pub minting_paused: bool,
+}
+
+#[cw_serde]
+pub struct LaunchAllowlistResponse {
+    pub addrs: Vec<Addr>,
+}
+
+#[cw_serde]
+pub struct LaunchRequestsResponse {
+    pub launch_requests: Vec<LaunchRequest>,
+}
+
+#[cw_serde]
+pub struct ReferralEarningsResponse {
+    pub referral_earnings: ReferralEarnings,
+}
+
+#[cw_serde]
+pub struct TemplatesResponse {
+    pub templates: Vec<Template>,
+}
+
 #[cw_serde]
 pub struct TokenContractResponse {
     pub token_contract: TokenContract,
@@ -123,8 +316,30 @@ pub struct TokenContractsResponse {
 pub enum QueryMsg {
     #[returns(ConfigResponse)]
     Config {},
+    #[returns(OwnershipResponse)]
+    Ownership {},
     #[returns(StatsResponse)]
     Stats {},
+    #[returns(PauseStatusResponse)]
+    PauseStatus {},
+    #[returns(LaunchAllowlistResponse)]
+    LaunchAllowlist {
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    /// Pending and approved launch requests
+    #[returns(LaunchRequestsResponse)]
+    LaunchRequests {
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    #[returns(ReferralEarningsResponse)]
+    ReferralEarnings { referrer: String },
+    #[returns(TemplatesResponse)]
+    Templates {
+        start_after: Option<TemplateId>,
+        limit: Option<u32>,
+    },
     #[returns(TokenContractResponse)]
     Cw404CollectionByContract { contract_addr: String },
     #[returns(TokenContractsResponse)]
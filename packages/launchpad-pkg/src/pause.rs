@@ -0,0 +1,20 @@
+use cosmwasm_schema::cw_serde;
+
+/// Launchpad actions that can be paused independently
+#[cw_serde]
+pub enum PauseAction {
+    /// create CW404 collections and coins
+    Creation {},
+    /// mint FT of CW404 collections through launchpad
+    Minting {},
+}
+
+impl PauseAction {
+    /// storage key of the action
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PauseAction::Creation {} => "creation",
+            PauseAction::Minting {} => "minting",
+        }
+    }
+}
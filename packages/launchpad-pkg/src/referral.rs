@@ -0,0 +1,15 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::Uint128;
+
+/// Referral share is taken from the fee, so it cannot exceed the whole fee
+pub const MAX_REFERRAL_SHARE_BPS: u64 = 10_000;
+
+/// Fees shared with a referrer, in fee denom
+#[cw_serde]
+#[derive(Default)]
+pub struct ReferralEarnings {
+    /// cumulative, never decreases
+    pub total_earned: Uint128,
+    /// accrued and not yet claimed with ClaimReferralRewards
+    pub unclaimed: Uint128,
+}
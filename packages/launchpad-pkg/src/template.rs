@@ -0,0 +1,28 @@
+use crate::token::TokenType;
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{HexBinary, Uint128, Uint64};
+
+#[cw_serde]
+pub struct TemplateId {
+    /// e.g. basic-coin, taxed-coin, cw404
+    pub name: String,
+    /// e.g. v1, v2
+    pub version: String,
+}
+
+/// Token contract code that can be selected when creating a token
+#[cw_serde]
+pub struct Template {
+    pub name: String,
+    pub version: String,
+    pub token_type: TokenType,
+    pub code_id: Uint64,
+    /// Checksum of the wasm code when template is added
+    pub checksum: HexBinary,
+    /// Create fee, replaces creation fee in config
+    pub creation_fee: Uint128,
+    /// Mint fee of CW404 collections created with this template, replaces mint fee in config
+    pub mint_fee: Uint128,
+    /// Disabled template cannot be selected, existing tokens are not affected
+    pub enabled: bool,
+}
@@ -1,10 +1,24 @@
 use cosmwasm_schema::cw_serde;
 use cosmwasm_std::Addr;
 
+use crate::template::TemplateId;
+
+#[cw_serde]
+pub enum TokenType {
+    Cw404Collection {},
+    Coin {},
+}
+
 #[cw_serde]
 pub struct TokenContract {
     /// The creator of the cw404 collection or coin
     pub creator_addr: Addr,
     /// The address of the token contract that manages the coin or cw404 collection
     pub contract_addr: Addr,
+    /// Astroport pair seeded at creation, only recorded for cw404 collections
+    pub pair_addr: Option<Addr>,
+    /// Template the token is created with, None if created with code ID in config
+    pub template: Option<TemplateId>,
+    /// Set by admin for curated tokens
+    pub verified: bool,
 }
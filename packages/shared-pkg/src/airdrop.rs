@@ -0,0 +1,126 @@
+use crate::{
+    error::ContractError,
+    merkle::{keccak256, verify_merkle_proof},
+};
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, Api, BlockInfo, Timestamp, Uint128};
+
+/// Number of claims tracked by one word of the claim bitmap
+pub const AIRDROP_CLAIM_BITMAP_WORD_SIZE: u32 = 64;
+
+#[cw_serde]
+pub struct AirdropConfig {
+    /// keccak256 merkle root, leaf is keccak256 of "{index},{address},{amount}"
+    pub merkle_root: Vec<u8>,
+    /// URI to the merkle tree
+    pub merkle_tree_uri: Option<String>,
+    /// in base denom, sum of all leaves, minted to and held by token contract
+    pub total_amount: Uint128,
+    /// claims close at expiration
+    pub expiration: Timestamp,
+    /// unclaimed amount goes here when airdrop is closed, None burns it
+    pub unclaimed_recipient: Option<String>,
+}
+
+#[cw_serde]
+pub struct Airdrop {
+    /// increases by 1 for every airdrop attached, claims are tracked per round
+    pub round: u64,
+    pub merkle_root: Vec<u8>,
+    pub merkle_tree_uri: Option<String>,
+    /// in base denom
+    pub total_amount: Uint128,
+    /// in base denom
+    pub claimed_amount: Uint128,
+    pub expiration: Timestamp,
+    pub unclaimed_recipient: Option<Addr>,
+    /// true once unclaimed amount is returned or burned, a new airdrop can be attached then
+    pub closed: bool,
+}
+
+#[cw_serde]
+pub struct AirdropResponse {
+    /// latest airdrop, None if no airdrop has been attached
+    pub airdrop: Option<Airdrop>,
+}
+
+#[cw_serde]
+pub struct AirdropClaimedResponse {
+    pub claimed: bool,
+}
+
+pub fn new_airdrop(
+    api: &dyn Api,
+    block: &BlockInfo,
+    config: AirdropConfig,
+    round: u64,
+) -> Result<Airdrop, ContractError> {
+    let invalid = |reason: &str| ContractError::InvalidAirdropConfig {
+        reason: reason.to_string(),
+    };
+    if config.merkle_root.len() != 32 {
+        return Err(invalid("merkle root must be 32 bytes"));
+    }
+    if config.total_amount.is_zero() {
+        return Err(invalid("total amount must be greater than 0"));
+    }
+    if config.expiration <= block.time {
+        return Err(invalid("expiration must be in the future"));
+    }
+    Ok(Airdrop {
+        round,
+        merkle_root: config.merkle_root,
+        merkle_tree_uri: config.merkle_tree_uri,
+        total_amount: config.total_amount,
+        claimed_amount: Uint128::zero(),
+        expiration: config.expiration,
+        unclaimed_recipient: config
+            .unclaimed_recipient
+            .map(|addr| api.addr_validate(&addr))
+            .transpose()?,
+        closed: false,
+    })
+}
+
+/// Word index in the claim bitmap and bit of the claim in that word
+pub fn airdrop_claim_bit(index: u32) -> (u32, u64) {
+    (
+        index / AIRDROP_CLAIM_BITMAP_WORD_SIZE,
+        1u64 << (index % AIRDROP_CLAIM_BITMAP_WORD_SIZE),
+    )
+}
+
+/// Unclaimed amount still held by token contract, 0 once closed
+pub fn airdrop_unclaimed_amount(airdrop: &Airdrop) -> Uint128 {
+    if airdrop.closed {
+        Uint128::zero()
+    } else {
+        airdrop.total_amount - airdrop.claimed_amount
+    }
+}
+
+pub fn assert_can_claim_airdrop(
+    airdrop: &Airdrop,
+    block: &BlockInfo,
+    claimed_bitmap_word: u64,
+    index: u32,
+    claimer_addr: &Addr,
+    amount: Uint128,
+    merkle_proof: Vec<Vec<u8>>,
+) -> Result<(), ContractError> {
+    if airdrop.closed || block.time >= airdrop.expiration {
+        return Err(ContractError::AirdropExpired {});
+    }
+    if claimed_bitmap_word & airdrop_claim_bit(index).1 != 0 {
+        return Err(ContractError::AirdropAlreadyClaimed { index });
+    }
+    let leaf_hash =
+        keccak256(format!("{index},{claimer_addr},{amount}").as_bytes());
+    if !verify_merkle_proof(leaf_hash, merkle_proof, &airdrop.merkle_root) {
+        return Err(ContractError::InvalidMerkleProofForAirdrop {});
+    }
+    if airdrop.claimed_amount + amount > airdrop.total_amount {
+        return Err(ContractError::AirdropClaimExceedsTotalAmount {});
+    }
+    Ok(())
+}
@@ -0,0 +1,91 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, Api, Empty, Order, StdResult, Storage, Uint128};
+use cw_storage_plus::{Bound, Map};
+
+const DEFAULT_LIMIT: u32 = 10;
+const MAX_LIMIT: u32 = 100;
+
+/// Cumulative self burn in base denom, key is burner
+pub const BURNED_AMOUNTS: Map<&Addr, Uint128> = Map::new("BURNED_AMOUNTS");
+/// Key is (cumulative self burn, burner), ranged descending for the leaderboard
+pub const BURN_LEADERBOARD: Map<(u128, &Addr), Empty> =
+    Map::new("BURN_LEADERBOARD");
+
+/// Cumulative self burn of a holder
+#[cw_serde]
+pub struct Burner {
+    pub addr: Addr,
+    /// in base denom
+    pub amount: Uint128,
+}
+
+#[cw_serde]
+pub struct BurnedAmountResponse {
+    /// in base denom
+    pub amount: Uint128,
+}
+
+#[cw_serde]
+pub struct BurnLeaderboardResponse {
+    /// sorted by amount descending
+    pub burners: Vec<Burner>,
+}
+
+/// Add self burn to burner's cumulative amount and move burner up the leaderboard
+pub fn record_self_burn(
+    storage: &mut dyn Storage,
+    burner_addr: &Addr,
+    amount: Uint128,
+) -> StdResult<Uint128> {
+    let previous_amount = BURNED_AMOUNTS
+        .may_load(storage, burner_addr)?
+        .unwrap_or_default();
+    BURN_LEADERBOARD.remove(storage, (previous_amount.u128(), burner_addr));
+    let total_amount = previous_amount + amount;
+    BURNED_AMOUNTS.save(storage, burner_addr, &total_amount)?;
+    BURN_LEADERBOARD.save(
+        storage,
+        (total_amount.u128(), burner_addr),
+        &Empty {},
+    )?;
+    Ok(total_amount)
+}
+
+pub fn query_burned_amount(
+    storage: &dyn Storage,
+    addr: &Addr,
+) -> StdResult<BurnedAmountResponse> {
+    Ok(BurnedAmountResponse {
+        amount: BURNED_AMOUNTS.may_load(storage, addr)?.unwrap_or_default(),
+    })
+}
+
+/// Burners sorted by amount descending, start after the last burner of the previous page
+pub fn query_burn_leaderboard(
+    api: &dyn Api,
+    storage: &dyn Storage,
+    start_after: Option<Burner>,
+    limit: Option<u32>,
+) -> StdResult<BurnLeaderboardResponse> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let start_after = start_after
+        .map(|burner| {
+            api.addr_validate(burner.addr.as_str())
+                .map(|addr| (burner.amount.u128(), addr))
+        })
+        .transpose()?;
+    let max = start_after
+        .as_ref()
+        .map(|(amount, addr)| Bound::exclusive((*amount, addr)));
+    let burners = BURN_LEADERBOARD
+        .keys(storage, None, max, Order::Descending)
+        .take(limit)
+        .map(|item| {
+            item.map(|(amount, addr)| Burner {
+                addr,
+                amount: Uint128::from(amount),
+            })
+        })
+        .collect::<StdResult<Vec<_>>>()?;
+    Ok(BurnLeaderboardResponse { burners })
+}
@@ -1,4 +1,4 @@
-use cosmwasm_std::{StdError, Uint128};
+use cosmwasm_std::{StdError, Timestamp, Uint128};
 use cw_utils::PaymentError;
 use thiserror::Error;
 
@@ -102,6 +102,12 @@ pub enum ContractError {
     #[error("Insufficient funds to create collection through launchpad, paid: {paid:?}, launchpad required: {required:?}")]
     FundsMisMatchToCreateCw404Collection { paid: Uint128, required: Uint128 },
 
+    #[error("Insufficient funds to seed liquidity, paid: {paid:?}, collection creation fee: {creation_fee:?}")]
+    InsufficientFundsToSeedLiquidity {
+        paid: Uint128,
+        creation_fee: Uint128,
+    },
+
     #[error("Insufficient funds to create coin through launchpad, paid: {paid:?}, launchpad required: {required:?}")]
     InsufficientFundsToCreateCoin { paid: Uint128, required: Uint128 },
 
@@ -129,12 +135,271 @@ pub enum ContractError {
     #[error("Cannot find created token from astroport reply")]
     CannotFindCreatedTokenFromAstroportReply {},
 
+    #[error("Error create pool in osmosis {err:?}")]
+    ErrorCreatePoolInOsmosis { err: String },
+
+    #[error("Cannot parse response from osmosis reply")]
+    CannotParseResponseFromOsmosisReply {},
+
     #[error("Cannot find subdenom in tokens {subdenom:?}")]
     CannotFindSubdenomToken { subdenom: String },
 
     #[error("Cannot find seed liquidity config")]
     CannotFindSeedLiquidityConfig {},
 
+    #[error("Trait layers are locked because NFTs already got their traits")]
+    TraitLayersLocked {},
+
+    #[error("Trait layer {trait_type:?} has no value")]
+    TraitLayerHasNoValue { trait_type: String },
+
+    #[error(
+        "Weight of trait value {value:?} in trait layer {trait_type:?} is zero"
+    )]
+    TraitValueWeightIsZero { trait_type: String, value: String },
+
+    #[error("NFT metadata is frozen")]
+    MetadataFrozen {},
+
+    #[error("NFT Token ID {nft_token_id:?} out of range, must be between 1 and max NFT supply {max_nft_supply:?}")]
+    NftTokenIdOutOfRange {
+        nft_token_id: Uint128,
+        max_nft_supply: Uint128,
+    },
+
+    #[error("NFT hook {hook_addr:?} already exists")]
+    NftHookAlreadyExists { hook_addr: String },
+
+    #[error("NFT hook {hook_addr:?} not found")]
+    NftHookNotFound { hook_addr: String },
+
+    #[error("Too many NFT hooks, max: {max:?}")]
+    TooManyNftHooks { max: u32 },
+
+    #[error("Only NFTs of collection {expected:?} can be staked, got NFT from {actual:?}")]
+    NftFromUnexpectedCollection { expected: String, actual: String },
+
+    #[error("NFT {token_id:?} is not staked by {staker:?}")]
+    NftNotStakedBySender { token_id: String, staker: String },
+
+    #[error("NFT {token_id:?} is already unstaked")]
+    NftAlreadyUnstaked { token_id: String },
+
+    #[error("NFT {token_id:?} is not unstaked")]
+    NftNotUnstaked { token_id: String },
+
+    #[error("NFT {token_id:?} is in unstake cooldown until {release_time:?}")]
+    NftInUnstakeCooldown {
+        token_id: String,
+        release_time: Timestamp,
+    },
+
+    #[error("No rewards to claim")]
+    NoRewardsToClaim {},
+
+    #[error("Invalid bonding curve config: {reason:?}")]
+    InvalidBondingCurveConfig { reason: String },
+
+    #[error("Coin is not launched on a bonding curve")]
+    BondingCurveNotEnabled {},
+
+    #[error("Bonding curve is closed for trading, coin has migrated or is migrating to Astroport")]
+    BondingCurveNotTrading {},
+
+    #[error("Bonding curve amount out {amount_out:?} is less than min amount out {min_amount_out:?}")]
+    BondingCurveAmountOutTooLow {
+        amount_out: Uint128,
+        min_amount_out: Uint128,
+    },
+
+    #[error("Astroport pair {pair_addr:?} already has liquidity, bonding curve cannot migrate into it")]
+    BondingCurvePairAlreadyHasLiquidity { pair_addr: String },
+
+    #[error("Cannot mint before bonding curve migrates to Astroport")]
+    CannotMintBeforeBondingCurveMigrates {},
+
+    #[error("Invalid seed liquidity config: {reason:?}")]
+    InvalidSeedLiquidityConfig { reason: String },
+
+    #[error("Invalid liquidity lock mode: {reason:?}")]
+    InvalidLiquidityLockMode { reason: String },
+
+    #[error("Only creator can call this function: {function:?}")]
+    OnlyCreatorCanCallThisFunction { function: String },
+
+    #[error(
+        "No locked LP to withdraw, LP is still locked or already withdrawn"
+    )]
+    NoLockedLpToWithdraw {},
+
+    #[error("Invalid vesting allocation: {reason:?}")]
+    InvalidVestingAllocation { reason: String },
+
+    #[error(
+        "No vested coin to claim, coin is still vesting or already claimed"
+    )]
+    NoVestedCoinToClaim {},
+
+    #[error("Invalid airdrop config: {reason:?}")]
+    InvalidAirdropConfig { reason: String },
+
+    #[error("Airdrop is still open, close it before attaching a new one")]
+    AirdropAlreadyActive {},
+
+    #[error("No airdrop is attached")]
+    NoAirdrop {},
+
+    #[error("Airdrop is expired or closed")]
+    AirdropExpired {},
+
+    #[error("Airdrop is not expired yet")]
+    AirdropNotExpired {},
+
+    #[error("Airdrop index {index:?} is already claimed")]
+    AirdropAlreadyClaimed { index: u32 },
+
+    #[error("Invalid merkle proof for airdrop")]
+    InvalidMerkleProofForAirdrop {},
+
+    #[error("Airdrop claim exceeds total amount of the airdrop")]
+    AirdropClaimExceedsTotalAmount {},
+
+    #[error("Transfer tax cannot exceed {max_bps:?} bps")]
+    TransferTaxTooHigh { max_bps: u64 },
+
+    #[error("Transfer tax is not set")]
+    TransferTaxNotSet {},
+
+    #[error("Transfer tax is renounced and can no longer be changed")]
+    TransferTaxRenounced {},
+
+    #[error("No tax owed can be swept from the given addresses")]
+    NoTaxToSweep {},
+
+    #[error(
+        "Transfer leaves {balance_after:?} but {owed:?} transfer tax is owed"
+    )]
+    TransferLeavesTaxUnpaid {
+        owed: Uint128,
+        balance_after: Uint128,
+    },
+
+    #[error("Invalid launch protection config: {reason}")]
+    InvalidLaunchProtectionConfig { reason: String },
+
+    #[error("Launch protection is not set or already expired")]
+    LaunchProtectionNotActive {},
+
+    #[error("Trading starts at {trading_start_time}")]
+    TradingNotStarted { trading_start_time: Timestamp },
+
+    #[error("Transfer amount exceeds max transaction amount {max_tx_amount}")]
+    MaxTxAmountExceeded { max_tx_amount: Uint128 },
+
+    #[error("Recipient balance would exceed max wallet balance {max_wallet_balance}")]
+    MaxWalletBalanceExceeded { max_wallet_balance: Uint128 },
+
+    #[error("{addr} cannot transfer again before block height {next_transfer_height}")]
+    TransferCooldownNotPassed {
+        addr: String,
+        next_transfer_height: u64,
+    },
+
+    #[error("Account {addr} is frozen")]
+    AccountFrozen { addr: String },
+
+    #[error("Coin is globally paused")]
+    CoinGloballyPaused {},
+
+    #[error(
+        "Initial supply {initial_supply:?} exceeds max supply {max_supply:?}"
+    )]
+    InitialSupplyExceedsMaxSupply {
+        initial_supply: Uint128,
+        max_supply: Uint128,
+    },
+
+    #[error("No pending admin proposal")]
+    NoPendingAdminProposal {},
+
+    #[error("Only pending admin can accept admin")]
+    OnlyPendingAdminCanAccept {},
+
+    #[error("Admin proposal expired")]
+    AdminProposalExpired {},
+
+    #[error("Sender is not admin and does not have role {role:?}")]
+    MissingRole { role: String },
+
+    #[error("{action} is paused")]
+    Paused { action: String },
+
+    #[error("Only admin or guardian can pause")]
+    OnlyAdminOrGuardianCanPause {},
+
+    #[error("Invalid contract version {version:?}: {reason}")]
+    InvalidContractVersion { version: String, reason: String },
+
+    #[error("Cannot migrate {stored_contract:?} to {new_contract:?}")]
+    CannotMigrateFromDifferentContract {
+        stored_contract: String,
+        new_contract: String,
+    },
+
+    #[error("Cannot migrate from version {stored_version:?} to older version {new_version:?}")]
+    CannotMigrateToOlderVersion {
+        stored_version: String,
+        new_version: String,
+    },
+
+    #[error("Token contract {contract_addr:?} is not registered in launchpad")]
+    TokenContractNotRegistered { contract_addr: String },
+
+    #[error("Template {name:?} {version:?} already exists")]
+    TemplateAlreadyExists { name: String, version: String },
+
+    #[error("Template {name:?} {version:?} not found")]
+    TemplateNotFound { name: String, version: String },
+
+    #[error("Template {name:?} {version:?} is disabled")]
+    TemplateDisabled { name: String, version: String },
+
+    #[error("Template {name:?} {version:?} is not for this token type")]
+    TemplateTokenTypeMismatch { name: String, version: String },
+
+    #[error("Creator is not in launch allowlist")]
+    CreatorNotInLaunchAllowlist {},
+
+    #[error("Launch merkle proof required")]
+    LaunchMerkleProofRequired {},
+
+    #[error("Invalid launch merkle proof")]
+    InvalidLaunchMerkleProof {},
+
+    #[error("Launch request of creator is not approved")]
+    LaunchRequestNotApproved {},
+
+    #[error("Launch request of creator is already approved")]
+    LaunchRequestAlreadyApproved {},
+
+    #[error("No pending launch request for {creator_addr:?}")]
+    NoPendingLaunchRequest { creator_addr: String },
+
+    #[error("Referral share cannot exceed {max_bps:?} bps")]
+    ReferralShareTooHigh { max_bps: u64 },
+
+    #[error("Sender cannot refer itself")]
+    SelfReferral {},
+
+    #[error("No referral rewards to claim")]
+    NoReferralRewardsToClaim {},
+
+    #[error("Invalid fee recipients: {reason}")]
+    InvalidFeeRecipients { reason: String },
+
+    #[error("Cannot burn more than {free_balance:?} held by the coin contract outside vesting and airdrop reserves")]
+    BurnExceedsFreeBalance { free_balance: Uint128 },
+
     #[error("Unknown reply ID {reply_id:?}")]
     UnknownReplyId { reply_id: u64 },
 }
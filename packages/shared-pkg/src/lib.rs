@@ -1,2 +1,7 @@
+pub mod airdrop;
+pub mod burn;
 pub mod denom_helpers;
 pub mod error;
+pub mod merkle;
+pub mod migration;
+pub mod ownership;
@@ -0,0 +1,28 @@
+use sha3::{Digest, Keccak256};
+
+pub fn keccak256(data: &[u8]) -> Vec<u8> {
+    let mut hasher = Keccak256::new();
+    hasher.update(data);
+    hasher.finalize().to_vec()
+}
+
+/// Hash leaf up to the root with each proof hash, smaller hash of a pair goes first
+pub fn verify_merkle_proof(
+    leaf_hash: Vec<u8>,
+    merkle_proof: Vec<Vec<u8>>,
+    merkle_root: &[u8],
+) -> bool {
+    let mut calculated_root_hash: Vec<u8> = leaf_hash;
+    for proof_hash in merkle_proof.into_iter() {
+        let mut hasher = Keccak256::new();
+        if calculated_root_hash < proof_hash {
+            hasher.update(&calculated_root_hash);
+            hasher.update(&proof_hash);
+        } else {
+            hasher.update(&proof_hash);
+            hasher.update(&calculated_root_hash);
+        }
+        calculated_root_hash = hasher.finalize().to_vec();
+    }
+    calculated_root_hash == merkle_root
+}
@@ -0,0 +1,106 @@
+use crate::error::ContractError;
+use cosmwasm_std::Storage;
+use cw2::get_contract_version;
+use semver::Version;
+
+pub fn parse_version(version: &str) -> Result<Version, ContractError> {
+    Version::parse(version).map_err(|err| {
+        ContractError::InvalidContractVersion {
+            version: version.to_string(),
+            reason: err.to_string(),
+        }
+    })
+}
+
+/// Error if stored contract is a different contract or newer than the new code, returns stored version
+/// Migrating to the same version is allowed so a patched code can be deployed without a bump
+pub fn assert_can_migrate(
+    storage: &dyn Storage,
+    contract_name: &str,
+    contract_version: &str,
+) -> Result<Version, ContractError> {
+    let stored = get_contract_version(storage)?;
+    if stored.contract != contract_name {
+        return Err(ContractError::CannotMigrateFromDifferentContract {
+            stored_contract: stored.contract,
+            new_contract: contract_name.to_string(),
+        });
+    }
+    let stored_version = parse_version(&stored.version)?;
+    if stored_version > parse_version(contract_version)? {
+        return Err(ContractError::CannotMigrateToOlderVersion {
+            stored_version: stored.version,
+            new_version: contract_version.to_string(),
+        });
+    }
+    Ok(stored_version)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cosmwasm_std::testing::MockStorage;
+    use cw2::set_contract_version;
+
+    const CONTRACT_NAME: &str = "crates.io:coin-base";
+
+    fn storage_with_version(version: &str) -> MockStorage {
+        let mut storage = MockStorage::new();
+        set_contract_version(&mut storage, CONTRACT_NAME, version).unwrap();
+        storage
+    }
+
+    #[test]
+    fn same_or_newer_code_can_migrate() {
+        let storage = storage_with_version("0.2.0");
+        let migrate =
+            |version| assert_can_migrate(&storage, CONTRACT_NAME, version);
+        assert_eq!(migrate("0.2.0").unwrap(), Version::new(0, 2, 0));
+        assert_eq!(migrate("0.2.1").unwrap(), Version::new(0, 2, 0));
+        // semver order, not string order
+        assert_eq!(migrate("0.10.0").unwrap(), Version::new(0, 2, 0));
+    }
+
+    #[test]
+    fn downgrade_is_rejected() {
+        let storage = storage_with_version("0.10.0");
+        assert!(matches!(
+            assert_can_migrate(&storage, CONTRACT_NAME, "0.9.0").unwrap_err(),
+            ContractError::CannotMigrateToOlderVersion { stored_version, new_version }
+                if stored_version == "0.10.0" && new_version == "0.9.0"
+        ));
+        // pre-release is older than its release
+        let storage = storage_with_version("1.0.0");
+        assert!(matches!(
+            assert_can_migrate(&storage, CONTRACT_NAME, "1.0.0-rc.1")
+                .unwrap_err(),
+            ContractError::CannotMigrateToOlderVersion { .. }
+        ));
+    }
+
+    #[test]
+    fn other_contract_or_invalid_version_is_rejected() {
+        let storage = storage_with_version("0.2.0");
+        assert!(matches!(
+            assert_can_migrate(&storage, "crates.io:cw404-base", "0.2.0")
+                .unwrap_err(),
+            ContractError::CannotMigrateFromDifferentContract { .. }
+        ));
+        assert!(matches!(
+            assert_can_migrate(&storage, CONTRACT_NAME, "v0.3").unwrap_err(),
+            ContractError::InvalidContractVersion { version, .. } if version == "v0.3"
+        ));
+        let storage = storage_with_version("latest");
+        assert!(matches!(
+            assert_can_migrate(&storage, CONTRACT_NAME, "0.3.0").unwrap_err(),
+            ContractError::InvalidContractVersion { version, .. } if version == "latest"
+        ));
+        // nothing stored
+        assert!(assert_can_migrate(
+            &MockStorage::new(),
+            CONTRACT_NAME,
+            "0.3.0"
+        )
+        .is_err());
+    }
+}
@@ -0,0 +1,129 @@
+use crate::error::ContractError;
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, Api, BlockInfo, Response, StdResult, Storage};
+use cw_storage_plus::Item;
+use cw_utils::Expiration;
+
+pub const PENDING_ADMIN: Item<PendingAdmin> = Item::new("PENDING_ADMIN");
+
+/// Admin proposed by current admin, becomes admin once it accepts
+#[cw_serde]
+pub struct PendingAdmin {
+    pub new_admin_addr: Addr,
+    /// proposal cannot be accepted after it, None never expires
+    pub expiry: Option<Expiration>,
+}
+
+#[cw_serde]
+pub struct OwnershipResponse {
+    /// None if contract has no admin, e.g. immutable or renounced
+    pub admin_addr: Option<Addr>,
+    pub pending_admin: Option<PendingAdmin>,
+}
+
+pub fn new_pending_admin(
+    api: &dyn Api,
+    block: &BlockInfo,
+    new_admin_addr: &str,
+    expiry: Option<Expiration>,
+) -> Result<PendingAdmin, ContractError> {
+    if let Some(expiry) = &expiry {
+        if expiry.is_expired(block) {
+            return Err(ContractError::AdminProposalExpired {});
+        }
+    }
+    Ok(PendingAdmin {
+        new_admin_addr: api.addr_validate(new_admin_addr)?,
+        expiry,
+    })
+}
+
+/// Error unless sender is the proposed admin and the proposal has not expired
+pub fn assert_can_accept_admin(
+    pending_admin: Option<PendingAdmin>,
+    block: &BlockInfo,
+    sender: &Addr,
+) -> Result<Addr, ContractError> {
+    let pending_admin =
+        pending_admin.ok_or(ContractError::NoPendingAdminProposal {})?;
+    if sender != pending_admin.new_admin_addr {
+        return Err(ContractError::OnlyPendingAdminCanAccept {});
+    }
+    if let Some(expiry) = pending_admin.expiry {
+        if expiry.is_expired(block) {
+            return Err(ContractError::AdminProposalExpired {});
+        }
+    }
+    Ok(pending_admin.new_admin_addr)
+}
+
+/// Replaces any previous proposal, admin does not change until new admin accepts
+pub fn propose_new_admin(
+    api: &dyn Api,
+    storage: &mut dyn Storage,
+    block: &BlockInfo,
+    new_admin_addr: String,
+    expiry: Option<Expiration>,
+) -> Result<Response, ContractError> {
+    let pending_admin = new_pending_admin(api, block, &new_admin_addr, expiry)?;
+    PENDING_ADMIN.save(storage, &pending_admin)?;
+    Ok(Response::new()
+        .add_attribute("action", "propose_new_admin")
+        .add_attribute("new_admin_addr", pending_admin.new_admin_addr)
+        .add_attribute(
+            "expiry",
+            match pending_admin.expiry {
+                Some(expiry) => expiry.to_string(),
+                None => "None".to_string(),
+            },
+        ))
+}
+
+/// save_admin_addr writes the admin into the calling contract's config
+pub fn accept_admin(
+    storage: &mut dyn Storage,
+    block: &BlockInfo,
+    sender_addr: &Addr,
+    save_admin_addr: fn(&mut dyn Storage, Option<Addr>) -> StdResult<()>,
+) -> Result<Response, ContractError> {
+    let new_admin_addr = assert_can_accept_admin(
+        PENDING_ADMIN.may_load(storage)?,
+        block,
+        sender_addr,
+    )?;
+    save_admin_addr(storage, Some(new_admin_addr.clone()))?;
+    PENDING_ADMIN.remove(storage);
+    Ok(Response::new()
+        .add_attribute("action", "accept_admin")
+        .add_attribute("admin_addr", new_admin_addr))
+}
+
+pub fn cancel_proposal(
+    storage: &mut dyn Storage,
+) -> Result<Response, ContractError> {
+    if !PENDING_ADMIN.exists(storage) {
+        return Err(ContractError::NoPendingAdminProposal {});
+    }
+    PENDING_ADMIN.remove(storage);
+    Ok(Response::new().add_attribute("action", "cancel_proposal"))
+}
+
+/// Contract has no admin forever, pending proposal is dropped too
+pub fn renounce_admin(
+    storage: &mut dyn Storage,
+    save_admin_addr: fn(&mut dyn Storage, Option<Addr>) -> StdResult<()>,
+) -> Result<Response, ContractError> {
+    save_admin_addr(storage, None)?;
+    PENDING_ADMIN.remove(storage);
+    Ok(Response::new().add_attribute("action", "renounce_admin"))
+}
+
+pub fn query_ownership(
+    storage: &dyn Storage,
+    admin_addr: Option<Addr>,
+) -> StdResult<OwnershipResponse> {
+    Ok(OwnershipResponse {
+        admin_addr,
+        pending_admin: PENDING_ADMIN.may_load(storage)?,
+    })
+}
@@ -76,12 +76,16 @@ const init = async (
       launchpadCodeId,
       {
         admin_addr: signerAddress,
-        cw404_fee_collector: signerAddress,
+        cw404_fee_recipients: [
+          { addr: signerAddress, weight_bps: 10_000 },
+        ],
         cw404_code_id: cw404CodeId.toString(),
         cw404_collection_creation_fee: (2_500).toString(),
         cw404_mint_fee: (1_000).toString(),
         astroport_factory_addr: astroportFactoryAddrOnTestnet,
-        coin_fee_collector: signerAddress,
+        coin_fee_recipients: [
+          { addr: signerAddress, weight_bps: 10_000 },
+        ],
         coin_code_id: coinCodeId.toString(),
         coin_creation_fee: (2_500).toString(),
       },